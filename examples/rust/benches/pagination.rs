@@ -0,0 +1,33 @@
+// Offset vs keyset pagination on a large in-memory store
+//
+// Fetches page 5000 of 100k employees both ways. Offset pagination walks past every earlier
+// row; the keyset cursor starts from the last id the client saw.
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use openapi_integration::bench_support::Store;
+
+const EMPLOYEES: usize = 100_000;
+const PER_PAGE: u32 = 20;
+const PAGE: u32 = 5_000;
+
+fn pagination(c: &mut Criterion) {
+    let store = Store::seeded(EMPLOYEES);
+    let cursor = store.cursor_before(PAGE, PER_PAGE);
+    // Timings only mean something if both strategies return the same rows; tests/unit/pagination.rs
+    // checks every page of smaller stores
+    assert_eq!(
+        store.keyset_page(cursor.as_deref(), PER_PAGE).0,
+        store.offset_page(PAGE, PER_PAGE)
+    );
+
+    let mut group = c.benchmark_group("page_5000_of_100k");
+    group.bench_function("offset", |b| {
+        b.iter(|| store.offset_page(black_box(PAGE), PER_PAGE))
+    });
+    group.bench_function("keyset", |b| {
+        b.iter(|| store.keyset_page(black_box(cursor.as_deref()), PER_PAGE))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, pagination);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Generates the gRPC server for proto/employees.proto when the `grpc` feature is on, and
+// records the commit and build time reported by GET /version
+fn main() {
+    #[cfg(feature = "grpc")]
+    {
+        // A protoc on PATH is not required; PROTOC still overrides the bundled one
+        if std::env::var_os("PROTOC").is_none() {
+            let protoc = protoc_bin_vendored::protoc_bin_path().expect("no bundled protoc");
+            std::env::set_var("PROTOC", protoc);
+        }
+        tonic_build::compile_protos("proto/employees.proto")
+            .expect("failed to compile proto/employees.proto");
+    }
+
+    // GIT_SHA wins so builds from a tarball or a Docker context without .git can still set it
+    println!("cargo:rerun-if-env-changed=GIT_SHA");
+    let git_sha = std::env::var("GIT_SHA").ok().or_else(|| {
+        Command::new("git")
+            .args(["rev-parse", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .and_then(|output| String::from_utf8(output.stdout).ok())
+            .map(|sha| sha.trim().to_string())
+    });
+    println!(
+        "cargo:rustc-env=GIT_SHA={}",
+        git_sha.as_deref().unwrap_or("unknown")
+    );
+
+    // Seconds since the epoch; SOURCE_DATE_EPOCH keeps reproducible builds reproducible
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+    let build_time = std::env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|epoch| epoch.parse::<u64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs())
+        });
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={build_time}");
+}
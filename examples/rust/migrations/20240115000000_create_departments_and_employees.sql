@@ -0,0 +1,27 @@
+-- Schema for the `postgres` feature of openapi-integration.rs.
+-- Constraint names are matched in code to report which unique field clashed.
+
+CREATE TABLE departments (
+    id   UUID PRIMARY KEY,
+    name TEXT NOT NULL CHECK (char_length(name) BETWEEN 1 AND 100),
+    code TEXT NOT NULL CONSTRAINT departments_code_key UNIQUE
+);
+
+CREATE TABLE employees (
+    id                 UUID PRIMARY KEY,
+    employee_id        TEXT NOT NULL CONSTRAINT employees_employee_id_key UNIQUE,
+    first_name         TEXT NOT NULL CHECK (char_length(first_name) BETWEEN 1 AND 100),
+    last_name          TEXT NOT NULL CHECK (char_length(last_name) BETWEEN 1 AND 100),
+    email              TEXT NOT NULL CONSTRAINT employees_email_key UNIQUE,
+    department_id      UUID REFERENCES departments (id),
+    position           TEXT CHECK (char_length(position) <= 100),
+    employment_status  TEXT NOT NULL DEFAULT 'active'
+                       CHECK (employment_status IN ('active', 'inactive', 'terminated')),
+    termination_reason TEXT CHECK (char_length(termination_reason) <= 500),
+    hire_date          DATE NOT NULL,
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT now(),
+    version            BIGINT NOT NULL DEFAULT 1 CHECK (version >= 1)
+);
+
+CREATE INDEX employees_department_id_idx ON employees (department_id);
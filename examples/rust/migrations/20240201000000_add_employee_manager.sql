@@ -0,0 +1,8 @@
+-- Reporting line for GET /employees/{id}/reports. Deleting a manager clears the column on
+-- their reports in the same transaction; the FK only guards against dangling ids.
+
+ALTER TABLE employees
+    ADD COLUMN manager_id UUID REFERENCES employees (id),
+    ADD CONSTRAINT employees_manager_not_self CHECK (manager_id <> id);
+
+CREATE INDEX employees_manager_id_idx ON employees (manager_id);
@@ -0,0 +1,5 @@
+-- Compensation; unconstrained NUMERIC keeps every digit the API accepts.
+
+ALTER TABLE employees
+    ADD COLUMN salary   NUMERIC CHECK (salary >= 0),
+    ADD COLUMN currency TEXT NOT NULL DEFAULT 'USD' CHECK (currency ~ '^[A-Z]{3}$');
@@ -0,0 +1,7 @@
+-- Emails are unique regardless of case. The column keeps the casing as submitted; the unique
+-- index is on lower(email) and takes over the constraint's name, so clashes still report `email`.
+-- Fails if existing rows already differ only in the case of their email; merge those first.
+
+ALTER TABLE employees DROP CONSTRAINT employees_email_key;
+
+CREATE UNIQUE INDEX employees_email_key ON employees (lower(email));
@@ -1,202 +0,0 @@
-// Rust OpenAPI integration example using utoipa
-use axum::{extract::State, http::StatusCode, response::Json, routing::get, Router};
-use serde::{Deserialize, Serialize};
-use utoipa::{OpenApi, ToSchema};
-use utoipa_swagger_ui::SwaggerUi;
-use uuid::Uuid;
-
-#[derive(OpenApi)]
-#[openapi(
-    paths(
-        list_employees,
-        create_employee,
-        get_employee
-    ),
-    components(
-        schemas(Employee, CreateEmployeeRequest, ApiError, PaginatedResponse)
-    ),
-    tags(
-        (name = "employees", description = "Employee management endpoints")
-    ),
-    security(
-        ("BearerAuth" = [])
-    )
-)]
-struct ApiDoc;
-
-#[derive(Serialize, Deserialize, ToSchema)]
-#[schema(example = json!({
-    "id": "123e4567-e89b-12d3-a456-426614174000",
-    "employee_id": "EMP001",
-    "first_name": "John",
-    "last_name": "Doe",
-    "email": "john.doe@company.com",
-    "employment_status": "active",
-    "hire_date": "2024-01-15",
-    "created_at": "2024-01-15T10:30:00Z",
-    "updated_at": "2024-01-15T10:30:00Z"
-}))]
-struct Employee {
-    id: Uuid,
-    #[schema(pattern = "^[A-Z]{3}[0-9]{3}$")]
-    employee_id: String,
-    #[schema(min_length = 1, max_length = 100)]
-    first_name: String,
-    #[schema(min_length = 1, max_length = 100)]
-    last_name: String,
-    #[schema(format = "email")]
-    email: String,
-    #[schema(enum_values = ["active", "inactive", "terminated"])]
-    employment_status: String,
-    #[schema(format = "date")]
-    hire_date: chrono::NaiveDate,
-    #[schema(format = "date-time")]
-    created_at: chrono::DateTime<chrono::Utc>,
-    #[schema(format = "date-time")]
-    updated_at: chrono::DateTime<chrono::Utc>,
-}
-
-#[derive(Deserialize, ToSchema)]
-#[schema(example = json!({
-    "employee_id": "EMP001",
-    "first_name": "John",
-    "last_name": "Doe",
-    "email": "john.doe@company.com",
-    "hire_date": "2024-01-15"
-}))]
-struct CreateEmployeeRequest {
-    #[schema(pattern = "^[A-Z]{3}[0-9]{3}$")]
-    employee_id: String,
-    #[schema(min_length = 1, max_length = 100)]
-    first_name: String,
-    #[schema(min_length = 1, max_length = 100)]
-    last_name: String,
-    #[schema(format = "email")]
-    email: String,
-    #[schema(format = "uuid")]
-    department_id: Option<Uuid>,
-    #[schema(max_length = 100)]
-    position: Option<String>,
-    #[schema(format = "date")]
-    hire_date: chrono::NaiveDate,
-}
-
-#[derive(Serialize, ToSchema)]
-struct ApiError {
-    error: ErrorDetails,
-}
-
-#[derive(Serialize, ToSchema)]
-struct ErrorDetails {
-    code: String,
-    message: String,
-    details: Option<Vec<ValidationError>>,
-}
-
-#[derive(Serialize, ToSchema)]
-struct ValidationError {
-    field: String,
-    message: String,
-}
-
-#[derive(Serialize, ToSchema)]
-struct PaginatedResponse<T> {
-    data: Vec<T>,
-    pagination: PaginationInfo,
-}
-
-#[derive(Serialize, ToSchema)]
-struct PaginationInfo {
-    page: u32,
-    per_page: u32,
-    total: u64,
-    total_pages: u32,
-}
-
-#[utoipa::path(
-    get,
-    path = "/employees",
-    tag = "employees",
-    summary = "List employees",
-    description = "Retrieve a paginated list of employees with optional filtering",
-    params(
-        ("page" = Option<u32>, Query, description = "Page number", minimum = 1, default = 1),
-        ("per_page" = Option<u32>, Query, description = "Items per page", minimum = 1, maximum = 100, default = 20),
-        ("department" = Option<String>, Query, description = "Filter by department"),
-        ("status" = Option<String>, Query, description = "Filter by employment status")
-    ),
-    responses(
-        (status = 200, description = "List of employees", body = PaginatedResponse<Employee>),
-        (status = 401, description = "Unauthorized", body = ApiError)
-    ),
-    security(
-        ("BearerAuth" = [])
-    )
-)]
-async fn list_employees(
-    State(state): State<AppState>,
-) -> Result<Json<PaginatedResponse<Employee>>, StatusCode> {
-    // Implementation here
-    todo!()
-}
-
-#[utoipa::path(
-    post,
-    path = "/employees",
-    tag = "employees",
-    summary = "Create employee",
-    description = "Create a new employee",
-    request_body = CreateEmployeeRequest,
-    responses(
-        (status = 201, description = "Employee created successfully", body = Employee),
-        (status = 400, description = "Bad request", body = ApiError),
-        (status = 422, description = "Validation error", body = ApiError)
-    ),
-    security(
-        ("BearerAuth" = [])
-    )
-)]
-async fn create_employee(
-    State(state): State<AppState>,
-    Json(payload): Json<CreateEmployeeRequest>,
-) -> Result<(StatusCode, Json<Employee>), StatusCode> {
-    // Implementation here
-    todo!()
-}
-
-#[utoipa::path(
-    get,
-    path = "/employees/{id}",
-    tag = "employees",
-    summary = "Get employee",
-    description = "Retrieve a specific employee by ID",
-    params(
-        ("id" = Uuid, Path, description = "Employee ID")
-    ),
-    responses(
-        (status = 200, description = "Employee details", body = Employee),
-        (status = 404, description = "Employee not found", body = ApiError)
-    ),
-    security(
-        ("BearerAuth" = [])
-    )
-)]
-async fn get_employee(
-    State(state): State<AppState>,
-    Path(id): axum::extract::Path<Uuid>,
-) -> Result<Json<Employee>, StatusCode> {
-    // Implementation here
-    todo!()
-}
-
-// App state placeholder
-#[derive(Clone)]
-struct AppState;
-
-pub fn create_app() -> Router {
-    Router::new()
-        .merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", ApiDoc::openapi()))
-        .route("/employees", get(list_employees).post(create_employee))
-        .route("/employees/:id", get(get_employee))
-        .with_state(AppState)
-}
\ No newline at end of file
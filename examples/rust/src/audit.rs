@@ -0,0 +1,94 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, PoisonError, RwLock};
+
+use uuid::Uuid;
+
+use crate::clock::Clock;
+use crate::error::AppError;
+use crate::models::audit::{AuditAction, AuditEntry, FieldChange};
+use crate::models::employees::Employee;
+
+// Append-only and in memory; a production deployment would persist it next to the
+// employees, in the same transaction as the write
+pub(crate) struct AuditLog {
+    pub(crate) entries: RwLock<Vec<AuditEntry>>,
+    pub(crate) clock: Arc<dyn Clock>,
+}
+
+impl AuditLog {
+    pub(crate) fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            entries: RwLock::new(Vec::new()),
+            clock,
+        }
+    }
+
+    pub(crate) fn created(&self, actor: &str, employee: &Employee) {
+        self.record(employee.id, AuditAction::Created, actor, BTreeMap::new());
+    }
+
+    pub(crate) fn updated(&self, actor: &str, before: &Employee, after: &Employee) {
+        self.record(
+            after.id,
+            AuditAction::Updated,
+            actor,
+            Self::diff(before, after),
+        );
+    }
+
+    pub(crate) fn deleted(&self, actor: &str, id: Uuid) {
+        self.record(id, AuditAction::Deleted, actor, BTreeMap::new());
+    }
+
+    pub(crate) fn record(
+        &self,
+        employee_id: Uuid,
+        action: AuditAction,
+        actor: &str,
+        changes: BTreeMap<String, FieldChange>,
+    ) {
+        let entry = AuditEntry {
+            id: Uuid::new_v4(),
+            employee_id,
+            action,
+            actor: actor.to_string(),
+            timestamp: self.clock.now(),
+            changes,
+        };
+        self.entries
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(entry);
+    }
+
+    pub(crate) fn for_employee(&self, employee_id: Uuid) -> Result<Vec<AuditEntry>, AppError> {
+        Ok(self
+            .entries
+            .read()?
+            .iter()
+            .filter(|entry| entry.employee_id == employee_id)
+            .cloned()
+            .collect())
+    }
+
+    // Compares the serialized forms, so the diff uses the same names and formats as the API.
+    // Bookkeeping fields change on every write and are left out.
+    pub(crate) fn diff(before: &Employee, after: &Employee) -> BTreeMap<String, FieldChange> {
+        let as_object = |employee: &Employee| match serde_json::to_value(employee) {
+            Ok(serde_json::Value::Object(object)) => object,
+            _ => serde_json::Map::new(),
+        };
+        let (before, mut after) = (as_object(before), as_object(after));
+
+        let mut keys: BTreeSet<String> = before.keys().cloned().collect();
+        keys.extend(after.keys().cloned());
+        keys.into_iter()
+            .filter(|key| !matches!(key.as_str(), "updated_at" | "version"))
+            .filter_map(|key| {
+                let old = before.get(&key).cloned().unwrap_or_default();
+                let new = after.remove(&key).unwrap_or_default();
+                (old != new).then_some((key, FieldChange { old, new }))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::builders;
+use crate::config::app::PageLimits;
+use crate::models::common::PaginationInfo;
+use crate::models::employees::Employee;
+use crate::utils::pagination::{cursor_page, encode_cursor, offset_page};
+
+// Entry points for benches/pagination.rs and tests/unit/pagination.rs. Only ids cross the
+// boundary, so neither depends on the employee fields.
+
+// Employees in id order, so offset and keyset pages line up
+pub struct Store(pub Vec<Employee>);
+
+impl Store {
+    pub fn seeded(count: usize) -> Self {
+        let now = Utc::now();
+        let mut employees: Vec<Employee> = (0..count)
+            .map(|i| {
+                builders::EmployeeBuilder::new(&format!("EMP{i:06}"), "Bench", "Employee", now)
+                    .with_email(&format!("bench{i}@company.com"))
+                    .build()
+            })
+            .collect();
+        employees.sort_by_key(|e| e.id);
+        Self(employees)
+    }
+
+    pub fn offset_page(&self, page: u32, per_page: u32) -> Vec<Uuid> {
+        let page = offset_page(
+            self.0.iter().collect(),
+            Some(page),
+            Some(per_page),
+            limits(per_page),
+        )
+        .unwrap_or_else(|_| panic!("invalid page {page}"));
+        page.data.iter().map(|e| e.id).collect()
+    }
+
+    pub fn keyset_page(&self, cursor: Option<&str>, per_page: u32) -> (Vec<Uuid>, Option<String>) {
+        let page = cursor_page(
+            self.0.iter().collect(),
+            cursor,
+            Some(per_page),
+            limits(per_page),
+        )
+        .unwrap_or_else(|_| panic!("invalid cursor {cursor:?}"));
+        let PaginationInfo::Cursor(pagination) = page.pagination else {
+            unreachable!("cursor_page returns cursor pagination");
+        };
+        (
+            page.data.iter().map(|e| e.id).collect(),
+            pagination.next_cursor,
+        )
+    }
+
+    // The cursor a client would hold after walking to the end of page - 1
+    pub fn cursor_before(&self, page: u32, per_page: u32) -> Option<String> {
+        let skipped = (page as usize - 1) * per_page as usize;
+        skipped
+            .checked_sub(1)
+            .map(|last| encode_cursor(self.0[last].id))
+    }
+}
+
+pub fn limits(per_page: u32) -> PageLimits {
+    PageLimits {
+        default_per_page: per_page,
+        max_per_page: per_page,
+    }
+}
@@ -0,0 +1,145 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::models::employees::{CreateEmployeeRequest, Employee, EmploymentStatus};
+use crate::utils::validation::default_currency;
+
+// Fixture builders: every field has a valid default, so a test or seed only spells out what
+// it cares about. This is the recommended way to construct employees when extending the
+// example; the seed data uses only a few setters, the rest are for tests. Dates and timestamps
+// come from the caller, normally `state.clock.now()`, so a FixedClock pins them.
+pub struct CreateEmployeeRequestBuilder {
+    pub request: CreateEmployeeRequest,
+}
+
+impl CreateEmployeeRequestBuilder {
+    // The email defaults to first.last@company.com, lowercased, and the hire date to `today`
+    pub fn new(employee_id: &str, first_name: &str, last_name: &str, today: NaiveDate) -> Self {
+        Self {
+            request: CreateEmployeeRequest {
+                employee_id: employee_id.to_string(),
+                first_name: first_name.to_string(),
+                last_name: last_name.to_string(),
+                email: format!("{first_name}.{last_name}@company.com").to_lowercase(),
+                department_id: None,
+                manager_id: None,
+                position: None,
+                salary: None,
+                currency: default_currency(),
+                employment_status: None,
+                hire_date: today,
+            },
+        }
+    }
+
+    pub fn with_email(mut self, email: &str) -> Self {
+        self.request.email = email.to_string();
+        self
+    }
+
+    pub fn with_department(mut self, department_id: Option<Uuid>) -> Self {
+        self.request.department_id = department_id;
+        self
+    }
+
+    pub fn with_manager(mut self, manager_id: Option<Uuid>) -> Self {
+        self.request.manager_id = manager_id;
+        self
+    }
+
+    pub fn with_position(mut self, position: &str) -> Self {
+        self.request.position = Some(position.to_string());
+        self
+    }
+
+    pub fn with_salary(mut self, salary: Decimal, currency: &str) -> Self {
+        self.request.salary = Some(salary);
+        self.request.currency = currency.to_string();
+        self
+    }
+
+    pub fn with_status(mut self, status: EmploymentStatus) -> Self {
+        self.request.employment_status = Some(status);
+        self
+    }
+
+    pub fn hired_on(mut self, hire_date: NaiveDate) -> Self {
+        self.request.hire_date = hire_date;
+        self
+    }
+
+    pub fn build(self) -> CreateEmployeeRequest {
+        self.request
+    }
+}
+
+// Builds a stored employee the way create_employee would at `now`, at version 1
+pub struct EmployeeBuilder {
+    pub request: CreateEmployeeRequestBuilder,
+    pub termination_reason: Option<String>,
+    pub now: DateTime<Utc>,
+}
+
+impl EmployeeBuilder {
+    pub fn new(employee_id: &str, first_name: &str, last_name: &str, now: DateTime<Utc>) -> Self {
+        Self {
+            request: CreateEmployeeRequestBuilder::new(
+                employee_id,
+                first_name,
+                last_name,
+                now.date_naive(),
+            ),
+            termination_reason: None,
+            now,
+        }
+    }
+
+    pub fn with_email(mut self, email: &str) -> Self {
+        self.request = self.request.with_email(email);
+        self
+    }
+
+    pub fn with_department(mut self, department_id: Option<Uuid>) -> Self {
+        self.request = self.request.with_department(department_id);
+        self
+    }
+
+    pub fn with_manager(mut self, manager_id: Option<Uuid>) -> Self {
+        self.request = self.request.with_manager(manager_id);
+        self
+    }
+
+    pub fn with_position(mut self, position: &str) -> Self {
+        self.request = self.request.with_position(position);
+        self
+    }
+
+    pub fn with_salary(mut self, salary: Decimal, currency: &str) -> Self {
+        self.request = self.request.with_salary(salary, currency);
+        self
+    }
+
+    pub fn with_status(mut self, status: EmploymentStatus) -> Self {
+        self.request = self.request.with_status(status);
+        self
+    }
+
+    pub fn terminated(mut self, reason: &str) -> Self {
+        self.request = self.request.with_status(EmploymentStatus::Terminated);
+        self.termination_reason = Some(reason.to_string());
+        self
+    }
+
+    pub fn hired_on(mut self, hire_date: NaiveDate) -> Self {
+        self.request = self.request.hired_on(hire_date);
+        self
+    }
+
+    pub fn build(self) -> Employee {
+        Employee {
+            termination_reason: self.termination_reason,
+            ..Employee::from_request(self.request.build(), self.now)
+        }
+    }
+}
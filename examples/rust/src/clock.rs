@@ -0,0 +1,36 @@
+use std::sync::{Mutex, PoisonError};
+
+use chrono::Utc;
+
+// Where every stored timestamp comes from: created_at/updated_at, audit entries, jobs, webhook
+// deliveries, idempotency keys and the retention cutoff. Tests can pin it with FixedClock.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> chrono::DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> chrono::DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+// Stands still until advanced, so timestamps and retention cutoffs are exact
+pub struct FixedClock(pub Mutex<chrono::DateTime<Utc>>);
+
+impl FixedClock {
+    pub fn new(now: chrono::DateTime<Utc>) -> Self {
+        Self(Mutex::new(now))
+    }
+
+    pub fn advance(&self, by: chrono::Duration) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> chrono::DateTime<Utc> {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
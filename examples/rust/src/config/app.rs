@@ -0,0 +1,320 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use axum::http::HeaderValue;
+
+use crate::error::AppError;
+use crate::middleware::auth::{ADMIN_ROLE, OAUTH_SCOPES};
+use crate::storage::PhotoStorageConfig;
+
+pub(crate) const DEFAULT_PER_PAGE: u32 = 20;
+pub(crate) const MAX_PER_PAGE: u32 = 100;
+
+// Runtime settings, read once at startup so misconfiguration fails before the port opens
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub bind_addr: SocketAddr,
+    pub jwt_secret: String,
+    pub page_limits: PageLimits,
+    pub cors_allowed_origins: Vec<HeaderValue>,
+    pub rate_limit_requests: u32,
+    pub rate_limit_window: Duration,
+    // In-flight API requests beyond this are answered 503
+    pub max_concurrent_requests: usize,
+    // Start with writes refused; toggled at runtime through POST /admin/maintenance
+    pub maintenance_mode: bool,
+    pub idempotency_ttl: Duration,
+    pub shutdown_timeout: Duration,
+    pub require_if_match: bool,
+    // Off while tokens without a `scope` claim are still being issued
+    pub require_scopes: bool,
+    // Adds `_links` to employee responses, prefixed with `public_base_url`
+    pub hypermedia_links: bool,
+    // Also prefixes the `Location` of created employees
+    pub public_base_url: String,
+    pub webhook: Option<WebhookConfig>,
+    // Demo data for the in-memory store; SEED_FILE, overridden by `--seed <path>`
+    pub seed_file: Option<PathBuf>,
+    // DEV_AUTH: any bearer token authenticates as this identity; refused outside debug builds
+    pub dev_identity: Option<DevIdentity>,
+    pub photo_storage: PhotoStorageConfig,
+    // Lifetime of presigned photo download URLs
+    pub photo_url_ttl: Duration,
+    // Terminated employees untouched for this long are purged; None keeps them indefinitely
+    pub terminated_retention: Option<Duration>,
+    pub retention_sweep_interval: Duration,
+    // Largest accepted request body outside photo uploads
+    pub max_body_bytes: usize,
+    pub request_timeout: Duration,
+    // For CSV imports and bulk creates
+    pub batch_request_timeout: Duration,
+    // Single-employee read cache in front of the repository
+    pub cache_ttl: Duration,
+    pub cache_max_capacity: u64,
+    #[cfg(feature = "postgres")]
+    pub database_url: Option<String>,
+    // Off when the schema is managed outside the service
+    #[cfg(feature = "postgres")]
+    pub run_migrations: bool,
+    // The gRPC service listens here, beside the REST port
+    #[cfg(feature = "grpc")]
+    pub grpc_addr: SocketAddr,
+}
+
+impl Config {
+    pub fn from_env() -> anyhow::Result<Self> {
+        Self::from_lookup(|name| std::env::var(name).ok())
+    }
+
+    // Separated from `from_env` so callers can supply settings without touching the process env
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let parsed = |name: &str, default: u32| positive_integer(&lookup, name, default);
+
+        let flag = |name: &str, default: bool| -> anyhow::Result<bool> {
+            match lookup(name).as_deref().map(str::trim) {
+                None => Ok(default),
+                Some("true" | "1") => Ok(true),
+                Some("false" | "0") => Ok(false),
+                Some(value) => anyhow::bail!("{name} must be true or false, got {value:?}"),
+            }
+        };
+        let require_if_match = flag("REQUIRE_IF_MATCH", true)?;
+        let require_scopes = flag("REQUIRE_SCOPES", true)?;
+        let hypermedia_links = flag("HYPERMEDIA_LINKS", false)?;
+
+        // Empty keeps links root-relative, e.g. `/v1/employees/{id}`
+        let public_base_url = lookup("PUBLIC_BASE_URL")
+            .unwrap_or_default()
+            .trim()
+            .trim_end_matches('/')
+            .to_string();
+        anyhow::ensure!(
+            public_base_url.is_empty()
+                || public_base_url.starts_with("http://")
+                || public_base_url.starts_with("https://"),
+            "PUBLIC_BASE_URL must be an http(s) URL, got {public_base_url:?}"
+        );
+
+        let jwt_secret = lookup("JWT_SECRET")
+            .filter(|secret| !secret.is_empty())
+            .context("JWT_SECRET must be set to the HS256 signing secret")?;
+
+        let bind_addr = match lookup("BIND_ADDR") {
+            None => SocketAddr::from(([0, 0, 0, 0], 3000)),
+            Some(value) => value
+                .parse()
+                .with_context(|| format!("BIND_ADDR must be host:port, got {value:?}"))?,
+        };
+
+        #[cfg(feature = "grpc")]
+        let grpc_addr = match lookup("GRPC_ADDR") {
+            None => SocketAddr::from(([0, 0, 0, 0], 50051)),
+            Some(value) => value
+                .parse()
+                .with_context(|| format!("GRPC_ADDR must be host:port, got {value:?}"))?,
+        };
+
+        let page_limits = PageLimits::from_lookup(&lookup)?;
+
+        let webhook = match (
+            lookup("WEBHOOK_URL").filter(|url| !url.is_empty()),
+            lookup("WEBHOOK_SECRET").filter(|secret| !secret.is_empty()),
+        ) {
+            (None, None) => None,
+            (Some(url), Some(secret)) => {
+                anyhow::ensure!(
+                    url.starts_with("http://") || url.starts_with("https://"),
+                    "WEBHOOK_URL must be an http(s) URL, got {url:?}"
+                );
+                Some(WebhookConfig { url, secret })
+            }
+            _ => anyhow::bail!("WEBHOOK_URL and WEBHOOK_SECRET must be set together"),
+        };
+
+        let photo_storage = match lookup("PHOTO_STORAGE").as_deref().map(str::trim) {
+            None | Some("" | "memory") => PhotoStorageConfig::Memory,
+            Some("local") => PhotoStorageConfig::Local {
+                dir: PathBuf::from(lookup("PHOTO_DIR").unwrap_or_else(|| "photos".to_string())),
+            },
+            #[cfg(feature = "s3")]
+            Some("s3") => PhotoStorageConfig::S3 {
+                bucket: lookup("S3_BUCKET")
+                    .filter(|bucket| !bucket.is_empty())
+                    .context("S3_BUCKET must be set when PHOTO_STORAGE=s3")?,
+                prefix: lookup("S3_PREFIX").unwrap_or_else(|| "photos/".to_string()),
+            },
+            Some(other) => anyhow::bail!(
+                "PHOTO_STORAGE must be memory, local{}, got {other:?}",
+                if cfg!(feature = "s3") { " or s3" } else { "" }
+            ),
+        };
+
+        let terminated_retention = match lookup("TERMINATED_RETENTION_DAYS") {
+            None => None,
+            Some(_) => {
+                let days = parsed("TERMINATED_RETENTION_DAYS", 1)?;
+                Some(Duration::from_secs(u64::from(days) * 24 * 60 * 60))
+            }
+        };
+
+        // Release builds refuse to start with it rather than silently ignoring it, so a
+        // misconfigured deployment is noticed
+        let dev_identity = if flag("DEV_AUTH", false)? {
+            anyhow::ensure!(
+                cfg!(debug_assertions),
+                "DEV_AUTH is only available in debug builds; unset it for release builds"
+            );
+            let list = |name: &str, default: &[&str]| -> Vec<String> {
+                lookup(name).map_or_else(
+                    || default.iter().map(|item| item.to_string()).collect(),
+                    |value| {
+                        value
+                            .split(|c: char| c == ',' || c.is_whitespace())
+                            .filter(|item| !item.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    },
+                )
+            };
+            let all_scopes: Vec<&str> = OAUTH_SCOPES.iter().map(|(scope, _)| *scope).collect();
+            Some(DevIdentity {
+                sub: lookup("DEV_AUTH_SUB").unwrap_or_else(|| "dev".to_string()),
+                roles: list("DEV_AUTH_ROLES", &[ADMIN_ROLE]),
+                scopes: list("DEV_AUTH_SCOPES", &all_scopes),
+            })
+        } else {
+            None
+        };
+
+        let cors_allowed_origins = lookup("CORS_ALLOWED_ORIGINS")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|origin| !origin.is_empty())
+            .map(|origin| {
+                HeaderValue::from_str(origin)
+                    .with_context(|| format!("invalid origin {origin:?} in CORS_ALLOWED_ORIGINS"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+
+        Ok(Self {
+            bind_addr,
+            jwt_secret,
+            page_limits,
+            cors_allowed_origins,
+            rate_limit_requests: parsed("RATE_LIMIT_REQUESTS", 100)?,
+            rate_limit_window: Duration::from_secs(u64::from(parsed(
+                "RATE_LIMIT_WINDOW_SECS",
+                60,
+            )?)),
+            max_concurrent_requests: parsed("MAX_CONCURRENT_REQUESTS", 512)? as usize,
+            maintenance_mode: flag("MAINTENANCE_MODE", false)?,
+            idempotency_ttl: Duration::from_secs(u64::from(parsed(
+                "IDEMPOTENCY_TTL_SECS",
+                24 * 60 * 60,
+            )?)),
+            shutdown_timeout: Duration::from_secs(u64::from(parsed("SHUTDOWN_TIMEOUT_SECS", 30)?)),
+            require_if_match,
+            require_scopes,
+            hypermedia_links,
+            public_base_url,
+            webhook,
+            seed_file: lookup("SEED_FILE")
+                .filter(|path| !path.trim().is_empty())
+                .map(PathBuf::from),
+            dev_identity,
+            photo_storage,
+            photo_url_ttl: Duration::from_secs(u64::from(parsed("PHOTO_URL_TTL_SECS", 300)?)),
+            terminated_retention,
+            retention_sweep_interval: Duration::from_secs(u64::from(parsed(
+                "RETENTION_SWEEP_INTERVAL_SECS",
+                60 * 60,
+            )?)),
+            max_body_bytes: parsed("MAX_BODY_BYTES", 1024 * 1024)? as usize,
+            request_timeout: Duration::from_secs(u64::from(parsed("REQUEST_TIMEOUT_SECS", 30)?)),
+            batch_request_timeout: Duration::from_secs(u64::from(parsed(
+                "BATCH_REQUEST_TIMEOUT_SECS",
+                300,
+            )?)),
+            cache_ttl: Duration::from_secs(u64::from(parsed("CACHE_TTL_SECS", 30)?)),
+            cache_max_capacity: u64::from(parsed("CACHE_MAX_CAPACITY", 10_000)?),
+            #[cfg(feature = "postgres")]
+            database_url: lookup("DATABASE_URL").filter(|url| !url.is_empty()),
+            #[cfg(feature = "postgres")]
+            run_migrations: !flag("SKIP_MIGRATIONS", false)?,
+            #[cfg(feature = "grpc")]
+            grpc_addr,
+        })
+    }
+}
+
+// Where employee events are POSTed, and the key their signatures are made with
+#[derive(Clone, Debug)]
+pub struct WebhookConfig {
+    pub url: String,
+    pub secret: String,
+}
+
+// Who every request is made as under DEV_AUTH: DEV_AUTH_SUB (default `dev`), DEV_AUTH_ROLES
+// (default `admin`) and DEV_AUTH_SCOPES (default every scope), the lists comma separated
+#[derive(Clone, Debug)]
+pub struct DevIdentity {
+    pub sub: String,
+    pub roles: Vec<String>,
+    pub scopes: Vec<String>,
+}
+
+pub(crate) fn positive_integer(
+    lookup: impl Fn(&str) -> Option<String>,
+    name: &str,
+    default: u32,
+) -> anyhow::Result<u32> {
+    match lookup(name) {
+        None => Ok(default),
+        Some(value) => value
+            .trim()
+            .parse()
+            .ok()
+            .filter(|value| *value > 0)
+            .with_context(|| format!("{name} must be a positive integer, got {value:?}")),
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PageLimits {
+    pub default_per_page: u32,
+    pub max_per_page: u32,
+}
+
+impl PageLimits {
+    // DEFAULT_PER_PAGE and MAX_PER_PAGE. Read on their own by the spec commands, which document
+    // the limits but need no other setting.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> anyhow::Result<Self> {
+        let limits = Self {
+            default_per_page: positive_integer(&lookup, "DEFAULT_PER_PAGE", DEFAULT_PER_PAGE)?,
+            max_per_page: positive_integer(&lookup, "MAX_PER_PAGE", MAX_PER_PAGE)?,
+        };
+        anyhow::ensure!(
+            limits.default_per_page <= limits.max_per_page,
+            "DEFAULT_PER_PAGE ({}) must not exceed MAX_PER_PAGE ({})",
+            limits.default_per_page,
+            limits.max_per_page,
+        );
+        Ok(limits)
+    }
+
+    // Out-of-range sizes are rejected rather than clamped, so a client asking for 1000 doesn't
+    // mistake 100 rows for the whole result
+    pub(crate) fn resolve(self, name: &str, per_page: Option<u32>) -> Result<u32, AppError> {
+        match per_page {
+            None => Ok(self.default_per_page),
+            Some(size) if (1..=self.max_per_page).contains(&size) => Ok(size),
+            Some(size) => Err(AppError::BadRequest(format!(
+                "{name} must be between 1 and {}, got {size}",
+                self.max_per_page
+            ))),
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::HashMap;
+use std::sync::PoisonError;
+
+use axum::{
+    http::{
+        header::{CONTENT_TYPE, RETRY_AFTER, WWW_AUTHENTICATE},
+        HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Json, Response},
+};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::middleware::locale::localized;
+use crate::middleware::logging::current_request_id;
+use crate::models::common::{
+    ApiError, ConflictErrorCode, ConflictErrorDetails, ErrorCode, ErrorDetails, GeneralErrorCode,
+    GeneralErrorDetails, ValidationError, ValidationErrorCode, ValidationErrorDetails,
+};
+use crate::repository::RepoError;
+
+impl ApiError {
+    pub(crate) fn new(code: GeneralErrorCode, message: impl Into<String>) -> Self {
+        Self {
+            error: ErrorDetails::General(GeneralErrorDetails {
+                code,
+                message: message.into(),
+                request_id: current_request_id(),
+            }),
+        }
+    }
+
+    pub(crate) fn validation(details: Vec<ValidationError>) -> Self {
+        Self {
+            error: ErrorDetails::Validation(ValidationErrorDetails {
+                code: ValidationErrorCode::ValidationError,
+                message: localized(ErrorCode::ValidationError.as_str(), &HashMap::new())
+                    .unwrap_or_else(|| "Invalid input data".to_string()),
+                details,
+                request_id: current_request_id(),
+            }),
+        }
+    }
+
+    pub(crate) fn conflict(message: impl Into<String>, field: Option<&str>) -> Self {
+        Self {
+            error: ErrorDetails::Conflict(ConflictErrorDetails {
+                code: ConflictErrorCode::Conflict,
+                message: message.into(),
+                field: field.map(str::to_string),
+                request_id: current_request_id(),
+            }),
+        }
+    }
+
+    pub(crate) fn status(&self) -> StatusCode {
+        self.error.code().status()
+    }
+}
+
+impl IntoResponse for ApiError {
+    #[cfg(not(feature = "problem-json"))]
+    fn into_response(self) -> Response {
+        (self.status(), Json(self)).into_response()
+    }
+
+    #[cfg(feature = "problem-json")]
+    fn into_response(self) -> Response {
+        ProblemDetails::from(self).into_response()
+    }
+}
+
+// RFC 7807 error document, used instead of ApiError when the `problem-json` feature is on
+#[derive(Serialize, ToSchema)]
+#[schema(example = json!({
+    "type": "https://api.company.com/problems/not_found",
+    "title": "Not Found",
+    "status": 404,
+    "detail": "Employee 123e4567-e89b-12d3-a456-426614174000 not found",
+    "instance": "urn:uuid:0f8fad5b-d9cb-469f-a165-70867728950e"
+}))]
+pub(crate) struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub(crate) problem_type: String,
+    pub(crate) title: String,
+    pub(crate) status: u16,
+    pub(crate) detail: String,
+    pub(crate) instance: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Vec<ValidationError>)]
+    pub(crate) errors: Option<Vec<ValidationError>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub(crate) field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub(crate) request_id: Option<String>,
+}
+
+impl From<ApiError> for ProblemDetails {
+    fn from(api_error: ApiError) -> Self {
+        let status = api_error.status();
+        let code = api_error.error.code();
+        let (message, errors, field, request_id) = match api_error.error {
+            ErrorDetails::Validation(details) => (
+                details.message,
+                Some(details.details),
+                None,
+                details.request_id,
+            ),
+            ErrorDetails::Conflict(details) => {
+                (details.message, None, details.field, details.request_id)
+            }
+            ErrorDetails::General(details) => (details.message, None, None, details.request_id),
+        };
+        Self {
+            problem_type: format!("https://api.company.com/problems/{code}"),
+            title: status.canonical_reason().unwrap_or("Error").to_string(),
+            status: status.as_u16(),
+            detail: message,
+            // Identifies this occurrence so it can be correlated with server logs
+            instance: format!("urn:uuid:{}", Uuid::new_v4()),
+            errors,
+            field,
+            request_id,
+        }
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        (
+            status,
+            [(CONTENT_TYPE, "application/problem+json")],
+            Json(self),
+        )
+            .into_response()
+    }
+}
+
+// Handler-facing error type; every variant renders as the documented ApiError body
+pub enum AppError {
+    BadRequest(String),
+    NotFound(String),
+    Conflict(String),
+    // A unique field already holds the submitted value
+    Duplicate {
+        field: &'static str,
+        message: String,
+    },
+    PreconditionFailed(String),
+    PreconditionRequired,
+    PayloadTooLarge(String),
+    UnsupportedMediaType(String),
+    NotAcceptable,
+    Validation(Vec<ValidationError>),
+    Unauthorized(AuthFailure),
+    Forbidden,
+    // The token is valid but was not granted this OAuth2 scope
+    MissingScope(&'static str),
+    RateLimited {
+        retry_after_secs: u64,
+    },
+    MethodNotAllowed,
+    ServiceUnavailable(String),
+    // Maintenance mode is on and this request would write
+    Maintenance,
+    // MAX_CONCURRENT_REQUESTS are already in flight
+    Overloaded {
+        retry_after_secs: u64,
+    },
+    GatewayTimeout,
+    Internal(String),
+}
+
+impl From<AppError> for ApiError {
+    fn from(err: AppError) -> Self {
+        match err {
+            AppError::BadRequest(message) => ApiError::new(GeneralErrorCode::BadRequest, message),
+            AppError::NotFound(message) => ApiError::new(GeneralErrorCode::NotFound, message),
+            AppError::Conflict(message) => ApiError::conflict(message, None),
+            AppError::Duplicate { field, message } => ApiError::conflict(message, Some(field)),
+            AppError::PreconditionFailed(message) => {
+                ApiError::new(GeneralErrorCode::PreconditionFailed, message)
+            }
+            AppError::PreconditionRequired => ApiError::new(
+                GeneralErrorCode::PreconditionRequired,
+                "If-Match header with the current ETag is required",
+            ),
+            AppError::PayloadTooLarge(message) => {
+                ApiError::new(GeneralErrorCode::PayloadTooLarge, message)
+            }
+            AppError::UnsupportedMediaType(message) => {
+                ApiError::new(GeneralErrorCode::UnsupportedMediaType, message)
+            }
+            AppError::NotAcceptable => ApiError::new(
+                GeneralErrorCode::NotAcceptable,
+                "Supported response types are application/json and application/xml",
+            ),
+            AppError::Validation(details) => ApiError::validation(details),
+            AppError::Unauthorized(AuthFailure::MissingToken) => {
+                ApiError::new(GeneralErrorCode::Unauthorized, "Authentication required")
+            }
+            AppError::Unauthorized(AuthFailure::InvalidToken) => ApiError::new(
+                GeneralErrorCode::Unauthorized,
+                "Bearer token is invalid or expired",
+            ),
+            AppError::Forbidden => {
+                ApiError::new(GeneralErrorCode::Forbidden, "Insufficient permissions")
+            }
+            AppError::MissingScope(scope) => ApiError::new(
+                GeneralErrorCode::Forbidden,
+                format!("Token lacks the {scope} scope"),
+            ),
+            AppError::RateLimited { retry_after_secs } => ApiError::new(
+                GeneralErrorCode::RateLimited,
+                format!("Too many requests; retry after {retry_after_secs}s"),
+            ),
+            AppError::MethodNotAllowed => ApiError::new(
+                GeneralErrorCode::MethodNotAllowed,
+                "This endpoint does not support the request method; see the Allow header",
+            ),
+            AppError::ServiceUnavailable(message) => {
+                ApiError::new(GeneralErrorCode::ServiceUnavailable, message)
+            }
+            AppError::Maintenance => ApiError::new(
+                GeneralErrorCode::ServiceUnavailable,
+                "The service is in maintenance mode; writes are disabled until it ends",
+            ),
+            AppError::Overloaded { retry_after_secs } => ApiError::new(
+                GeneralErrorCode::ServiceUnavailable,
+                format!("The server is at capacity; retry after {retry_after_secs}s"),
+            ),
+            AppError::GatewayTimeout => ApiError::new(
+                GeneralErrorCode::GatewayTimeout,
+                "The request took too long to process",
+            ),
+            AppError::Internal(message) => ApiError::new(GeneralErrorCode::InternalError, message),
+        }
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let extra_header = match &self {
+            AppError::Unauthorized(failure) => Some((
+                WWW_AUTHENTICATE,
+                HeaderValue::from_static(failure.challenge()),
+            )),
+            AppError::MissingScope(scope) => Some((
+                WWW_AUTHENTICATE,
+                // Scope names are static and header-safe
+                HeaderValue::from_str(&format!(
+                    "Bearer error=\"insufficient_scope\", scope=\"{scope}\""
+                ))
+                .expect("valid challenge"),
+            )),
+            AppError::RateLimited { retry_after_secs }
+            | AppError::Overloaded { retry_after_secs } => {
+                Some((RETRY_AFTER, HeaderValue::from(*retry_after_secs)))
+            }
+            _ => None,
+        };
+        let api_error = ApiError::from(self);
+        // Emitted inside the request span, so the failure is attached to that request
+        let (code, message) = (api_error.error.code(), api_error.error.message());
+        if api_error.status().is_server_error() {
+            tracing::error!(%code, %message, "request failed");
+        } else {
+            tracing::warn!(%code, %message, "request rejected");
+        }
+
+        let mut response = api_error.into_response();
+        if let Some((name, value)) = extra_header {
+            response.headers_mut().insert(name, value);
+        }
+        response
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum AuthFailure {
+    MissingToken,
+    InvalidToken,
+}
+
+impl AuthFailure {
+    // RFC 6750 challenge sent with every 401
+    pub(crate) fn challenge(self) -> &'static str {
+        match self {
+            AuthFailure::MissingToken => r#"Bearer realm="api", error="invalid_request""#,
+            AuthFailure::InvalidToken => r#"Bearer realm="api", error="invalid_token""#,
+        }
+    }
+}
+
+impl From<validator::ValidationErrors> for AppError {
+    fn from(errors: validator::ValidationErrors) -> Self {
+        AppError::Validation(validation_details(&errors))
+    }
+}
+
+pub(crate) fn validation_details(errors: &validator::ValidationErrors) -> Vec<ValidationError> {
+    let mut details: Vec<ValidationError> = errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            errors
+                .iter()
+                .map(move |error| ValidationError::from_rule(field, error))
+        })
+        .collect();
+    // field_errors() is a HashMap; keep the response deterministic
+    details.sort_by(|a, b| a.field.cmp(&b.field));
+    details
+}
+
+impl<T> From<PoisonError<T>> for AppError {
+    fn from(_: PoisonError<T>) -> Self {
+        AppError::Internal("Data store is unavailable".to_string())
+    }
+}
+
+impl From<RepoError> for AppError {
+    fn from(err: RepoError) -> Self {
+        match err {
+            RepoError::NotFound(id) => AppError::NotFound(format!("Employee {id} not found")),
+            RepoError::Conflict(field) => AppError::Duplicate {
+                field,
+                message: format!("An employee with this {field} already exists"),
+            },
+            RepoError::VersionMismatch(id) => AppError::Conflict(format!(
+                "Employee {id} was modified concurrently; retry the request"
+            )),
+            RepoError::Unavailable(message) => AppError::Internal(message),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::models::audit::AuditAction;
+use crate::models::employees::Employee;
+
+// Events a slow subscriber may fall behind by before it is told it lagged
+pub(crate) const EVENT_BUFFER: usize = 256;
+
+#[derive(Clone, Serialize, ToSchema)]
+pub(crate) struct EmployeeEvent {
+    pub(crate) action: AuditAction,
+    pub(crate) employee_id: Uuid,
+    /// State after the change; absent for deletes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Employee)]
+    pub(crate) employee: Option<Employee>,
+}
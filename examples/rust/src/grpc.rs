@@ -0,0 +1,333 @@
+use std::future::Future;
+use std::net::SocketAddr;
+use std::str::FromStr;
+
+use anyhow::Context;
+use axum::http::StatusCode;
+use chrono::{NaiveDate, SecondsFormat, Utc};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::error::AppError;
+use crate::handlers::employees::{ensure_department_exists, ensure_valid_manager, sort_employees};
+use crate::handlers::find_employee;
+use crate::middleware::auth::{decode_claims, Claims, ADMIN_ROLE, EMPLOYEES_READ, EMPLOYEES_WRITE};
+use crate::models::common::{ApiError, ErrorDetails, PaginationInfo, SortOrder, ValidationError};
+use crate::models::employees::{CreateEmployeeRequest, Employee, SortField};
+use crate::repository::RepoError;
+use crate::state::AppState;
+use crate::utils::pagination::offset_page;
+use crate::utils::validation::default_currency;
+
+// gRPC mirror of the employee CRUD endpoints (proto/employees.proto), served on GRPC_ADDR.
+// It shares AppState with the REST router, so both surfaces read and write the same repository,
+// feed the same audit log and event stream, and accept the same tokens, roles and scopes.
+
+use tonic::{Code, Status};
+
+pub mod pb {
+    tonic::include_proto!("employees.v1");
+}
+
+use pb::employee_service_server::{EmployeeService, EmployeeServiceServer};
+
+pub(crate) async fn serve(
+    addr: SocketAddr,
+    state: AppState,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> anyhow::Result<()> {
+    tonic::transport::Server::builder()
+        .add_service(EmployeeServiceServer::new(EmployeeGrpc { state }))
+        .serve_with_shutdown(addr, shutdown)
+        .await
+        .with_context(|| format!("gRPC server on {addr} failed"))
+}
+
+pub struct EmployeeGrpc {
+    pub(crate) state: AppState,
+}
+
+#[tonic::async_trait]
+impl EmployeeService for EmployeeGrpc {
+    async fn get_employee(
+        &self,
+        request: tonic::Request<pb::GetEmployeeRequest>,
+    ) -> Result<tonic::Response<pb::Employee>, Status> {
+        Ok(tonic::Response::new(self.get(request).await?))
+    }
+
+    async fn list_employees(
+        &self,
+        request: tonic::Request<pb::ListEmployeesRequest>,
+    ) -> Result<tonic::Response<pb::ListEmployeesResponse>, Status> {
+        Ok(tonic::Response::new(self.list(request).await?))
+    }
+
+    async fn create_employee(
+        &self,
+        request: tonic::Request<pb::CreateEmployeeRequest>,
+    ) -> Result<tonic::Response<pb::Employee>, Status> {
+        Ok(tonic::Response::new(self.create(request).await?))
+    }
+
+    async fn update_employee(
+        &self,
+        request: tonic::Request<pb::UpdateEmployeeRequest>,
+    ) -> Result<tonic::Response<pb::Employee>, Status> {
+        Ok(tonic::Response::new(self.update(request).await?))
+    }
+
+    async fn delete_employee(
+        &self,
+        request: tonic::Request<pb::DeleteEmployeeRequest>,
+    ) -> Result<tonic::Response<pb::DeleteEmployeeResponse>, Status> {
+        Ok(tonic::Response::new(self.delete(request).await?))
+    }
+}
+
+// Same steps as the REST handlers, so both surfaces enforce the same rules
+impl EmployeeGrpc {
+    // What require_bearer_auth, require_scope and require_role do for REST routes
+    pub(crate) fn authorize<T>(
+        &self,
+        request: &tonic::Request<T>,
+        write: bool,
+    ) -> Result<Claims, AppError> {
+        let authorization = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok());
+        let claims = decode_claims(&self.state, authorization)?;
+        let scope = if write {
+            EMPLOYEES_WRITE
+        } else {
+            EMPLOYEES_READ
+        };
+        if self.state.require_scopes && !claims.has_scope(scope) {
+            return Err(AppError::MissingScope(scope));
+        }
+        if write && !claims.has_role(ADMIN_ROLE) {
+            return Err(AppError::Forbidden);
+        }
+        if write {
+            self.state.ensure_writable()?;
+        }
+        Ok(claims)
+    }
+
+    pub(crate) async fn get(
+        &self,
+        request: tonic::Request<pb::GetEmployeeRequest>,
+    ) -> Result<pb::Employee, AppError> {
+        let claims = self.authorize(&request, false)?;
+        let id = parse_id("id", &request.get_ref().id)?;
+        let employee = find_employee(&self.state, id).await?;
+        Ok(employee.redacted_for(&claims).into())
+    }
+
+    pub(crate) async fn list(
+        &self,
+        request: tonic::Request<pb::ListEmployeesRequest>,
+    ) -> Result<pb::ListEmployeesResponse, AppError> {
+        let claims = self.authorize(&request, false)?;
+        let pb::ListEmployeesRequest { page, per_page } = request.into_inner();
+        let employees = self.state.employees.list().await?;
+        let mut matching: Vec<&Employee> = employees.iter().collect();
+        sort_employees(&mut matching, SortField::LastName, SortOrder::Asc);
+        let page = offset_page(matching, page, per_page, self.state.page_limits)?;
+        let PaginationInfo::Offset(pagination) = &page.pagination else {
+            unreachable!("offset_page returns offset pagination");
+        };
+        let (total, page_number, per_page) =
+            (pagination.total, pagination.page, pagination.per_page);
+        Ok(pb::ListEmployeesResponse {
+            employees: page
+                .data
+                .into_iter()
+                .map(|employee| employee.redacted_for(&claims).into())
+                .collect(),
+            total,
+            page: page_number,
+            per_page,
+        })
+    }
+
+    pub(crate) async fn create(
+        &self,
+        request: tonic::Request<pb::CreateEmployeeRequest>,
+    ) -> Result<pb::Employee, AppError> {
+        let claims = self.authorize(&request, true)?;
+        let payload = employee_input(request.into_inner().employee)?;
+        payload.validate()?;
+        ensure_department_exists(&self.state, payload.department_id).await?;
+        ensure_valid_manager(self.state.employees.as_ref(), None, payload.manager_id).await?;
+        let employee = self
+            .state
+            .employees
+            .create(Employee::from_request(payload, self.state.clock.now()))
+            .await?;
+        self.state.record_created(&claims.sub, &employee);
+        Ok(employee.into())
+    }
+
+    pub(crate) async fn update(
+        &self,
+        request: tonic::Request<pb::UpdateEmployeeRequest>,
+    ) -> Result<pb::Employee, AppError> {
+        let claims = self.authorize(&request, true)?;
+        let pb::UpdateEmployeeRequest {
+            id,
+            employee,
+            version,
+        } = request.into_inner();
+        let id = parse_id("id", &id)?;
+        let payload = employee_input(employee)?;
+        payload.validate()?;
+        ensure_department_exists(&self.state, payload.department_id).await?;
+        ensure_valid_manager(self.state.employees.as_ref(), Some(id), payload.manager_id).await?;
+
+        let before = find_employee(&self.state, id).await?;
+        if version.is_some_and(|version| version != before.version) {
+            return Err(stale_version(id));
+        }
+        let mut employee = before.clone();
+        employee.replace_with(payload);
+        let employee = self
+            .state
+            .employees
+            .update(employee)
+            .await
+            .map_err(|err| match err {
+                RepoError::VersionMismatch(_) => stale_version(id),
+                other => other.into(),
+            })?;
+        self.state.record_updated(&claims.sub, &before, &employee);
+        Ok(employee.into())
+    }
+
+    pub(crate) async fn delete(
+        &self,
+        request: tonic::Request<pb::DeleteEmployeeRequest>,
+    ) -> Result<pb::DeleteEmployeeResponse, AppError> {
+        let claims = self.authorize(&request, true)?;
+        let id = parse_id("id", &request.get_ref().id)?;
+        self.state.employees.delete(id).await?;
+        self.state.discard_photo(id).await;
+        self.state.record_deleted(&claims.sub, id);
+        Ok(pb::DeleteEmployeeResponse {})
+    }
+}
+
+pub(crate) fn stale_version(id: Uuid) -> AppError {
+    AppError::PreconditionFailed(format!(
+        "Employee {id} has changed; fetch it again and retry with the new version"
+    ))
+}
+
+pub(crate) fn parse_id(field: &str, value: &str) -> Result<Uuid, AppError> {
+    value
+        .parse()
+        .map_err(|_| AppError::BadRequest(format!("{field} must be a UUID, got {value:?}")))
+}
+
+// Turns the wire input into the REST request type so it is validated identically
+pub(crate) fn employee_input(
+    input: Option<pb::EmployeeInput>,
+) -> Result<CreateEmployeeRequest, AppError> {
+    let input = input.ok_or_else(|| AppError::BadRequest("employee is required".to_string()))?;
+    let invalid = |field: &str, code: &str, message: &str| {
+        AppError::Validation(vec![ValidationError::new(field, code, message)])
+    };
+    Ok(CreateEmployeeRequest {
+        employee_id: input.employee_id,
+        first_name: input.first_name,
+        last_name: input.last_name,
+        email: input.email.trim().to_string(),
+        department_id: input
+            .department_id
+            .map(|id| parse_id("department_id", &id))
+            .transpose()?,
+        manager_id: input
+            .manager_id
+            .map(|id| parse_id("manager_id", &id))
+            .transpose()?,
+        position: input.position,
+        salary: input
+            .salary
+            .map(|salary| {
+                Decimal::from_str(&salary)
+                    .map_err(|_| invalid("salary", "invalid_decimal", "must be a decimal number"))
+            })
+            .transpose()?,
+        currency: if input.currency.is_empty() {
+            default_currency()
+        } else {
+            input.currency
+        },
+        employment_status: input
+            .employment_status
+            .map(|status| status.parse())
+            .transpose()?,
+        hire_date: NaiveDate::parse_from_str(&input.hire_date, "%Y-%m-%d")
+            .map_err(|_| invalid("hire_date", "invalid_date", "must be a YYYY-MM-DD date"))?,
+    })
+}
+
+impl From<Employee> for pb::Employee {
+    fn from(employee: Employee) -> Self {
+        let timestamp = |at: chrono::DateTime<Utc>| at.to_rfc3339_opts(SecondsFormat::Secs, true);
+        Self {
+            id: employee.id.to_string(),
+            employee_id: employee.employee_id,
+            first_name: employee.first_name,
+            last_name: employee.last_name,
+            email: employee.email,
+            department_id: employee.department_id.map(|id| id.to_string()),
+            manager_id: employee.manager_id.map(|id| id.to_string()),
+            position: employee.position,
+            salary: employee.salary.map(|salary| salary.to_string()),
+            currency: employee.currency,
+            employment_status: employee.employment_status.as_str().to_string(),
+            termination_reason: employee.termination_reason,
+            hire_date: employee.hire_date.format("%Y-%m-%d").to_string(),
+            created_at: timestamp(employee.created_at),
+            updated_at: timestamp(employee.updated_at),
+            version: employee.version,
+        }
+    }
+}
+
+// The status a REST client would get, translated to the nearest gRPC code
+impl From<AppError> for Status {
+    fn from(err: AppError) -> Self {
+        let api_error = ApiError::from(err);
+        let code = match (&api_error.error, api_error.status()) {
+            (ErrorDetails::Conflict(details), _) if details.field.is_none() => {
+                Code::FailedPrecondition
+            }
+            (_, StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY) => {
+                Code::InvalidArgument
+            }
+            (_, StatusCode::UNAUTHORIZED) => Code::Unauthenticated,
+            (_, StatusCode::FORBIDDEN) => Code::PermissionDenied,
+            (_, StatusCode::NOT_FOUND) => Code::NotFound,
+            (_, StatusCode::CONFLICT) => Code::AlreadyExists,
+            (_, StatusCode::PRECONDITION_FAILED) => Code::FailedPrecondition,
+            (_, StatusCode::TOO_MANY_REQUESTS) => Code::ResourceExhausted,
+            (_, StatusCode::SERVICE_UNAVAILABLE) => Code::Unavailable,
+            (_, StatusCode::GATEWAY_TIMEOUT) => Code::DeadlineExceeded,
+            _ => Code::Internal,
+        };
+        let message = match &api_error.error {
+            ErrorDetails::Validation(details) => details
+                .details
+                .iter()
+                .map(|error| format!("{} {}", error.field, error.message))
+                .collect::<Vec<_>>()
+                .join("; "),
+            other => other.message().to_string(),
+        };
+        Status::new(code, message)
+    }
+}
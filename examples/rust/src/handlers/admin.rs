@@ -0,0 +1,42 @@
+use std::sync::atomic::Ordering;
+
+use axum::{extract::State, response::Json, Extension};
+
+use crate::handlers::extract::ApiJson;
+use crate::middleware::auth::Claims;
+use crate::middleware::maintenance::MaintenanceMode;
+use crate::state::AppState;
+
+/// Toggle maintenance mode
+///
+/// While enabled, every POST, PUT, PATCH and DELETE under /v1 except this one and `POST
+/// /employees/export` answers 503; reads are unaffected. The setting lasts until changed again or
+/// the process restarts, which goes back to MAINTENANCE_MODE.
+#[utoipa::path(
+    post,
+    path = "/admin/maintenance",
+    tag = "admin",
+    request_body = MaintenanceMode,
+    responses(
+        (status = 200, description = "The mode now in effect", body = MaintenanceMode),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn set_maintenance_mode(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiJson(mode): ApiJson<MaintenanceMode>,
+) -> Json<MaintenanceMode> {
+    let was_enabled = state.maintenance.swap(mode.enabled, Ordering::Relaxed);
+    if was_enabled != mode.enabled {
+        tracing::warn!(enabled = mode.enabled, actor = %claims.sub, "maintenance mode changed");
+    }
+    Json(mode)
+}
@@ -0,0 +1,154 @@
+use axum::{extract::State, http::StatusCode, response::Json};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::error::AppError;
+use crate::handlers::extract::{ApiJson, ApiPath, ApiQuery};
+use crate::models::common::PaginatedResponse;
+use crate::models::departments::{CreateDepartmentRequest, Department};
+use crate::models::employees::PageParams;
+use crate::repository::RepoError;
+use crate::state::AppState;
+use crate::utils::pagination::offset_page;
+
+/// List departments
+///
+/// Retrieve a paginated list of departments ordered by code
+#[utoipa::path(
+    get,
+    path = "/departments",
+    tag = "departments",
+    params(
+        ("page" = Option<u32>, Query, description = "Page number (default `1`)", minimum = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", minimum = 1)
+    ),
+    responses(
+        (status = 200, description = "List of departments", body = PaginatedDepartments),
+        (status = 400, description = "Invalid page or page size", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `departments:read` scope", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["departments:read"])
+    )
+)]
+pub(crate) async fn list_departments(
+    State(state): State<AppState>,
+    ApiQuery(params): ApiQuery<PageParams>,
+) -> Result<Json<PaginatedResponse<Department>>, AppError> {
+    let departments = state.departments.list().await?;
+    let mut matching: Vec<&Department> = departments.iter().collect();
+    matching.sort_by(|a, b| a.code.cmp(&b.code));
+
+    Ok(Json(offset_page(
+        matching,
+        params.page,
+        params.per_page,
+        state.page_limits,
+    )?))
+}
+
+/// Create department
+///
+/// Create a new department
+#[utoipa::path(
+    post,
+    path = "/departments",
+    tag = "departments",
+    request_body = CreateDepartmentRequest,
+    responses(
+        (status = 201, description = "Department created successfully", body = Department),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `departments:write` scope", body = ApiError),
+        (status = 409, description = "Department code already exists", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        (status = 422, description = "Validation error", body = ApiError, examples(
+            ("validation_error" = (summary = "Fields failed validation", value = json!({
+                "error": {
+                    "code": "validation_error",
+                    "message": "Invalid input data",
+                    "details": [
+                        {"field": "code", "code": "invalid_department_code", "message": "must be 2-10 uppercase letters"}
+                    ],
+                    "request_id": "9b2f6a4e-3c1d-4f8a-b7e5-2d6c8a1f4e90"
+                }
+            })))
+        )),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["departments:write"])
+    )
+)]
+pub(crate) async fn create_department(
+    State(state): State<AppState>,
+    ApiJson(payload): ApiJson<CreateDepartmentRequest>,
+) -> Result<(StatusCode, Json<Department>), AppError> {
+    payload.validate()?;
+
+    let code = payload.code.clone();
+    let department = Department {
+        id: Uuid::new_v4(),
+        name: payload.name,
+        code: payload.code,
+    };
+    let department = state
+        .departments
+        .create(department)
+        .await
+        .map_err(|err| match err {
+            RepoError::Conflict(field) => AppError::Duplicate {
+                field,
+                message: format!("A department with code {code} already exists"),
+            },
+            other => other.into(),
+        })?;
+
+    Ok((StatusCode::CREATED, Json(department)))
+}
+
+/// Get department
+///
+/// Retrieve a specific department by ID
+#[utoipa::path(
+    get,
+    path = "/departments/{id}",
+    tag = "departments",
+    params(
+        ("id" = Uuid, Path, description = "Department ID")
+    ),
+    responses(
+        (status = 200, description = "Department details", body = Department),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `departments:read` scope", body = ApiError),
+        (status = 404, description = "Department not found", body = ApiError, examples(
+            ("not_found" = (summary = "No department with this ID", value = json!({
+                "error": {
+                    "code": "not_found",
+                    "message": "Department 550e8400-e29b-41d4-a716-446655440000 not found",
+                    "request_id": "9b2f6a4e-3c1d-4f8a-b7e5-2d6c8a1f4e90"
+                }
+            })))
+        )),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["departments:read"])
+    )
+)]
+pub(crate) async fn get_department(
+    State(state): State<AppState>,
+    ApiPath(id): ApiPath<Uuid>,
+) -> Result<Json<Department>, AppError> {
+    state
+        .departments
+        .get(id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound(format!("Department {id} not found")))
+}
@@ -0,0 +1,1434 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::convert::Infallible;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{OriginalUri, State},
+    http::{
+        header::{ACCEPT, CONTENT_TYPE, ETAG, IF_MATCH, IF_NONE_MATCH, VARY},
+        HeaderMap, HeaderName, HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use futures::stream::{self, StreamExt};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::error::AppError;
+use crate::handlers::extract::{
+    media_type, parse_json_body, require_media_type, ApiJson, ApiPath, ApiQuery, JSON_PATCH,
+    MERGE_PATCH,
+};
+use crate::handlers::negotiate::ResponseFormat;
+use crate::handlers::{department_index, find_employee};
+use crate::idempotency::{IDEMPOTENCY_KEY, IDEMPOTENT_REPLAYED};
+use crate::middleware::auth::Claims;
+use crate::models::common::{
+    EmployeeNotFound, InvalidEmployee, PaginatedResponse, SortOrder, ValidationError,
+};
+use crate::models::employees::{
+    CreateEmployeeRequest, DeleteParams, DryRunParams, DryRunResult, Employee, EmployeeCount,
+    EmployeeWithDepartment, ExpandParams, ListEmployeesQuery, PageParams, PatchEmployeeRequest,
+    SearchParams, SortField, StatusTransition, TerminateEmployeeRequest,
+};
+use crate::repository::memory::InMemoryRepository;
+use crate::repository::EmployeeRepository;
+use crate::repository::RepoError;
+use crate::state::AppState;
+use crate::utils::filter::{Comparison, EmployeeFilter};
+use crate::utils::pagination::{
+    cursor_page, insert_pagination_headers, offset_page, pagination_links,
+};
+use crate::utils::validation::EMPLOYEE_ID_PATTERN;
+
+/// List employees
+///
+/// Retrieve a paginated list of employees with optional filtering. Offset mode (`page`/`per_page`)
+/// is the default. Supplying `cursor` or `limit` switches to cursor mode, which orders by id and
+/// reports `has_more`, `next_cursor` and `prev_cursor` instead of page numbers; `page`/`per_page`
+/// and sorting are then ignored. `expand=department` inlines each employee's department.
+/// `hire_date` and `salary` also take comparison filters written `field[op]=value`, where `op` is
+/// one of `eq`, `gt`, `gte`, `lt` or `lte`; `hire_date[gte]=2020-01-01&hire_date[lte]=2020-12-31`
+/// selects 2020 hires. Salary filters need the admin role or the `employees:read-sensitive` scope,
+/// and never match employees without a salary. A `Link` header (RFC 8288) carries `first`, `prev`,
+/// `next` and `last` page URLs that keep the other query parameters; in cursor mode it carries
+/// `prev` and `next` cursor URLs.
+#[utoipa::path(
+    get,
+    path = "/employees",
+    tag = "employees",
+    params(ListEmployeesQuery),
+    responses(
+        (status = 200, description = "List of employees, as XML when the `Accept` header prefers it",
+            body = PaginatedEmployees, content_type = ["application/json", "application/xml"],
+            headers(
+                ("Link" = String, description = "Pagination links with `first`, `prev`, `next` and `last` relations"),
+                ("X-Total-Count" = u64, description = "Number of employees matching the filters")
+            )),
+        (status = 400, description = "Unparseable query parameter or comparison filter, invalid cursor, page, page size, sort field, expand value, field name, status or hire date range", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope, or filters on salary without access to it", body = ApiError),
+        (status = 406, description = "`Accept` allows neither JSON nor XML", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn list_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    OriginalUri(uri): OriginalUri,
+    ApiQuery(params): ApiQuery<ListEmployeesQuery>,
+    ApiQuery(query): ApiQuery<Vec<(String, String)>>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let format = ResponseFormat::negotiate(&headers)?;
+    let sort_field = SortField::parse(params.sort_by.as_deref())?;
+    let order = params.order.unwrap_or_default();
+    let expand_department = parse_expand(params.expand.as_deref())?;
+    let fields = parse_fields(params.fields.as_deref())?;
+    let departments = if expand_department || params.department.is_some() {
+        department_index(&state).await?
+    } else {
+        HashMap::new()
+    };
+    let comparisons = Comparison::parse_all(&query, &claims)?;
+    let filter = EmployeeFilter::from_params(&params, &departments)?.with_comparisons(comparisons);
+
+    let cursor_mode = params.cursor.is_some() || params.limit.is_some();
+    let employees = state.employees.list().await?;
+    let page = {
+        let mut matching: Vec<&Employee> = employees.iter().filter(|e| filter.matches(e)).collect();
+
+        if cursor_mode {
+            cursor_page(
+                matching,
+                params.cursor.as_deref(),
+                params.limit,
+                state.page_limits,
+            )?
+        } else {
+            sort_employees(&mut matching, sort_field, order);
+            offset_page(matching, params.page, params.per_page, state.page_limits)?
+        }
+    };
+
+    let links = pagination_links(&uri, &page.pagination);
+    let total = page.pagination.total();
+    let departments = expand_department.then_some(&departments);
+    let data = page
+        .data
+        .into_iter()
+        .map(|employee| state.employee_view(employee.redacted_for(&claims), departments));
+    let mut response = match &fields {
+        None => format.render(
+            "employees",
+            &PaginatedResponse {
+                data: data.collect(),
+                pagination: page.pagination,
+            },
+        )?,
+        Some(fields) => format.render(
+            "employees",
+            &PaginatedResponse {
+                data: data
+                    .map(|employee| project_fields(&employee, fields))
+                    .collect::<Result<Vec<_>, _>>()?,
+                pagination: page.pagination,
+            },
+        )?,
+    };
+
+    insert_pagination_headers(response.headers_mut(), total, &links);
+    response
+        .headers_mut()
+        .insert(VARY, HeaderValue::from_static("accept"));
+    Ok(response)
+}
+
+/// List direct reports
+///
+/// Employees whose `manager_id` is this employee, ordered by last name. Paginated like `GET
+/// /employees` in offset mode.
+#[utoipa::path(
+    get,
+    path = "/employees/{id}/reports",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID of the manager"),
+        ("page" = Option<u32>, Query, description = "Page number (default `1`)", minimum = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", minimum = 1)
+    ),
+    responses(
+        (status = 200, description = "Direct reports", body = PaginatedEmployees,
+            headers(
+                ("Link" = String, description = "Pagination links with `first`, `prev`, `next` and `last` relations"),
+                ("X-Total-Count" = u64, description = "Number of direct reports")
+            )),
+        (status = 400, description = "Invalid page or page size", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn list_reports(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+    OriginalUri(uri): OriginalUri,
+    ApiQuery(params): ApiQuery<PageParams>,
+) -> Result<Response, AppError> {
+    find_employee(&state, id).await?;
+
+    let employees = state.employees.list().await?;
+    let page = {
+        let mut reports: Vec<&Employee> = employees
+            .iter()
+            .filter(|employee| employee.manager_id == Some(id))
+            .collect();
+        sort_employees(&mut reports, SortField::LastName, SortOrder::default());
+        offset_page(reports, params.page, params.per_page, state.page_limits)?
+    };
+
+    let links = pagination_links(&uri, &page.pagination);
+    let total = page.pagination.total();
+    let mut response = Json(PaginatedResponse {
+        data: page
+            .data
+            .into_iter()
+            .map(|employee| state.employee_view(employee.redacted_for(&claims), None))
+            .collect(),
+        pagination: page.pagination,
+    })
+    .into_response();
+    insert_pagination_headers(response.headers_mut(), total, &links);
+    Ok(response)
+}
+
+// Kept for clients written before pagination. The handler is #[deprecated] so utoipa marks the
+// operation deprecated; LEGACY_LIST adds the runtime headers.
+/// List all employees (deprecated)
+///
+/// Every employee in one unpaginated array, ordered by last name. Deprecated in favour of `GET
+/// /employees` and `GET /employees.ndjson`, and removed after the `Sunset` date. Responses carry
+/// `Deprecation`, `Sunset` and a `Link` with `rel="deprecation"`.
+#[utoipa::path(
+    get,
+    path = "/employees/all",
+    tag = "employees",
+    responses(
+        (status = 200, description = "All employees", body = [Employee],
+            headers(
+                ("Deprecation" = String, description = "Always `true`"),
+                ("Sunset" = String, description = "HTTP-date after which the endpoint may be removed"),
+                ("Link" = String, description = "Migration notes, with `rel=\"deprecation\"`")
+            )),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+#[deprecated(note = "use list_employees or stream_employees_ndjson")]
+pub(crate) async fn list_all_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<Vec<Employee>>, AppError> {
+    let employees = state.employees.list().await?;
+    let mut matching: Vec<&Employee> = employees.iter().collect();
+    sort_employees(&mut matching, SortField::LastName, SortOrder::default());
+    Ok(Json(
+        matching
+            .into_iter()
+            .map(|employee| employee.clone().redacted_for(&claims))
+            .collect(),
+    ))
+}
+
+/// Search employees
+///
+/// Case-insensitive substring search across `first_name`, `last_name`, `email` and `employee_id`,
+/// ordered by last name. Paginated like `GET /employees` in offset mode.
+#[utoipa::path(
+    get,
+    path = "/employees/search",
+    tag = "employees",
+    params(
+        ("q" = String, Query, description = "Text to look for; must not be blank"),
+        ("page" = Option<u32>, Query, description = "Page number (default `1`)", minimum = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", minimum = 1)
+    ),
+    responses(
+        (status = 200, description = "Matching employees", body = PaginatedEmployees,
+            headers(
+                ("Link" = String, description = "Pagination links with `first`, `prev`, `next` and `last` relations"),
+                ("X-Total-Count" = u64, description = "Number of matching employees")
+            )),
+        (status = 400, description = "Missing or blank `q`, or invalid page or page size", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn search_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    OriginalUri(uri): OriginalUri,
+    ApiQuery(params): ApiQuery<SearchParams>,
+) -> Result<Response, AppError> {
+    let needle = params
+        .q
+        .as_deref()
+        .map(str::trim)
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| AppError::BadRequest("q must not be empty".to_string()))?
+        .to_lowercase();
+
+    let employees = state.employees.list().await?;
+    let page = {
+        let mut matching: Vec<&Employee> = employees
+            .iter()
+            .filter(|employee| {
+                [
+                    &employee.first_name,
+                    &employee.last_name,
+                    &employee.email,
+                    &employee.employee_id,
+                ]
+                .iter()
+                .any(|value| value.to_lowercase().contains(&needle))
+            })
+            .collect();
+        sort_employees(&mut matching, SortField::LastName, SortOrder::default());
+        offset_page(matching, params.page, params.per_page, state.page_limits)?
+    };
+
+    let links = pagination_links(&uri, &page.pagination);
+    let total = page.pagination.total();
+    let mut response = Json(PaginatedResponse {
+        data: page
+            .data
+            .into_iter()
+            .map(|employee| state.employee_view(employee.redacted_for(&claims), None))
+            .collect(),
+        pagination: page.pagination,
+    })
+    .into_response();
+    insert_pagination_headers(response.headers_mut(), total, &links);
+    Ok(response)
+}
+
+pub(crate) fn sort_employees(employees: &mut [&Employee], field: SortField, order: SortOrder) {
+    employees.sort_by(|a, b| {
+        let ordering = match field {
+            SortField::LastName => a.last_name.cmp(&b.last_name),
+            SortField::HireDate => a.hire_date.cmp(&b.hire_date),
+            SortField::CreatedAt => a.created_at.cmp(&b.created_at),
+        };
+        let ordering = match order {
+            SortOrder::Asc => ordering,
+            SortOrder::Desc => ordering.reverse(),
+        };
+        // HashMap iteration order is arbitrary, so break ties on id to keep pages stable
+        ordering.then_with(|| a.id.cmp(&b.id))
+    });
+}
+
+/// Count employees
+///
+/// Count the employees matching the filters, without paging, sorting or rendering them. Takes the
+/// same filters as `GET /employees`, including the `hire_date[op]` and `salary[op]` comparisons,
+/// and returns the `total` that list would report.
+#[utoipa::path(
+    get,
+    path = "/employees/count",
+    tag = "employees",
+    params(
+        ("department" = Option<String>, Query, description = "Filter by department code or name (case-insensitive)"),
+        ("status" = Option<String>, Query, description = "Filter by employment status; comma-separated for several, e.g. `active,inactive`"),
+        ("hired_after" = Option<NaiveDate>, Query, description = "Only employees hired on or after this date", format = Date),
+        ("hired_before" = Option<NaiveDate>, Query, description = "Only employees hired on or before this date", format = Date)
+    ),
+    responses(
+        (status = 200, description = "Number of matching employees", body = EmployeeCount),
+        (status = 400, description = "Unparseable comparison filter, invalid status or hire date range", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope, or filters on salary without access to it", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn count_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiQuery(params): ApiQuery<ListEmployeesQuery>,
+    ApiQuery(query): ApiQuery<Vec<(String, String)>>,
+) -> Result<Json<EmployeeCount>, AppError> {
+    let departments = if params.department.is_some() {
+        department_index(&state).await?
+    } else {
+        HashMap::new()
+    };
+    let comparisons = Comparison::parse_all(&query, &claims)?;
+    let filter = EmployeeFilter::from_params(&params, &departments)?.with_comparisons(comparisons);
+
+    let employees = state.employees.list().await?;
+    let count = employees.iter().filter(|e| filter.matches(e)).count() as u64;
+    Ok(Json(EmployeeCount { count }))
+}
+
+/// Stream employees as NDJSON
+///
+/// Stream every employee matching the filters as newline-delimited JSON, one `Employee` object per
+/// line, for clients such as `jq` that process records as they arrive. Takes the same filters and
+/// sorting as `GET /employees`, including the `hire_date[op]` and `salary[op]` comparisons, but is
+/// never paginated. `salary` and `currency` are omitted unless the caller is an admin or has
+/// `employees:read-sensitive`.
+#[utoipa::path(
+    get,
+    path = "/employees.ndjson",
+    tag = "employees",
+    params(
+        ("sort_by" = Option<String>, Query, description = "Sort field: `last_name`, `hire_date` or `created_at` (default `last_name`)"),
+        ("order" = Option<SortOrder>, Query, description = "Sort direction"),
+        ("department" = Option<String>, Query, description = "Filter by department code or name (case-insensitive)"),
+        ("status" = Option<String>, Query, description = "Filter by employment status; comma-separated for several, e.g. `active,inactive`"),
+        ("hired_after" = Option<NaiveDate>, Query, description = "Only employees hired on or after this date", format = Date),
+        ("hired_before" = Option<NaiveDate>, Query, description = "Only employees hired on or before this date", format = Date)
+    ),
+    responses(
+        (status = 200, description = "One JSON-encoded employee per line", body = Employee, content_type = "application/x-ndjson"),
+        (status = 400, description = "Unparseable comparison filter, invalid sort field, status or hire date range", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope, or filters on salary without access to it", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn stream_employees_ndjson(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiQuery(params): ApiQuery<ListEmployeesQuery>,
+    ApiQuery(query): ApiQuery<Vec<(String, String)>>,
+) -> Result<Response, AppError> {
+    let sort_field = SortField::parse(params.sort_by.as_deref())?;
+    let order = params.order.unwrap_or_default();
+    let departments = if params.department.is_some() {
+        department_index(&state).await?
+    } else {
+        HashMap::new()
+    };
+    let comparisons = Comparison::parse_all(&query, &claims)?;
+    let filter = EmployeeFilter::from_params(&params, &departments)?.with_comparisons(comparisons);
+
+    let employees = state.employees.list().await?;
+    let mut matching: Vec<&Employee> = employees.iter().filter(|e| filter.matches(e)).collect();
+    sort_employees(&mut matching, sort_field, order);
+    // Owned copies so the stream doesn't borrow the snapshot while a slow client drains it
+    let employees: Vec<Employee> = matching.into_iter().cloned().collect();
+
+    let lines = stream::iter(employees).map(move |employee| {
+        let mut line = serde_json::to_vec(&employee.redacted_for(&claims))
+            .expect("employees always serialize");
+        line.push(b'\n');
+        Ok::<_, Infallible>(Bytes::from(line))
+    });
+    Ok((
+        [(CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(lines),
+    )
+        .into_response())
+}
+
+/// Create employee
+///
+/// Create a new employee. Send an `Idempotency-Key` to make retries safe: a repeat of a successful
+/// request with the same key and body returns the original response (marked `Idempotent-Replayed:
+/// true`) instead of creating another employee. Keys are remembered per caller for 24 hours by
+/// default. With `dry_run=true` the request is validated and checked for conflicts as usual, but
+/// nothing is stored and the would-be employee is returned with `200`; `Idempotency-Key` is
+/// ignored. Reusing a key with a different body is a `422`.
+#[utoipa::path(
+    post,
+    path = "/employees",
+    tag = "employees",
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Client-chosen unique key for this create, at most 255 characters"),
+        ("dry_run" = Option<bool>, Query, description = "Validate the request without creating the employee (default `false`)")
+    ),
+    request_body = CreateEmployeeRequest,
+    responses(
+        (status = 200, description = "Dry run passed; the employee that would be created", body = DryRunResult),
+        (status = 201, description = "Employee created successfully", body = EmployeeWithDepartment,
+            headers(
+                ("Location" = String, description = "URL of the new employee, prefixed with PUBLIC_BASE_URL when set"),
+                ("Idempotent-Replayed" = bool, description = "Present and `true` when replaying a stored response")
+            )),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        (status = 400, description = "Bad request or malformed Idempotency-Key", body = ApiError),
+        (status = 409, description = "Employee ID or email (compared ignoring case) already exists", body = ApiError, examples(
+            ("duplicate" = (summary = "Email already in use", value = json!({
+                "error": {
+                    "code": "conflict",
+                    "message": "An employee with this email already exists",
+                    "field": "email",
+                    "request_id": "9b2f6a4e-3c1d-4f8a-b7e5-2d6c8a1f4e90"
+                }
+            })))
+        )),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        InvalidEmployee,
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn create_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiQuery(params): ApiQuery<DryRunParams>,
+    headers: HeaderMap,
+    ApiJson(payload): ApiJson<CreateEmployeeRequest>,
+) -> Result<Response, AppError> {
+    payload.validate()?;
+    if params.dry_run {
+        ensure_department_exists(&state, payload.department_id).await?;
+        ensure_valid_manager(state.employees.as_ref(), None, payload.manager_id).await?;
+        let employee = dry_run_store(&state)
+            .await?
+            .create(Employee::from_request(payload, state.clock.now()))
+            .await?;
+        return Ok(dry_run_response(&state, employee));
+    }
+
+    // Scoped to the caller so two clients can't collide on (or probe) each other's keys
+    let idempotency = match headers.get(IDEMPOTENCY_KEY) {
+        Some(value) => {
+            let key = value
+                .to_str()
+                .ok()
+                .filter(|key| !key.is_empty() && key.len() <= 255)
+                .ok_or_else(|| {
+                    AppError::BadRequest(
+                        "Idempotency-Key must be 1-255 visible ASCII characters".to_string(),
+                    )
+                })?;
+            let fingerprint = serde_json::to_string(&payload).map_err(|err| {
+                AppError::Internal(format!("Failed to fingerprint request: {err}"))
+            })?;
+            Some((format!("{}:{key}", claims.sub), fingerprint))
+        }
+        None => None,
+    };
+    if let Some((key, fingerprint)) = &idempotency {
+        if let Some(employee) = state.idempotency.replay(key, fingerprint)? {
+            return Ok((
+                StatusCode::CREATED,
+                [state.employee_location(employee.id)],
+                [(IDEMPOTENT_REPLAYED, "true")],
+                Json(state.employee_view(employee, None)),
+            )
+                .into_response());
+        }
+    }
+
+    ensure_department_exists(&state, payload.department_id).await?;
+    ensure_valid_manager(state.employees.as_ref(), None, payload.manager_id).await?;
+    let employee = state
+        .employees
+        .create(Employee::from_request(payload, state.clock.now()))
+        .await?;
+    state.record_created(&claims.sub, &employee);
+
+    // Only successes are stored; a failed attempt can be corrected and retried with the key.
+    // Two concurrent first attempts both run, and the unique employee_id/email lets one win.
+    if let Some((key, fingerprint)) = idempotency {
+        state.idempotency.store(key, fingerprint, employee.clone());
+    }
+    Ok((
+        StatusCode::CREATED,
+        [state.employee_location(employee.id)],
+        Json(state.employee_view(employee, None)),
+    )
+        .into_response())
+}
+
+// Dry runs write to a copy of the store, so its uniqueness checks still apply
+pub(crate) async fn dry_run_store(state: &AppState) -> Result<InMemoryRepository, AppError> {
+    Ok(InMemoryRepository::new(
+        state.employees.list().await?,
+        state.clock.clone(),
+    ))
+}
+
+pub(crate) fn dry_run_response(state: &AppState, employee: Employee) -> Response {
+    Json(DryRunResult {
+        dry_run: true,
+        employee: state.employee_view(employee, None),
+    })
+    .into_response()
+}
+
+/// Get employee
+///
+/// Retrieve a specific employee by ID. `expand=department` inlines the referenced department.
+/// `fields` trims the response to the listed properties; `id` is always included. Responses carry a
+/// weak `ETag`; send it back in `If-None-Match` to get `304 Not Modified` while the employee is
+/// unchanged. `HEAD` answers with the same status and headers and no body.
+#[utoipa::path(
+    get,
+    path = "/employees/{id}",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID"),
+        ("expand" = Option<String>, Query, description = "Related data to embed; only `department` is supported"),
+        ("fields" = Option<String>, Query, description = "Comma-separated properties to return, e.g. `email,first_name`"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag from a previous response")
+    ),
+    responses(
+        (status = 200, description = "Employee details, as XML when the `Accept` header prefers it",
+            body = EmployeeWithDepartment, content_type = ["application/json", "application/xml"],
+            headers(("ETag" = String, description = "Weak validator for this representation"))),
+        (status = 304, description = "Employee unchanged since the supplied ETag",
+            headers(("ETag" = String, description = "Weak validator for this representation"))),
+        (status = 400, description = "Unknown expand value or field name", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 406, description = "`Accept` allows neither JSON nor XML", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn get_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiQuery(params): ApiQuery<ExpandParams>,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    let format = ResponseFormat::negotiate(&headers)?;
+    let expand_department = parse_expand(params.expand.as_deref())?;
+    let fields = parse_fields(params.fields.as_deref())?;
+
+    let employee = find_employee(&state, id).await?.redacted_for(&claims);
+
+    let mut representation = String::new();
+    if format == ResponseFormat::Xml {
+        representation.push_str("-xml");
+    }
+    if !claims.can_read_sensitive() {
+        representation.push_str("-redacted");
+    }
+    if expand_department {
+        representation.push_str("-department");
+    }
+    if let Some(fields) = &fields {
+        representation.push_str("-fields.");
+        representation.push_str(&fields.iter().copied().collect::<Vec<_>>().join("."));
+    }
+    let etag = employee_etag(&employee, &representation);
+    let etag_header = [(ETAG, etag.clone()), (VARY, ACCEPT.to_string())];
+    if if_none_match(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, etag_header).into_response());
+    }
+
+    let departments = if expand_department {
+        Some(department_index(&state).await?)
+    } else {
+        None
+    };
+    let employee = state.employee_view(employee, departments.as_ref());
+    let body = match &fields {
+        None => format.render("employee", &employee)?,
+        Some(fields) => format.render("employee", &project_fields(&employee, fields)?)?,
+    };
+    Ok((etag_header, body).into_response())
+}
+
+// The version identifies the record state; each representation (expanded, projected)
+// gets its own suffix so If-None-Match never confuses them
+pub(crate) fn employee_etag(employee: &Employee, representation: &str) -> String {
+    format!(
+        "W/\"{}-{}{representation}\"",
+        employee.id.simple(),
+        employee.version
+    )
+}
+
+pub(crate) fn with_etag(state: &AppState, employee: Employee) -> Response {
+    let etag = employee_etag(&employee, "");
+    ([(ETAG, etag)], Json(state.employee_view(employee, None))).into_response()
+}
+
+// Tags listed in a conditional header; both If-None-Match and (for our weak tags) If-Match
+// compare without the W/ prefix
+pub(crate) fn listed_etags<'a>(headers: &'a HeaderMap, name: &HeaderName) -> Option<Vec<&'a str>> {
+    let values: Vec<&str> = headers
+        .get_all(name)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .filter(|tag| !tag.is_empty())
+        .collect();
+    (!values.is_empty()).then_some(values)
+}
+
+pub(crate) fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    let current = etag.trim_start_matches("W/");
+    listed_etags(headers, &IF_NONE_MATCH)
+        .is_some_and(|tags| tags.iter().any(|tag| *tag == "*" || *tag == current))
+}
+
+// Rejects a write unless If-Match names the current version of `employee`; a missing
+// header is allowed only when REQUIRE_IF_MATCH=false
+pub(crate) fn check_if_match(
+    state: &AppState,
+    headers: &HeaderMap,
+    employee: &Employee,
+) -> Result<(), AppError> {
+    let Some(tags) = listed_etags(headers, &IF_MATCH) else {
+        return if state.require_if_match {
+            Err(AppError::PreconditionRequired)
+        } else {
+            Ok(())
+        };
+    };
+    // Only the id and version matter, so a tag from any representation will do
+    let current = employee_etag(employee, "");
+    let current = current.trim_start_matches("W/");
+    let prefix = format!("{}-", current.trim_end_matches('"'));
+    let matches = tags
+        .iter()
+        .any(|tag| *tag == "*" || *tag == current || tag.starts_with(&prefix));
+    if matches {
+        Ok(())
+    } else {
+        Err(stale_etag(employee.id))
+    }
+}
+
+pub(crate) fn stale_etag(id: Uuid) -> AppError {
+    AppError::PreconditionFailed(format!(
+        "Employee {id} has changed; fetch it again and retry with the new ETag"
+    ))
+}
+
+// A write that lost the race since If-Match was checked is a failed precondition too
+pub(crate) fn conditional_write_error(id: Uuid) -> impl FnOnce(RepoError) -> AppError {
+    move |err| match err {
+        RepoError::VersionMismatch(_) => stale_etag(id),
+        other => other.into(),
+    }
+}
+
+/// Update employee
+///
+/// Replace an existing employee. The ID in the path identifies the record; employment status and
+/// creation time are preserved. `If-Match` must carry the employee's current ETag so concurrent
+/// edits are not lost. With `dry_run=true` every check still runs but nothing is stored; the body
+/// is the would-be employee with `dry_run: true` and no ETag is sent. A `manager_id` that does not
+/// exist or would create a reporting cycle is a `422`.
+#[utoipa::path(
+    put,
+    path = "/employees/{id}",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID"),
+        ("If-Match" = String, Header, description = "ETag of the version being replaced"),
+        ("dry_run" = Option<bool>, Query, description = "Validate the request without updating the employee (default `false`)")
+    ),
+    request_body = CreateEmployeeRequest,
+    responses(
+        (status = 200, description = "Employee updated successfully, or the would-be result of a dry run", body = EmployeeWithDepartment,
+            headers(("ETag" = String, description = "Validator for the updated employee; not sent for a dry run"))),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 409, description = "Employee ID or email (compared ignoring case) already exists", body = ApiError),
+        (status = 412, description = "Employee changed since the supplied ETag", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        InvalidEmployee,
+        (status = 428, description = "If-Match header is required", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn update_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiQuery(params): ApiQuery<DryRunParams>,
+    headers: HeaderMap,
+    ApiJson(payload): ApiJson<CreateEmployeeRequest>,
+) -> Result<Response, AppError> {
+    // 404, 428 and 412 come before anything about the body's references
+    let before = find_employee(&state, id).await?;
+    check_if_match(&state, &headers, &before)?;
+
+    payload.validate()?;
+    ensure_department_exists(&state, payload.department_id).await?;
+    ensure_valid_manager(state.employees.as_ref(), Some(id), payload.manager_id).await?;
+
+    let mut employee = before.clone();
+    employee.replace_with(payload);
+    if params.dry_run {
+        let employee = dry_run_store(&state)
+            .await?
+            .update(employee)
+            .await
+            .map_err(conditional_write_error(id))?;
+        return Ok(dry_run_response(&state, employee));
+    }
+
+    let employee = state
+        .employees
+        .update(employee)
+        .await
+        .map_err(conditional_write_error(id))?;
+    state.record_updated(&claims.sub, &before, &employee);
+    Ok(with_etag(&state, employee))
+}
+
+/// Create or replace employee by employee ID
+///
+/// Provision an employee by its business key. If no employee has this `employee_id` one is created
+/// (`201` with a `Location` header); otherwise that employee is replaced like `PUT /employees/{id}`
+/// (`200`). Repeating the same request leaves the same state, so upstream HR systems can resend it
+/// safely. The body's `employee_id` must equal the path. `If-Match` is optional here; when sent it
+/// must match the existing employee. A `manager_id` that does not exist or would create a reporting
+/// cycle is a `422`.
+#[utoipa::path(
+    put,
+    path = "/employees/by-employee-id/{employee_id}",
+    tag = "employees",
+    params(
+        ("employee_id" = String, Path, description = "Employee ID; must match `^[A-Z]{3}[0-9]{3}$`"),
+        ("If-Match" = Option<String>, Header, description = "ETag the existing employee must still have")
+    ),
+    request_body = CreateEmployeeRequest,
+    responses(
+        (status = 200, description = "Existing employee replaced", body = EmployeeWithDepartment,
+            headers(("ETag" = String, description = "Validator for the updated employee"))),
+        (status = 201, description = "Employee created", body = EmployeeWithDepartment,
+            headers(
+                ("Location" = String, description = "URL of the new employee, prefixed with PUBLIC_BASE_URL when set"),
+                ("ETag" = String, description = "Validator for the new employee")
+            )),
+        (status = 400, description = "Path is not a valid employee ID or does not match the body", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        (status = 409, description = "Email (compared ignoring case) already exists", body = ApiError),
+        (status = 412, description = "Employee changed since the supplied ETag", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        InvalidEmployee,
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn upsert_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(employee_id): ApiPath<String>,
+    headers: HeaderMap,
+    ApiJson(payload): ApiJson<CreateEmployeeRequest>,
+) -> Result<Response, AppError> {
+    if !EMPLOYEE_ID_PATTERN.is_match(&employee_id) {
+        return Err(AppError::BadRequest(format!(
+            "{employee_id} is not a valid employee ID; expected three uppercase letters and three digits"
+        )));
+    }
+    if payload.employee_id != employee_id {
+        return Err(AppError::BadRequest(
+            "employee_id in the body must match the path".to_string(),
+        ));
+    }
+    payload.validate()?;
+    ensure_department_exists(&state, payload.department_id).await?;
+
+    let existing = state
+        .employees
+        .list()
+        .await?
+        .into_iter()
+        .find(|employee| employee.employee_id == employee_id);
+    let Some(before) = existing else {
+        ensure_valid_manager(state.employees.as_ref(), None, payload.manager_id).await?;
+        let employee = state
+            .employees
+            .create(Employee::from_request(payload, state.clock.now()))
+            .await?;
+        state.record_created(&claims.sub, &employee);
+        let (name, location) = state.employee_location(employee.id);
+        let mut response = with_etag(&state, employee);
+        *response.status_mut() = StatusCode::CREATED;
+        response.headers_mut().insert(name, location);
+        return Ok(response);
+    };
+
+    let id = before.id;
+    ensure_valid_manager(state.employees.as_ref(), Some(id), payload.manager_id).await?;
+    if headers.contains_key(IF_MATCH) {
+        check_if_match(&state, &headers, &before)?;
+    }
+    let mut employee = before.clone();
+    employee.replace_with(payload);
+
+    let employee = state
+        .employees
+        .update(employee)
+        .await
+        .map_err(conditional_write_error(id))?;
+    state.record_updated(&claims.sub, &before, &employee);
+    Ok(with_etag(&state, employee))
+}
+
+/// Partially update employee
+///
+/// Apply a JSON Merge Patch (RFC 7396) to an employee. Omitted fields are left unchanged. An
+/// explicit `null` clears `department_id`, `manager_id`, `position` or `salary`; required fields
+/// cannot be cleared, so `null` for one of them is a `422`. Sent as `application/json-patch+json`, the
+/// body is instead a JSON Patch (RFC 6902) array of operations. Paths must name one of the
+/// merge-patch fields; the patched employee is validated like an update, so removing a required
+/// field is a `422`, as is an operation that fails to apply. `If-Match` must carry the employee's current ETag.
+#[utoipa::path(
+    patch,
+    path = "/employees/{id}",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID"),
+        ("If-Match" = String, Header, description = "ETag of the version being patched")
+    ),
+    request_body(content = PatchEmployeeRequest, content_type = "application/merge-patch+json"),
+    responses(
+        (status = 200, description = "Employee updated successfully", body = EmployeeWithDepartment,
+            headers(("ETag" = String, description = "Validator for the updated employee"))),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 409, description = "Email (compared ignoring case) already exists", body = ApiError),
+        (status = 412, description = "Employee changed since the supplied ETag", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Body is neither a merge patch nor a JSON Patch", body = ApiError),
+        InvalidEmployee,
+        (status = 428, description = "If-Match header is required", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn patch_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Response, AppError> {
+    let (before, employee) = match media_type(&headers).as_deref() {
+        Some(JSON_PATCH) => {
+            let before = find_employee(&state, id).await?;
+            check_if_match(&state, &headers, &before)?;
+            let patched = apply_json_patch(&before, &body)?;
+            ensure_department_exists(&state, patched.department_id).await?;
+            ensure_valid_manager(state.employees.as_ref(), Some(id), patched.manager_id).await?;
+
+            let mut employee = before.clone();
+            employee.employee_id = patched.employee_id;
+            employee.first_name = patched.first_name;
+            employee.last_name = patched.last_name;
+            employee.email = patched.email;
+            employee.department_id = patched.department_id;
+            employee.manager_id = patched.manager_id;
+            employee.position = patched.position;
+            employee.salary = patched.salary;
+            employee.currency = patched.currency;
+            employee.hire_date = patched.hire_date;
+            (before, employee)
+        }
+        Some(MERGE_PATCH | "application/json") => {
+            let patch: PatchEmployeeRequest = parse_json_body(&body)?;
+            merge_patch(&state, id, &headers, patch).await?
+        }
+        _ => {
+            return Err(AppError::UnsupportedMediaType(format!(
+                "Expected {MERGE_PATCH} or {JSON_PATCH}"
+            )))
+        }
+    };
+
+    let employee = state
+        .employees
+        .update(employee)
+        .await
+        .map_err(conditional_write_error(id))?;
+    state.record_updated(&claims.sub, &before, &employee);
+    Ok(with_etag(&state, employee))
+}
+
+// Returns the stored employee and the patched copy, ready to be written
+pub(crate) async fn merge_patch(
+    state: &AppState,
+    id: Uuid,
+    headers: &HeaderMap,
+    patch: PatchEmployeeRequest,
+) -> Result<(Employee, Employee), AppError> {
+    // As with PUT, 404, 428 and 412 come before anything about the body
+    let before = find_employee(state, id).await?;
+    check_if_match(state, headers, &before)?;
+
+    let nulls = patch.null_required_fields();
+    if !nulls.is_empty() {
+        return Err(AppError::Validation(nulls));
+    }
+
+    patch.validate()?;
+    ensure_department_exists(state, patch.department_id.flatten()).await?;
+    ensure_valid_manager(
+        state.employees.as_ref(),
+        Some(id),
+        patch.manager_id.flatten(),
+    )
+    .await?;
+
+    let mut employee = before.clone();
+    if let Some(Some(employee_id)) = patch.employee_id {
+        employee.employee_id = employee_id;
+    }
+    if let Some(Some(first_name)) = patch.first_name {
+        employee.first_name = first_name;
+    }
+    if let Some(Some(last_name)) = patch.last_name {
+        employee.last_name = last_name;
+    }
+    if let Some(Some(email)) = patch.email {
+        employee.email = email;
+    }
+    if let Some(department_id) = patch.department_id {
+        employee.department_id = department_id;
+    }
+    if let Some(manager_id) = patch.manager_id {
+        employee.manager_id = manager_id;
+    }
+    if let Some(position) = patch.position {
+        employee.position = position;
+    }
+    if let Some(salary) = patch.salary {
+        employee.salary = salary;
+    }
+    if let Some(Some(currency)) = patch.currency {
+        employee.currency = currency;
+    }
+    if let Some(Some(hire_date)) = patch.hire_date {
+        employee.hire_date = hire_date;
+    }
+    Ok((before, employee))
+}
+
+// Fields a JSON Patch may touch; the same ones a merge patch can change
+pub(crate) const JSON_PATCH_FIELDS: [&str; 10] = [
+    "employee_id",
+    "first_name",
+    "last_name",
+    "email",
+    "department_id",
+    "manager_id",
+    "position",
+    "salary",
+    "currency",
+    "hire_date",
+];
+
+// Runs the operations against the employee's request representation, so the result can be
+// checked with the same deserialization and validation rules as a full update
+pub(crate) fn apply_json_patch(
+    employee: &Employee,
+    body: &[u8],
+) -> Result<CreateEmployeeRequest, AppError> {
+    let patch: json_patch::Patch = parse_json_body(body)?;
+    let invalid = |path: &str, message: String| {
+        AppError::Validation(vec![ValidationError::new(path, "invalid_patch", message)])
+    };
+
+    for operation in &patch.0 {
+        let paths = match operation {
+            json_patch::PatchOperation::Add(op) => vec![&op.path],
+            json_patch::PatchOperation::Remove(op) => vec![&op.path],
+            json_patch::PatchOperation::Replace(op) => vec![&op.path],
+            json_patch::PatchOperation::Move(op) => vec![&op.from, &op.path],
+            json_patch::PatchOperation::Copy(op) => vec![&op.from, &op.path],
+            json_patch::PatchOperation::Test(op) => vec![&op.path],
+        };
+        for path in paths {
+            let field = path.strip_prefix('/').unwrap_or_default();
+            if !JSON_PATCH_FIELDS.contains(&field) {
+                return Err(invalid(
+                    path,
+                    format!("must be one of /{}", JSON_PATCH_FIELDS.join(", /")),
+                ));
+            }
+        }
+    }
+
+    let mut document = serde_json::to_value(employee_request(employee))
+        .map_err(|err| AppError::Internal(format!("Failed to serialize employee: {err}")))?;
+    json_patch::patch(&mut document, &patch.0).map_err(|err| invalid("body", err.to_string()))?;
+
+    let patched: CreateEmployeeRequest = serde_json::from_value(document)
+        .map_err(|err| invalid("body", format!("patched employee is invalid: {err}")))?;
+    patched.validate()?;
+    Ok(patched)
+}
+
+// The writable view of a stored employee
+pub(crate) fn employee_request(employee: &Employee) -> CreateEmployeeRequest {
+    CreateEmployeeRequest {
+        employee_id: employee.employee_id.clone(),
+        first_name: employee.first_name.clone(),
+        last_name: employee.last_name.clone(),
+        email: employee.email.clone(),
+        department_id: employee.department_id,
+        manager_id: employee.manager_id,
+        position: employee.position.clone(),
+        salary: employee.salary,
+        currency: employee.currency.clone(),
+        employment_status: None,
+        hire_date: employee.hire_date,
+    }
+}
+
+/// Delete employee
+///
+/// Remove an employee. With `soft=true` the record is kept and the employee is terminated instead,
+/// so it continues to appear in employee listings for reporting. As with
+/// `POST /employees/{id}/terminate`, that is a `409` when the employee is already terminated.
+#[utoipa::path(
+    delete,
+    path = "/employees/{id}",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID"),
+        ("soft" = Option<bool>, Query, description = "Terminate instead of removing the record (default `false`)")
+    ),
+    responses(
+        (status = 204, description = "Employee deleted or terminated"),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 409, description = "`soft=true` and the employee is already terminated", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn delete_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiQuery(params): ApiQuery<DeleteParams>,
+) -> Result<StatusCode, AppError> {
+    if params.soft {
+        transition_employee(&state, &claims.sub, id, StatusTransition::Terminate, None).await?;
+    } else {
+        state.employees.delete(id).await?;
+        state.discard_photo(id).await;
+        state.record_deleted(&claims.sub, id);
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Terminate employee
+///
+/// Move an active or inactive employee to `terminated`, optionally recording a reason
+#[utoipa::path(
+    post,
+    path = "/employees/{id}/terminate",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID")
+    ),
+    request_body(content = Option<TerminateEmployeeRequest>, description = "Optional termination details"),
+    responses(
+        (status = 200, description = "Employee terminated", body = EmployeeWithDepartment),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 409, description = "Employee is already terminated", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        InvalidEmployee,
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn terminate_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<EmployeeWithDepartment>, AppError> {
+    // The body may be left out, but one that is sent must be JSON
+    let reason = if body.is_empty() {
+        None
+    } else {
+        require_media_type(&headers, "application/json")?;
+        let request: TerminateEmployeeRequest = parse_json_body(&body)?;
+        request.validate()?;
+        request.reason
+    };
+    let employee =
+        transition_employee(&state, &claims.sub, id, StatusTransition::Terminate, reason).await?;
+    Ok(Json(state.employee_view(employee, None)))
+}
+
+/// Reactivate employee
+///
+/// Move an inactive or terminated employee back to `active`
+#[utoipa::path(
+    post,
+    path = "/employees/{id}/reactivate",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID")
+    ),
+    responses(
+        (status = 200, description = "Employee reactivated", body = EmployeeWithDepartment),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 409, description = "Employee is already active", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn reactivate_employee(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+) -> Result<Json<EmployeeWithDepartment>, AppError> {
+    let employee =
+        transition_employee(&state, &claims.sub, id, StatusTransition::Reactivate, None).await?;
+    Ok(Json(state.employee_view(employee, None)))
+}
+
+pub(crate) async fn transition_employee(
+    state: &AppState,
+    actor: &str,
+    id: Uuid,
+    transition: StatusTransition,
+    reason: Option<String>,
+) -> Result<Employee, AppError> {
+    let before = find_employee(state, id).await?;
+    let mut employee = before.clone();
+    employee.employment_status = employee.employment_status.apply(transition)?;
+    employee.termination_reason = reason;
+
+    let employee = state.employees.update(employee).await?;
+    state.record_updated(actor, &before, &employee);
+    Ok(employee)
+}
+
+// Everything an employee response can contain, including the expandable `department`
+pub(crate) const EMPLOYEE_FIELDS: [&str; 17] = [
+    "id",
+    "employee_id",
+    "first_name",
+    "last_name",
+    "email",
+    "department_id",
+    "manager_id",
+    "position",
+    "salary",
+    "currency",
+    "employment_status",
+    "termination_reason",
+    "hire_date",
+    "created_at",
+    "updated_at",
+    "version",
+    "department",
+];
+
+// Sparse fieldset from ?fields=; None means the full representation
+pub(crate) fn parse_fields(
+    value: Option<&str>,
+) -> Result<Option<BTreeSet<&'static str>>, AppError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let mut fields = BTreeSet::from(["id"]);
+    let mut unknown = Vec::new();
+    for name in value
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+    {
+        match EMPLOYEE_FIELDS.iter().find(|field| **field == name) {
+            Some(field) => {
+                fields.insert(*field);
+            }
+            None => unknown.push(name),
+        }
+    }
+    if unknown.is_empty() {
+        Ok(Some(fields))
+    } else {
+        Err(AppError::BadRequest(format!(
+            "Unknown fields: {}; expected any of {}",
+            unknown.join(", "),
+            EMPLOYEE_FIELDS.join(", ")
+        )))
+    }
+}
+
+pub(crate) fn project_fields(
+    employee: &EmployeeWithDepartment,
+    fields: &BTreeSet<&str>,
+) -> Result<serde_json::Value, AppError> {
+    let mut value = serde_json::to_value(employee)
+        .map_err(|err| AppError::Internal(format!("Failed to serialize employee: {err}")))?;
+    if let Some(object) = value.as_object_mut() {
+        object.retain(|key, _| key == "_links" || fields.contains(key.as_str()));
+    }
+    Ok(value)
+}
+
+// Returns whether the department should be embedded; `department` is the only expandable relation
+pub(crate) fn parse_expand(value: Option<&str>) -> Result<bool, AppError> {
+    let mut expand_department = false;
+    for relation in value.unwrap_or_default().split(',').map(str::trim) {
+        match relation {
+            "" => {}
+            "department" => expand_department = true,
+            other => {
+                return Err(AppError::BadRequest(format!(
+                    "Unknown expand value '{other}'; expected department"
+                )))
+            }
+        }
+    }
+    Ok(expand_department)
+}
+
+// Rejects dangling department references instead of storing them
+pub(crate) async fn ensure_department_exists(
+    state: &AppState,
+    department_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let Some(id) = department_id else {
+        return Ok(());
+    };
+    if state.departments.get(id).await?.is_some() {
+        Ok(())
+    } else {
+        Err(AppError::Validation(vec![ValidationError::new(
+            "department_id",
+            "unknown_department",
+            "must reference an existing department",
+        )]))
+    }
+}
+
+// `employee` is None for a new employee, which cannot be part of a cycle yet
+pub(crate) async fn ensure_valid_manager(
+    employees: &dyn EmployeeRepository,
+    employee: Option<Uuid>,
+    manager_id: Option<Uuid>,
+) -> Result<(), AppError> {
+    let Some(manager_id) = manager_id else {
+        return Ok(());
+    };
+    let invalid = |code: &str, message: &str| {
+        Err(AppError::Validation(vec![ValidationError::new(
+            "manager_id",
+            code,
+            message,
+        )]))
+    };
+    if employee == Some(manager_id) {
+        return invalid("self_manager", "must not be the employee itself");
+    }
+
+    let managers: HashMap<Uuid, Option<Uuid>> = employees
+        .list()
+        .await?
+        .into_iter()
+        .map(|e| (e.id, e.manager_id))
+        .collect();
+    if !managers.contains_key(&manager_id) {
+        return invalid("unknown_manager", "must reference an existing employee");
+    }
+    let Some(employee) = employee else {
+        return Ok(());
+    };
+    // Walk up the chain from the new manager; reaching the employee means a cycle.
+    // The visited set stops the walk on any cycle already in the data.
+    let mut visited = HashSet::new();
+    let mut current = Some(manager_id);
+    while let Some(id) = current.filter(|id| visited.insert(*id)) {
+        if id == employee {
+            return invalid("reporting_cycle", "would create a reporting cycle");
+        }
+        current = managers.get(&id).copied().flatten();
+    }
+    Ok(())
+}
+
+// Checks for rows of an import or bulk create, which are always new employees. Managers are
+// looked up in `employees`, the store the rows are written to, so a dry run's scratch copy sees
+// the rows created before this one.
+pub(crate) async fn ensure_references_exist(
+    state: &AppState,
+    employees: &dyn EmployeeRepository,
+    payload: &CreateEmployeeRequest,
+) -> Result<(), AppError> {
+    ensure_department_exists(state, payload.department_id).await?;
+    ensure_valid_manager(employees, None, payload.manager_id).await
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+    Extension,
+};
+use futures::stream::{self};
+use tokio::sync::broadcast;
+
+use crate::events::EmployeeEvent;
+use crate::middleware::auth::Claims;
+use crate::state::AppState;
+
+/// Stream employee changes
+///
+/// Server-Sent Events stream with one event per employee create, update or delete. The SSE `event`
+/// name is the action and `data` is an `EmployeeEvent`. A subscriber that falls too far behind
+/// receives a `lagged` event, whose data is the number of missed events, and should refetch what it
+/// displays. Comments are sent every 15 seconds to keep idle connections open through proxies.
+#[utoipa::path(
+    get,
+    path = "/employees/events",
+    tag = "employees",
+    responses(
+        (status = 200, description = "Event stream", body = EmployeeEvent, content_type = "text/event-stream"),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn employee_events(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Sse<impl futures::Stream<Item = Result<Event, axum::Error>>> {
+    // When the client disconnects axum drops the stream, and with it the receiver, so the
+    // channel stops buffering for it
+    let events = stream::unfold(state.events.subscribe(), move |mut receiver| {
+        let claims = claims.clone();
+        async move {
+            let event = match receiver.recv().await {
+                Ok(event) => {
+                    let event = EmployeeEvent {
+                        employee: event
+                            .employee
+                            .map(|employee| employee.redacted_for(&claims)),
+                        ..event
+                    };
+                    Event::default()
+                        .event(event.action.as_str())
+                        .json_data(&event)
+                }
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    Ok(Event::default().event("lagged").data(missed.to_string()))
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            };
+            Some((event, receiver))
+        }
+    });
+    Sse::new(events).keep_alive(KeepAlive::new().interval(Duration::from_secs(15)))
+}
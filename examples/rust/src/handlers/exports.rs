@@ -0,0 +1,269 @@
+use std::convert::Infallible;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::State,
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE, LOCATION},
+        HeaderValue, StatusCode,
+    },
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use chrono::{SecondsFormat, Utc};
+use futures::stream::{self, StreamExt};
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::extract::ApiPath;
+use crate::middleware::auth::Claims;
+use crate::models::employees::Employee;
+use crate::models::jobs::{Job, JobStatus};
+use crate::state::AppState;
+
+/// Export employees as CSV
+///
+/// Stream every employee as CSV, one row per employee ordered by `employee_id`. Dates use ISO 8601
+/// (`hire_date` as `YYYY-MM-DD`, timestamps as RFC 3339 in UTC); empty cells stand for absent
+/// values. `salary` and `currency` are left empty unless the caller is an admin or has
+/// `employees:read-sensitive`.
+#[utoipa::path(
+    get,
+    path = "/employees/export.csv",
+    tag = "employees",
+    responses(
+        (status = 200, description = "CSV document with a header row", body = String, content_type = "text/csv",
+            headers(("Content-Disposition" = String, description = "`attachment; filename=\"employees.csv\"`"))),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn export_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Response, AppError> {
+    // Snapshot so the store isn't held while a slow client drains the stream
+    let mut employees = state.employees.list().await?;
+    employees.sort_by(|a, b| a.employee_id.cmp(&b.employee_id));
+
+    let rows =
+        stream::once(async { csv_record(&CSV_COLUMNS) })
+            .chain(stream::iter(employees).map(move |employee| {
+                csv_record(&employee_csv_row(&employee.redacted_for(&claims)))
+            }))
+            .map(Ok::<_, Infallible>);
+
+    Ok((
+        [
+            (CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                CONTENT_DISPOSITION,
+                r#"attachment; filename="employees.csv""#,
+            ),
+        ],
+        Body::from_stream(rows),
+    )
+        .into_response())
+}
+
+pub(crate) const CSV_COLUMNS: [&str; 16] = [
+    "id",
+    "employee_id",
+    "first_name",
+    "last_name",
+    "email",
+    "department_id",
+    "manager_id",
+    "position",
+    "salary",
+    "currency",
+    "employment_status",
+    "termination_reason",
+    "hire_date",
+    "created_at",
+    "updated_at",
+    "version",
+];
+
+pub(crate) fn employee_csv_row(employee: &Employee) -> [String; 16] {
+    let timestamp = |at: chrono::DateTime<Utc>| at.to_rfc3339_opts(SecondsFormat::Secs, true);
+    [
+        employee.id.to_string(),
+        employee.employee_id.clone(),
+        employee.first_name.clone(),
+        employee.last_name.clone(),
+        employee.email.clone(),
+        employee
+            .department_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        employee
+            .manager_id
+            .map(|id| id.to_string())
+            .unwrap_or_default(),
+        employee.position.clone().unwrap_or_default(),
+        employee
+            .salary
+            .map(|salary| salary.to_string())
+            .unwrap_or_default(),
+        employee.currency.clone(),
+        employee.employment_status.as_str().to_string(),
+        employee.termination_reason.clone().unwrap_or_default(),
+        employee.hire_date.format("%Y-%m-%d").to_string(),
+        timestamp(employee.created_at),
+        timestamp(employee.updated_at),
+        employee.version.to_string(),
+    ]
+}
+
+// One CSV line per chunk; the csv crate takes care of quoting commas, quotes and newlines
+pub(crate) fn csv_record<T: AsRef<[u8]>>(fields: &[T]) -> Bytes {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    writer
+        .write_record(fields)
+        .expect("writing to a Vec cannot fail");
+    let Ok(line) = writer.into_inner() else {
+        unreachable!("flushing into a Vec cannot fail");
+    };
+    Bytes::from(line)
+}
+
+// The same document as GET /employees/export.csv, built in memory
+pub(crate) async fn export_csv(state: &AppState, claims: &Claims) -> Result<Bytes, AppError> {
+    let mut employees = state.employees.list().await?;
+    employees.sort_by(|a, b| a.employee_id.cmp(&b.employee_id));
+
+    let mut file = csv_record(&CSV_COLUMNS).to_vec();
+    for employee in &employees {
+        file.extend_from_slice(&csv_record(&employee_csv_row(
+            &employee.clone().redacted_for(claims),
+        )));
+    }
+    Ok(Bytes::from(file))
+}
+
+/// Start a CSV export job
+///
+/// Queue a background export of every employee, in the format of `GET /employees/export.csv`. Poll
+/// the job at `Location` until it is `completed` (then fetch `download_url`) or `failed`. Jobs are
+/// visible only to the caller that queued them and are kept in memory until the server restarts.
+#[utoipa::path(
+    post,
+    path = "/employees/export",
+    tag = "employees",
+    responses(
+        (status = 202, description = "Job queued", body = Job,
+            headers(("Location" = String, description = "URL of the job to poll"))),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError),
+        (status = 503, description = "The job queue is full", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn start_export_job(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Response, AppError> {
+    let job = state.jobs.enqueue(&claims)?;
+    let location = state.api_url(&format!("/jobs/{}", job.id));
+    Ok((
+        StatusCode::ACCEPTED,
+        [(
+            LOCATION,
+            HeaderValue::from_str(&location).expect("valid Location header"),
+        )],
+        Json(job),
+    )
+        .into_response())
+}
+
+/// Get a background job
+///
+/// Status of a job queued by the caller: `pending` until the worker picks it up, `running` while
+/// the file is generated, then `completed` with a `download_url` or `failed` with an `error`.
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 200, description = "Job found", body = Job),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 404, description = "Job not found", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn get_job(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+) -> Result<Json<Job>, AppError> {
+    let (mut job, _) = state.jobs.get(id, &claims)?;
+    if job.status == JobStatus::Completed {
+        job.download_url = Some(state.api_url(&format!("/jobs/{id}/download")));
+    }
+    Ok(Json(job))
+}
+
+/// Download a job's file
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}/download",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Job ID")
+    ),
+    responses(
+        (status = 200, description = "CSV document with a header row", body = String, content_type = "text/csv",
+            headers(("Content-Disposition" = String, description = "`attachment; filename=\"employees.csv\"`"))),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 404, description = "Job not found", body = ApiError),
+        (status = 409, description = "The job has not completed", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn download_job_file(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiPath(id): ApiPath<Uuid>,
+) -> Result<Response, AppError> {
+    let (job, file) = state.jobs.get(id, &claims)?;
+    let file = file.ok_or_else(|| {
+        AppError::Conflict(format!(
+            "Job {id} is {}, not completed",
+            job.status.as_str()
+        ))
+    })?;
+    Ok((
+        [
+            (CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                CONTENT_DISPOSITION,
+                r#"attachment; filename="employees.csv""#,
+            ),
+        ],
+        file,
+    )
+        .into_response())
+}
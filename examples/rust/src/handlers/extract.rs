@@ -0,0 +1,128 @@
+use async_trait::async_trait;
+use axum::{
+    body::Bytes,
+    extract::{FromRequest, FromRequestParts, Path, Query, Request},
+    http::{header::CONTENT_TYPE, request::Parts, HeaderMap, StatusCode},
+};
+
+use crate::error::AppError;
+use crate::models::common::ValidationError;
+
+pub(crate) const MERGE_PATCH: &str = "application/merge-patch+json";
+pub(crate) const JSON_PATCH: &str = "application/json-patch+json";
+
+// Essence of the Content-Type header, lowercased and without parameters such as `charset`
+pub(crate) fn media_type(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_ascii_lowercase())
+}
+
+pub(crate) fn require_media_type(headers: &HeaderMap, expected: &str) -> Result<(), AppError> {
+    if media_type(headers).as_deref() == Some(expected) {
+        Ok(())
+    } else {
+        Err(AppError::UnsupportedMediaType(format!(
+            "Expected a {expected} body"
+        )))
+    }
+}
+
+// Same split as axum's Json rejection: malformed JSON is a 400, well-formed but wrong shape a 422.
+// The 422 names the member that failed (e.g. `employment_status`, or `[1].email` in an array),
+// or `body` when the document as a whole is wrong, such as a missing field.
+pub(crate) fn parse_json_body<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, AppError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    let value = serde_path_to_error::deserialize(&mut deserializer).map_err(|err| {
+        let field = match err.path().to_string() {
+            path if path == "." => "body".to_string(),
+            path => path,
+        };
+        json_body_error(field, err.into_inner())
+    })?;
+    deserializer
+        .end()
+        .map_err(|err| json_body_error("body".to_string(), err))?;
+    Ok(value)
+}
+
+fn json_body_error(field: String, err: serde_json::Error) -> AppError {
+    if err.classify() == serde_json::error::Category::Data {
+        AppError::Validation(vec![ValidationError::new(
+            field,
+            "invalid_body",
+            err.to_string(),
+        )])
+    } else {
+        AppError::BadRequest(format!("Invalid JSON body: {err}"))
+    }
+}
+
+// `Query`, but a parameter that fails to parse (e.g. `page=abc`) is answered with the usual
+// 400 `ApiError` naming it instead of axum's plain-text rejection
+pub(crate) struct ApiQuery<T>(pub(crate) T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for ApiQuery<T>
+where
+    T: serde::de::DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Query::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Query(params)| ApiQuery(params))
+            .map_err(|rejection| AppError::BadRequest(rejection.body_text()))
+    }
+}
+
+// `Path`, but a segment that fails to parse (e.g. an id that is not a UUID) is answered with the
+// usual 400 `ApiError` instead of axum's plain-text rejection
+pub(crate) struct ApiPath<T>(pub(crate) T);
+
+#[async_trait]
+impl<T, S> FromRequestParts<S> for ApiPath<T>
+where
+    T: serde::de::DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        Path::<T>::from_request_parts(parts, state)
+            .await
+            .map(|Path(params)| ApiPath(params))
+            .map_err(|rejection| AppError::BadRequest(rejection.body_text()))
+    }
+}
+
+// `Json`, but the body must be sent as `application/json` (415 otherwise) and every rejection is
+// answered with the usual `ApiError`
+pub(crate) struct ApiJson<T>(pub(crate) T);
+
+#[async_trait]
+impl<T, S> FromRequest<S> for ApiJson<T>
+where
+    T: serde::de::DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Self::Rejection> {
+        require_media_type(request.headers(), "application/json")?;
+        let body =
+            Bytes::from_request(request, state)
+                .await
+                .map_err(|rejection| match rejection.status() {
+                    StatusCode::PAYLOAD_TOO_LARGE => {
+                        AppError::PayloadTooLarge(rejection.body_text())
+                    }
+                    _ => AppError::BadRequest(rejection.body_text()),
+                })?;
+        parse_json_body(&body).map(ApiJson)
+    }
+}
@@ -0,0 +1,269 @@
+use axum::{body::Bytes, extract::State, http::HeaderMap, response::Json, Extension};
+use validator::Validate;
+
+use crate::error::{validation_details, AppError};
+use crate::handlers::employees::{dry_run_store, ensure_references_exist};
+use crate::handlers::extract::{require_media_type, ApiJson, ApiQuery};
+use crate::middleware::auth::Claims;
+use crate::models::common::{ApiError, ErrorCode, ValidationError};
+use crate::models::employees::{CreateEmployeeRequest, DryRunParams, Employee};
+use crate::models::imports::{
+    BulkCreateReport, BulkMode, BulkParams, BulkRowError, FailedRow, ImportReport, ImportedRow,
+    MAX_BULK_ROWS,
+};
+use crate::repository::EmployeeRepository;
+use crate::state::AppState;
+
+/// Import employees from CSV
+///
+/// Create one employee per CSV row. The header row names the columns, using the field names of
+/// `CreateEmployeeRequest`; other columns are ignored, so a file from `/employees/export.csv` can
+/// be imported as is. Every row is validated on its own: valid rows are created and invalid rows
+/// are reported with their line number. With `dry_run=true` nothing is stored, but the report is
+/// the same.
+#[utoipa::path(
+    post,
+    path = "/employees/import",
+    tag = "employees",
+    params(
+        ("dry_run" = Option<bool>, Query, description = "Validate the file without creating anyone (default `false`)")
+    ),
+    request_body(content = String, content_type = "text/csv", description = "CSV document with a header row"),
+    responses(
+        (status = 200, description = "Per-row import report", body = ImportReport),
+        (status = 400, description = "Body is not UTF-8 or the CSV header is unreadable", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        (status = 415, description = "Content-Type is not `text/csv`", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn import_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiQuery(params): ApiQuery<DryRunParams>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<Json<ImportReport>, AppError> {
+    require_media_type(&headers, "text/csv")?;
+    let body = std::str::from_utf8(&body)
+        .map_err(|err| AppError::BadRequest(format!("CSV body is not valid UTF-8: {err}")))?;
+    let mut reader = csv::Reader::from_reader(body.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|err| AppError::BadRequest(format!("Unreadable CSV header: {err}")))?
+        .clone();
+
+    // A dry run works on a copy so duplicates within the file are still caught
+    let scratch;
+    let target: &dyn EmployeeRepository = if params.dry_run {
+        scratch = dry_run_store(&state).await?;
+        &scratch
+    } else {
+        state.employees.as_ref()
+    };
+
+    let mut report = ImportReport {
+        dry_run: params.dry_run,
+        succeeded: Vec::new(),
+        failed: Vec::new(),
+    };
+    for record in reader.records() {
+        let (line, outcome) = match record {
+            Ok(record) => {
+                let line = record.position().map_or(0, |position| position.line());
+                let outcome = record
+                    .deserialize::<CreateEmployeeRequest>(Some(&headers))
+                    .map_err(|err| vec![csv_row_error(&err, &headers)])
+                    .and_then(|payload| {
+                        payload
+                            .validate()
+                            .map_err(|errors| validation_details(&errors))?;
+                        Ok(payload)
+                    });
+                (line, outcome)
+            }
+            Err(err) => {
+                let line = err.position().map_or(0, |position| position.line());
+                (line, Err(vec![csv_row_error(&err, &headers)]))
+            }
+        };
+
+        let created = match outcome {
+            Ok(payload) => match ensure_references_exist(&state, target, &payload).await {
+                Ok(()) => target
+                    .create(Employee::from_request(payload, state.clock.now()))
+                    .await
+                    .map_err(|err| row_errors(err.into())),
+                Err(err) => Err(row_errors(err)),
+            },
+            Err(errors) => Err(errors),
+        };
+        match created {
+            Ok(employee) => {
+                if !params.dry_run {
+                    state.record_created(&claims.sub, &employee);
+                }
+                report.succeeded.push(ImportedRow {
+                    line,
+                    id: employee.id,
+                    employee_id: employee.employee_id,
+                });
+            }
+            Err(errors) => report.failed.push(FailedRow { line, errors }),
+        }
+    }
+
+    Ok(Json(report))
+}
+
+// Names the offending column when the csv crate can tell which one failed to parse
+pub(crate) fn csv_row_error(err: &csv::Error, headers: &csv::StringRecord) -> ValidationError {
+    let field = match err.kind() {
+        csv::ErrorKind::Deserialize { err, .. } => err
+            .field()
+            .and_then(|index| headers.get(index as usize))
+            .map(str::to_string),
+        _ => None,
+    };
+    ValidationError::new(
+        field.unwrap_or_else(|| "row".to_string()),
+        "invalid_csv_row",
+        err.to_string(),
+    )
+}
+
+// Errors that would have failed a single create, reshaped into the row report
+pub(crate) fn row_errors(err: AppError) -> Vec<ValidationError> {
+    match err {
+        AppError::Validation(details) => details,
+        AppError::Duplicate { field, message } => vec![ValidationError::new(
+            field,
+            ErrorCode::Conflict.as_str(),
+            message,
+        )],
+        other => {
+            let error = ApiError::from(other).error;
+            vec![ValidationError::new(
+                "row",
+                error.code().as_str(),
+                error.message(),
+            )]
+        }
+    }
+}
+
+/// Create employees in bulk
+///
+/// Create up to 1000 employees from a JSON array. With `mode=atomic` (the default) the batch is all
+/// or nothing: if any row is invalid or clashes with an existing employee or an earlier row,
+/// nothing is created and the `422` lists every failure, with fields prefixed by the row index
+/// (e.g. `[2].email`). With `mode=partial` the valid rows are created and the failures are reported
+/// alongside them by index.
+#[utoipa::path(
+    post,
+    path = "/employees/bulk",
+    tag = "employees",
+    params(
+        ("mode" = Option<BulkMode>, Query, description = "`atomic` or `partial` (default `atomic`)")
+    ),
+    request_body = Vec<CreateEmployeeRequest>,
+    responses(
+        (status = 200, description = "Created employees and, in partial mode, the rows that failed", body = BulkCreateReport),
+        (status = 400, description = "Empty or oversized batch", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        (status = 413, description = "Request body too large", body = ApiError),
+        (status = 415, description = "Content-Type is not `application/json`", body = ApiError),
+        (status = 422, description = "Atomic mode: at least one row failed, nothing was created", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn bulk_create_employees(
+    State(state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    ApiQuery(params): ApiQuery<BulkParams>,
+    ApiJson(payloads): ApiJson<Vec<CreateEmployeeRequest>>,
+) -> Result<Json<BulkCreateReport>, AppError> {
+    if payloads.is_empty() || payloads.len() > MAX_BULK_ROWS {
+        return Err(AppError::BadRequest(format!(
+            "A bulk request takes 1 to {MAX_BULK_ROWS} employees"
+        )));
+    }
+
+    // Rehearse the whole batch on a copy first, so rows clashing with each other are caught too
+    let scratch = dry_run_store(&state).await?;
+    let mut accepted = Vec::new();
+    let mut errors = Vec::new();
+    for (index, payload) in payloads.into_iter().enumerate() {
+        let outcome = match payload.validate() {
+            Ok(()) => match ensure_references_exist(&state, &scratch, &payload).await {
+                Ok(()) => scratch
+                    .create(Employee::from_request(payload, state.clock.now()))
+                    .await
+                    .map_err(|err| row_errors(err.into())),
+                Err(err) => Err(row_errors(err)),
+            },
+            Err(err) => Err(validation_details(&err)),
+        };
+        match outcome {
+            Ok(employee) => accepted.push((index, employee)),
+            Err(row) => errors.push(BulkRowError { index, errors: row }),
+        }
+    }
+
+    if params.mode == BulkMode::Atomic && !errors.is_empty() {
+        return Err(AppError::Validation(
+            errors
+                .into_iter()
+                .flat_map(|failed| {
+                    failed.errors.into_iter().map(move |error| {
+                        let field = format!("[{}].{}", failed.index, error.field);
+                        error.with_field(field)
+                    })
+                })
+                .collect(),
+        ));
+    }
+
+    // There is no cross-row transaction, so an atomic batch undoes its own rows if a concurrent
+    // write makes one of them fail after the rehearsal
+    let mut created = Vec::with_capacity(accepted.len());
+    for (index, employee) in accepted {
+        match state.employees.create(employee).await {
+            Ok(employee) => created.push(employee),
+            Err(err) if params.mode == BulkMode::Atomic => {
+                for employee in &created {
+                    if let Err(undo) = state.employees.delete(employee.id).await {
+                        tracing::error!(id = %employee.id, error = ?undo, "failed to roll back bulk create");
+                    }
+                }
+                return Err(err.into());
+            }
+            Err(err) => errors.push(BulkRowError {
+                index,
+                errors: row_errors(err.into()),
+            }),
+        }
+    }
+
+    for employee in &created {
+        state.record_created(&claims.sub, employee);
+    }
+    Ok(Json(BulkCreateReport {
+        created: created
+            .into_iter()
+            .map(|employee| state.employee_view(employee, None))
+            .collect(),
+        errors,
+    }))
+}
@@ -0,0 +1,41 @@
+pub mod admin;
+pub mod audit;
+pub mod departments;
+pub mod employees;
+pub mod events;
+pub mod exports;
+pub mod extract;
+pub mod imports;
+pub mod negotiate;
+pub mod photos;
+pub mod system;
+
+use std::collections::HashMap;
+
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::departments::Department;
+use crate::models::employees::Employee;
+use crate::state::AppState;
+
+pub(crate) async fn find_employee(state: &AppState, id: Uuid) -> Result<Employee, AppError> {
+    state
+        .employees
+        .get(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Employee {id} not found")))
+}
+
+// Departments keyed by id, for filtering by department and for ?expand=department
+pub(crate) async fn department_index(
+    state: &AppState,
+) -> Result<HashMap<Uuid, Department>, AppError> {
+    Ok(state
+        .departments
+        .list()
+        .await?
+        .into_iter()
+        .map(|d| (d.id, d))
+        .collect())
+}
@@ -0,0 +1,62 @@
+use axum::{
+    http::{
+        header::{ACCEPT, CONTENT_TYPE},
+        HeaderMap,
+    },
+    response::{IntoResponse, Json, Response},
+};
+use serde::Serialize;
+
+use crate::error::AppError;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResponseFormat {
+    Json,
+    Xml,
+}
+
+impl ResponseFormat {
+    // The acceptable type with the highest q-value, earliest on a tie; no Accept means JSON
+    pub(crate) fn negotiate(headers: &HeaderMap) -> Result<Self, AppError> {
+        let Some(accept) = headers.get(ACCEPT).filter(|accept| !accept.is_empty()) else {
+            return Ok(Self::Json);
+        };
+        let accept = accept.to_str().map_err(|_| AppError::NotAcceptable)?;
+
+        let mut best: Option<(f32, Self)> = None;
+        for item in accept.split(',') {
+            let mut parts = item.split(';').map(str::trim);
+            let format = match parts
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase()
+                .as_str()
+            {
+                "application/json" | "application/*" | "*/*" => Self::Json,
+                "application/xml" | "text/xml" => Self::Xml,
+                _ => continue,
+            };
+            let quality = parts
+                .find_map(|param| param.strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            if quality > 0.0 && best.is_none_or(|(best_quality, _)| quality > best_quality) {
+                best = Some((quality, format));
+            }
+        }
+        best.map(|(_, format)| format)
+            .ok_or(AppError::NotAcceptable)
+    }
+
+    // `root` names the XML document element; field names become child elements as they are
+    pub(crate) fn render<T: Serialize>(self, root: &str, value: &T) -> Result<Response, AppError> {
+        match self {
+            Self::Json => Ok(Json(value).into_response()),
+            Self::Xml => {
+                let xml = quick_xml::se::to_string_with_root(root, value)
+                    .map_err(|err| AppError::Internal(format!("Failed to render XML: {err}")))?;
+                Ok(([(CONTENT_TYPE, "application/xml; charset=utf-8")], xml).into_response())
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use axum::{
+    extract::{
+        multipart::{Multipart, MultipartError, MultipartRejection},
+        State,
+    },
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Json, Response},
+};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::handlers::extract::ApiPath;
+use crate::handlers::find_employee;
+use crate::models::common::EmployeeNotFound;
+use crate::models::photos::PhotoUrl;
+use crate::state::AppState;
+use crate::storage::{Photo, MAX_PHOTO_BYTES};
+
+#[allow(dead_code)]
+#[derive(ToSchema)]
+#[schema(value_type = String, format = Binary)]
+pub(crate) struct PhotoFile(pub(crate) Vec<u8>);
+
+/// Upload employee photo
+///
+/// Store a profile picture for an employee, replacing any previous one. Send a
+/// `multipart/form-data` body with the image in a part named `photo`; JPEG and PNG up to 5 MiB are
+/// accepted.
+#[utoipa::path(
+    post,
+    path = "/employees/{id}/photo",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID")
+    ),
+    request_body(content = PhotoUpload, content_type = "multipart/form-data"),
+    responses(
+        (status = 204, description = "Photo stored"),
+        (status = 400, description = "Malformed multipart body or missing `photo` part", body = ApiError),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Requires the admin role and the `employees:write` scope", body = ApiError),
+        EmployeeNotFound,
+        (status = 413, description = "Photo is larger than 5 MiB", body = ApiError),
+        (status = 415, description = "Not multipart, or the photo is not JPEG or PNG", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:write"])
+    )
+)]
+pub(crate) async fn upload_employee_photo(
+    State(state): State<AppState>,
+    ApiPath(id): ApiPath<Uuid>,
+    multipart: Result<Multipart, MultipartRejection>,
+) -> Result<StatusCode, AppError> {
+    let mut multipart = multipart.map_err(|_| {
+        AppError::UnsupportedMediaType("Expected a multipart/form-data body".to_string())
+    })?;
+    find_employee(&state, id).await?;
+
+    let photo = loop {
+        let Some(field) = multipart.next_field().await.map_err(multipart_error)? else {
+            return Err(AppError::BadRequest(
+                "Missing multipart part named 'photo'".to_string(),
+            ));
+        };
+        if field.name() != Some("photo") {
+            continue;
+        }
+
+        let declared = field.content_type().map(str::to_string);
+        let bytes = field.bytes().await.map_err(multipart_error)?;
+        if bytes.len() > MAX_PHOTO_BYTES {
+            return Err(photo_too_large());
+        }
+        break Photo {
+            content_type: photo_content_type(declared.as_deref(), &bytes)?,
+            bytes,
+        };
+    };
+
+    // Re-checked so a photo never outlives an employee deleted during the upload
+    find_employee(&state, id).await?;
+    state.photos.put(id, photo).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Get employee photo
+///
+/// Download the employee's profile picture with its original content type
+#[utoipa::path(
+    get,
+    path = "/employees/{id}/photo",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID")
+    ),
+    responses(
+        (status = 200, description = "Photo bytes", body = PhotoFile, content_type = ["image/jpeg", "image/png"]),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 404, description = "Employee or photo not found", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn get_employee_photo(
+    State(state): State<AppState>,
+    ApiPath(id): ApiPath<Uuid>,
+) -> Result<Response, AppError> {
+    find_employee(&state, id).await?;
+    let photo = state
+        .photos
+        .get(id)
+        .await?
+        .ok_or_else(|| AppError::NotFound(format!("Employee {id} has no photo")))?;
+
+    Ok(([(CONTENT_TYPE, photo.content_type)], photo.bytes).into_response())
+}
+
+/// Get a photo download URL
+///
+/// With S3 photo storage, a presigned URL that downloads the photo straight from the bucket until
+/// `expires_at` (PHOTO_URL_TTL_SECS, 5 minutes by default). Other storage backends return the photo
+/// endpoint itself with a null `expires_at`.
+#[utoipa::path(
+    get,
+    path = "/employees/{id}/photo-url",
+    tag = "employees",
+    params(
+        ("id" = Uuid, Path, description = "Employee ID")
+    ),
+    responses(
+        (status = 200, description = "Where to download the photo", body = PhotoUrl),
+        (status = 401, description = "Unauthorized", body = ApiError),
+        (status = 403, description = "Token lacks the `employees:read` scope", body = ApiError),
+        (status = 404, description = "Employee or photo not found", body = ApiError),
+        (status = 429, description = "Rate limit exceeded", body = ApiError)
+    ),
+    security(
+        ("BearerAuth" = []),
+        ("OAuth2" = ["employees:read"])
+    )
+)]
+pub(crate) async fn get_employee_photo_url(
+    State(state): State<AppState>,
+    ApiPath(id): ApiPath<Uuid>,
+) -> Result<Json<PhotoUrl>, AppError> {
+    find_employee(&state, id).await?;
+    if !state.photos.exists(id).await? {
+        return Err(AppError::NotFound(format!("Employee {id} has no photo")));
+    }
+
+    let ttl = state.photo_url_ttl;
+    let issued_at = state.clock.now();
+    let photo_url = match state.photos.presigned_url(id, ttl).await? {
+        Some(url) => PhotoUrl {
+            url,
+            expires_at: Some(issued_at + chrono::Duration::seconds(ttl.as_secs() as i64)),
+        },
+        None => PhotoUrl {
+            url: state.api_url(&format!("/employees/{id}/photo")),
+            expires_at: None,
+        },
+    };
+    Ok(Json(photo_url))
+}
+
+// The declared type must be JPEG or PNG and agree with the file's magic bytes
+pub(crate) fn photo_content_type(
+    declared: Option<&str>,
+    bytes: &[u8],
+) -> Result<&'static str, AppError> {
+    match sniff_photo_type(bytes) {
+        Some(content_type) if declared == Some(content_type) => Ok(content_type),
+        _ => Err(AppError::UnsupportedMediaType(
+            "Photo must be a JPEG or PNG image".to_string(),
+        )),
+    }
+}
+
+pub(crate) fn sniff_photo_type(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else {
+        None
+    }
+}
+
+pub(crate) fn photo_too_large() -> AppError {
+    AppError::PayloadTooLarge(format!(
+        "Photo must be at most {} MiB",
+        MAX_PHOTO_BYTES / (1024 * 1024)
+    ))
+}
+
+// Hitting the route's body limit surfaces here as a 413 multipart error
+pub(crate) fn multipart_error(err: MultipartError) -> AppError {
+    if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        photo_too_large()
+    } else {
+        AppError::BadRequest(err.body_text())
+    }
+}
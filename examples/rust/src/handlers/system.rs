@@ -0,0 +1,134 @@
+use std::sync::atomic::Ordering;
+use std::sync::LazyLock;
+
+use axum::{
+    extract::State,
+    http::header::CONTENT_TYPE,
+    response::{IntoResponse, Json},
+};
+
+use crate::error::AppError;
+use crate::middleware::metrics::prometheus_handle;
+use crate::models::common::{ErrorCode, ErrorCodeInfo, HealthResponse, VersionInfo};
+use crate::state::AppState;
+
+/// List error codes
+///
+/// Every value of `error.code` (the `type` suffix of problem documents) with its HTTP status. Codes
+/// are stable: new ones may be added, but existing ones are not renamed or removed.
+#[utoipa::path(
+    get,
+    path = "/error-codes",
+    tag = "system",
+    responses(
+        (status = 200, description = "All error codes", body = [ErrorCodeInfo])
+    ),
+    security(())
+)]
+pub(crate) async fn list_error_codes() -> Json<Vec<ErrorCodeInfo>> {
+    Json(
+        ErrorCode::ALL
+            .into_iter()
+            .map(|code| ErrorCodeInfo {
+                code,
+                status: code.status().as_u16(),
+                description: code.description(),
+            })
+            .collect(),
+    )
+}
+
+/// Liveness probe
+///
+/// Returns 200 while the process is able to serve requests
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "system",
+    responses(
+        (status = 200, description = "Service is alive", body = HealthResponse)
+    ),
+    security(())
+)]
+pub(crate) async fn health(State(state): State<AppState>) -> Json<HealthResponse> {
+    Json(HealthResponse {
+        status: "ok".to_string(),
+        maintenance: state.maintenance.load(Ordering::Relaxed),
+    })
+}
+
+/// Readiness probe
+///
+/// Returns 200 when the backing store can serve traffic, 503 otherwise
+#[utoipa::path(
+    get,
+    path = "/ready",
+    tag = "system",
+    responses(
+        (status = 200, description = "Service is ready", body = HealthResponse),
+        (status = 503, description = "Backing store unavailable", body = ApiError)
+    ),
+    security(())
+)]
+pub(crate) async fn ready(State(state): State<AppState>) -> Result<Json<HealthResponse>, AppError> {
+    // In memory, a poisoned lock means a writer panicked mid-update and the data can no
+    // longer be trusted; a database backend reports whether it can reach the server
+    let employees_ready = state.employees.ping().await.is_ok();
+    let departments_ready = state.departments.ping().await.is_ok();
+    if !employees_ready || !departments_ready {
+        return Err(AppError::ServiceUnavailable(
+            "Data store is unavailable".to_string(),
+        ));
+    }
+    Ok(Json(HealthResponse {
+        status: "ready".to_string(),
+        maintenance: state.maintenance.load(Ordering::Relaxed),
+    }))
+}
+
+// GIT_SHA and BUILD_TIMESTAMP (seconds since the epoch) are set by build.rs
+pub(crate) static VERSION_INFO: LazyLock<VersionInfo> = LazyLock::new(|| VersionInfo {
+    name: env!("CARGO_PKG_NAME"),
+    version: env!("CARGO_PKG_VERSION"),
+    git_sha: env!("GIT_SHA"),
+    build_time: env!("BUILD_TIMESTAMP")
+        .parse()
+        .ok()
+        .and_then(|secs| chrono::DateTime::from_timestamp(secs, 0))
+        .unwrap_or_default(),
+});
+
+/// Build information
+///
+/// Reports the crate name and version, and the commit and time the running binary was built from
+#[utoipa::path(
+    get,
+    path = "/version",
+    tag = "system",
+    responses(
+        (status = 200, description = "Build metadata", body = VersionInfo)
+    ),
+    security(())
+)]
+pub(crate) async fn version() -> Json<&'static VersionInfo> {
+    Json(&VERSION_INFO)
+}
+
+/// Prometheus metrics
+///
+/// Request counts, latency histograms and in-flight requests per route in Prometheus text format
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "system",
+    responses(
+        (status = 200, description = "Prometheus exposition", body = String, content_type = "text/plain; version=0.0.4")
+    ),
+    security(())
+)]
+pub(crate) async fn metrics() -> impl IntoResponse {
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4")],
+        prometheus_handle().render(),
+    )
+}
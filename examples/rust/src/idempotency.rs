@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::clock::Clock;
+use crate::error::AppError;
+use crate::models::common::ValidationError;
+use crate::models::employees::Employee;
+
+pub(crate) const IDEMPOTENCY_KEY: &str = "idempotency-key";
+pub(crate) const IDEMPOTENT_REPLAYED: &str = "idempotent-replayed";
+
+// Successful creates remembered by idempotency key until `ttl` passes
+pub(crate) struct IdempotencyStore {
+    pub(crate) ttl: chrono::Duration,
+    pub(crate) entries: Mutex<HashMap<String, IdempotentCreate>>,
+    pub(crate) clock: Arc<dyn Clock>,
+}
+
+pub(crate) struct IdempotentCreate {
+    pub(crate) fingerprint: String,
+    pub(crate) employee: Employee,
+    pub(crate) stored_at: chrono::DateTime<Utc>,
+}
+
+impl IdempotencyStore {
+    pub(crate) fn new(ttl: Duration, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            ttl: chrono::Duration::from_std(ttl)
+                .expect("idempotency TTL fits in a chrono::Duration"),
+            entries: Mutex::new(HashMap::new()),
+            clock,
+        }
+    }
+
+    // The stored employee for `key`, or a 422 when the key was used for a different body
+    pub(crate) fn replay(
+        &self,
+        key: &str,
+        fingerprint: &str,
+    ) -> Result<Option<Employee>, AppError> {
+        let now = self.clock.now();
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        match entries.get(key) {
+            Some(entry) if now - entry.stored_at >= self.ttl => Ok(None),
+            Some(entry) if entry.fingerprint != fingerprint => {
+                Err(AppError::Validation(vec![ValidationError::new(
+                    "Idempotency-Key",
+                    "idempotency_key_reused",
+                    "was already used with a different request body",
+                )]))
+            }
+            Some(entry) => Ok(Some(entry.employee.clone())),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn store(&self, key: String, fingerprint: String, employee: Employee) {
+        let now = self.clock.now();
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|_, entry| now - entry.stored_at < self.ttl);
+        entries.insert(
+            key,
+            IdempotentCreate {
+                fingerprint,
+                employee,
+                stored_at: now,
+            },
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use axum::body::Bytes;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::clock::Clock;
+use crate::error::AppError;
+use crate::handlers::exports::export_csv;
+use crate::middleware::auth::Claims;
+use crate::models::common::ApiError;
+use crate::models::jobs::{Job, JobStatus};
+use crate::state::AppState;
+
+// Exports too large to stream within the request timeout run as background jobs: the handler
+// queues the job, one worker task builds the CSV, and clients poll GET /jobs/{id}. Jobs and
+// their files live in memory, so they are lost on restart.
+pub(crate) const JOB_QUEUE_CAPACITY: usize = 32;
+
+pub(crate) struct JobEntry {
+    pub(crate) job: Job,
+    // The export is redacted for, and only visible to, whoever queued it
+    pub(crate) claims: Claims,
+    pub(crate) file: Option<Bytes>,
+}
+
+pub(crate) struct JobRegistry {
+    pub(crate) jobs: RwLock<HashMap<Uuid, JobEntry>>,
+    pub(crate) clock: Arc<dyn Clock>,
+    pub(crate) queue: mpsc::Sender<Uuid>,
+    // Taken by the worker when it starts
+    pub(crate) receiver: Mutex<Option<mpsc::Receiver<Uuid>>>,
+}
+
+impl JobRegistry {
+    pub(crate) fn new(clock: Arc<dyn Clock>) -> Self {
+        let (queue, receiver) = mpsc::channel(JOB_QUEUE_CAPACITY);
+        Self {
+            jobs: RwLock::new(HashMap::new()),
+            clock,
+            queue,
+            receiver: Mutex::new(Some(receiver)),
+        }
+    }
+
+    pub(crate) fn enqueue(&self, claims: &Claims) -> Result<Job, AppError> {
+        let job = Job {
+            id: Uuid::new_v4(),
+            status: JobStatus::Pending,
+            created_at: self.clock.now(),
+            finished_at: None,
+            download_url: None,
+            error: None,
+        };
+        // Reserve the slot first so a full queue doesn't leave a pending job behind
+        let permit = self.queue.try_reserve().map_err(|_| {
+            AppError::ServiceUnavailable(
+                "Too many export jobs are queued; try again later".to_string(),
+            )
+        })?;
+        self.jobs
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                job.id,
+                JobEntry {
+                    job: job.clone(),
+                    claims: claims.clone(),
+                    file: None,
+                },
+            );
+        permit.send(job.id);
+        Ok(job)
+    }
+
+    // Another caller's job is reported as missing rather than forbidden
+    pub(crate) fn get(&self, id: Uuid, claims: &Claims) -> Result<(Job, Option<Bytes>), AppError> {
+        let jobs = self.jobs.read().unwrap_or_else(PoisonError::into_inner);
+        match jobs.get(&id) {
+            Some(entry) if entry.claims.sub == claims.sub => {
+                Ok((entry.job.clone(), entry.file.clone()))
+            }
+            _ => Err(AppError::NotFound(format!("Job {id} not found"))),
+        }
+    }
+
+    // Marks the job running and hands back the claims it runs with
+    pub(crate) fn start(&self, id: Uuid) -> Option<Claims> {
+        let mut jobs = self.jobs.write().unwrap_or_else(PoisonError::into_inner);
+        let entry = jobs.get_mut(&id)?;
+        entry.job.status = JobStatus::Running;
+        Some(entry.claims.clone())
+    }
+
+    pub(crate) fn finish(&self, id: Uuid, outcome: Result<Bytes, String>) {
+        let mut jobs = self.jobs.write().unwrap_or_else(PoisonError::into_inner);
+        let Some(entry) = jobs.get_mut(&id) else {
+            return;
+        };
+        entry.job.finished_at = Some(self.clock.now());
+        match outcome {
+            Ok(file) => {
+                entry.job.status = JobStatus::Completed;
+                entry.file = Some(file);
+            }
+            Err(message) => {
+                entry.job.status = JobStatus::Failed;
+                entry.job.error = Some(message);
+            }
+        }
+    }
+}
+
+// Runs queued jobs one at a time so exports don't compete with request handling for the store
+pub(crate) fn spawn_job_worker(state: AppState) {
+    let receiver = state
+        .jobs
+        .receiver
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .take();
+    // Already running for this state
+    let Some(mut queue) = receiver else {
+        return;
+    };
+    tokio::spawn(async move {
+        while let Some(id) = queue.recv().await {
+            let Some(claims) = state.jobs.start(id) else {
+                continue;
+            };
+            let outcome = export_csv(&state, &claims).await.map_err(|err| {
+                let message = ApiError::from(err).error.message().to_string();
+                tracing::warn!(job_id = %id, error = %message, "export job failed");
+                message
+            });
+            state.jobs.finish(id, outcome);
+        }
+    });
+}
@@ -0,0 +1,221 @@
+// Rust OpenAPI integration example using utoipa
+//
+// Optional cargo features (declared in Cargo.toml):
+// - `redoc`: also serve ReDoc at /redoc, e.g. `cargo run --features redoc`.
+// - `problem-json`: render errors as RFC 7807 `application/problem+json` documents instead of
+//   the default `ApiError` body.
+// - `postgres`: store employees and departments in PostgreSQL when DATABASE_URL is set
+//   (schema in migrations/). Without it the in-memory store is used.
+//   Pending migrations run at startup unless SKIP_MIGRATIONS=true; `sqlx::migrate!` resolves
+//   migrations/ against the manifest directory.
+// - `grpc`: also serve the employee CRUD operations over gRPC (proto/employees.proto) on
+//   GRPC_ADDR (default 0.0.0.0:50051), next to REST on BIND_ADDR. Both share one AppState, so
+//   they use the same repository and tokens; send the JWT as `authorization: Bearer <token>`
+//   metadata. build.rs generates the server from the proto with a bundled protoc, unless
+//   PROTOC points at another one.
+// - `s3`: allow PHOTO_STORAGE=s3, keeping photos in S3_BUCKET under S3_PREFIX (default
+//   `photos/`). Without it photos are kept in memory (PHOTO_STORAGE=memory, the default) or as
+//   files under PHOTO_DIR (PHOTO_STORAGE=local).
+//
+// `cargo run -- --dump-openapi [path]` writes the spec to disk and exits instead of serving.
+// The path defaults to $OPENAPI_OUTPUT, then target/openapi.json, so CI can diff the committed spec.
+// `cargo run -- --check-openapi [path]` compares the spec against a committed snapshot
+// (tests/openapi.snapshot.json by default) and fails on any difference, so API changes show up in
+// review; rerun with UPDATE_SNAPSHOTS=1 to accept them. `cargo test` runs the same check
+// (tests/integration/openapi.rs), so `UPDATE_SNAPSHOTS=1 cargo test` regenerates it too.
+// Both write OpenAPI 3.0 unless `--openapi-3.1` is also passed; the running service serves the
+// 3.1 rendering at /api-docs/openapi-3.1.json next to /api-docs/openapi.json, and the 3.0 spec
+// as YAML at /api-docs/openapi.yaml. Page sizes are documented with DEFAULT_PER_PAGE and
+// MAX_PER_PAGE from the environment, as the service enforces them.
+//
+// `cargo bench --bench pagination` times offset against keyset pagination over 100k employees
+// (benches/pagination.rs); `cargo test` checks that both return the same pages.
+//
+// GET /version reports the commit from build.rs: `git rev-parse HEAD`, or GIT_SHA when set.
+//
+// For local use, DEV_AUTH=true in a debug build accepts any bearer token as a configurable dev
+// identity, so Swagger UI's "Authorize" works with a dummy value; release builds refuse to start with it.
+//
+// `cargo run -- --seed <path>` (or SEED_FILE) starts the in-memory store with the departments and
+// employees in a JSON or YAML file instead of the demo data; an invalid record stops startup.
+//
+// Settings are read from the environment by `Config::from_env`; only JWT_SECRET is required.
+pub mod audit;
+pub mod bench_support;
+pub mod builders;
+pub mod clock;
+pub mod config;
+pub mod error;
+pub mod events;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+pub mod handlers;
+pub mod idempotency;
+pub mod jobs;
+pub mod middleware;
+pub mod models;
+pub mod repository;
+pub mod retention;
+pub mod routes;
+pub mod seed;
+pub mod state;
+pub mod storage;
+pub mod utils;
+pub mod webhooks;
+
+use std::future::{Future, IntoFuture};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use axum::Router;
+
+use crate::clock::{Clock, SystemClock};
+use crate::config::app::Config;
+#[cfg(feature = "postgres")]
+use crate::repository::postgres;
+use crate::retention::run_retention_sweeps;
+use crate::routes::app_router;
+use crate::state::AppState;
+
+// Serves until `shutdown` resolves, then stops accepting connections and gives in-flight
+// requests up to `drain_timeout` to finish before returning.
+pub async fn serve(
+    listener: tokio::net::TcpListener,
+    app: Router,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+    drain_timeout: Duration,
+) -> anyhow::Result<()> {
+    let (draining_tx, draining_rx) = tokio::sync::oneshot::channel();
+    // ConnectInfo gives the rate limiter a peer address for anonymous clients
+    let server = axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .with_graceful_shutdown(async move {
+        shutdown.await;
+        tracing::info!("shutdown signal received, draining in-flight requests");
+        let _ = draining_tx.send(());
+    })
+    .into_future();
+    tokio::pin!(server);
+
+    tokio::select! {
+        result = &mut server => return Ok(result?),
+        _ = draining_rx => {}
+    }
+    match tokio::time::timeout(drain_timeout, server).await {
+        Ok(result) => Ok(result?),
+        Err(_) => {
+            tracing::warn!(
+                ?drain_timeout,
+                "drain timeout elapsed, dropping remaining connections"
+            );
+            Ok(())
+        }
+    }
+}
+
+// Resolves on Ctrl+C (SIGINT) or, on Unix, SIGTERM from the orchestrator
+pub(crate) async fn shutdown_signal() {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install SIGTERM handler")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+// Builds the state the configuration asks for and serves REST (and gRPC, with the feature)
+// until a shutdown signal
+pub async fn run(config: Config) -> anyhow::Result<()> {
+    let clock: Arc<dyn Clock> = Arc::new(SystemClock);
+    #[cfg(feature = "postgres")]
+    let state = match &config.database_url {
+        Some(_) if config.seed_file.is_some() => {
+            anyhow::bail!("a seed file only applies to the in-memory store; unset DATABASE_URL")
+        }
+        Some(url) => {
+            let pool = sqlx::postgres::PgPoolOptions::new()
+                .connect(url)
+                .await
+                .context("failed to connect to DATABASE_URL")?;
+            if config.run_migrations {
+                let applied = postgres::run_migrations(&pool).await?;
+                tracing::info!(applied, "database migrations complete");
+            } else {
+                tracing::info!("SKIP_MIGRATIONS is set, leaving the schema untouched");
+            }
+            AppState::new(
+                &config,
+                clock.clone(),
+                Arc::new(postgres::PgEmployeeRepository::new(pool.clone(), clock)),
+                Arc::new(postgres::PgDepartmentRepository::new(pool)),
+            )
+        }
+        None => AppState::from_seed(&config, clock)?,
+    };
+    #[cfg(not(feature = "postgres"))]
+    let state = AppState::from_seed(&config, clock)?;
+
+    // Same state as the REST router, so both surfaces see every write
+    #[cfg(feature = "grpc")]
+    let grpc = {
+        tracing::info!("🔌 gRPC service on {}", config.grpc_addr);
+        tokio::spawn(grpc::serve(
+            config.grpc_addr,
+            state.clone(),
+            shutdown_signal(),
+        ))
+    };
+    let retention = config.terminated_retention.map(|retention| {
+        tracing::info!(
+            "🧹 purging terminated employees after {} days",
+            retention.as_secs() / (24 * 60 * 60)
+        );
+        tokio::spawn(run_retention_sweeps(
+            state.clone(),
+            retention,
+            config.retention_sweep_interval,
+            shutdown_signal(),
+        ))
+    });
+    let app = app_router(&config, state);
+
+    let listener = tokio::net::TcpListener::bind(config.bind_addr)
+        .await
+        .with_context(|| format!("failed to bind {}", config.bind_addr))?;
+
+    tracing::info!("🚀 Service running on http://{}", config.bind_addr);
+    tracing::info!("📚 API docs at http://{}/docs", config.bind_addr);
+    if let Some(identity) = &config.dev_identity {
+        tracing::warn!(
+            sub = %identity.sub,
+            roles = ?identity.roles,
+            "DEV_AUTH is on: any bearer token is accepted"
+        );
+    }
+
+    serve(listener, app, shutdown_signal(), config.shutdown_timeout).await?;
+    #[cfg(feature = "grpc")]
+    grpc.await.context("gRPC server task panicked")??;
+    if let Some(retention) = retention {
+        retention.await.context("retention task panicked")?;
+    }
+    Ok(())
+}
@@ -0,0 +1,59 @@
+// Entry point: handles the spec commands, then serves with settings from the environment
+use std::path::{Path as FsPath, PathBuf};
+
+use anyhow::Context;
+use openapi_integration::config::app::{Config, PageLimits};
+use openapi_integration::middleware::logging::init_tracing;
+use openapi_integration::routes::api::{check_openapi, dump_openapi, SpecVersion};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    init_tracing();
+
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    // `--openapi-3.1` may come anywhere and switches the spec commands below from 3.0
+    let spec_version = match args.iter().position(|arg| arg == "--openapi-3.1") {
+        Some(index) => {
+            args.remove(index);
+            SpecVersion::V3_1
+        }
+        None => SpecVersion::V3_0,
+    };
+    let seed_file = match args.iter().position(|arg| arg == "--seed") {
+        Some(index) => {
+            args.remove(index);
+            anyhow::ensure!(index < args.len(), "--seed needs a path");
+            Some(PathBuf::from(args.remove(index)))
+        }
+        None => None,
+    };
+    // The spec commands document the configured page sizes, like the running service
+    let page_limits = || {
+        PageLimits::from_lookup(|name| std::env::var(name).ok()).context("invalid configuration")
+    };
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("--dump-openapi") => {
+            let path = args
+                .next()
+                .or_else(|| std::env::var("OPENAPI_OUTPUT").ok())
+                .unwrap_or_else(|| "target/openapi.json".to_string());
+            dump_openapi(FsPath::new(&path), spec_version, page_limits()?)?;
+            tracing::info!("📄 OpenAPI spec written to {path}");
+            return Ok(());
+        }
+        Some("--check-openapi") => {
+            let path = args
+                .next()
+                .unwrap_or_else(|| "tests/openapi.snapshot.json".to_string());
+            return check_openapi(FsPath::new(&path), spec_version, page_limits()?);
+        }
+        _ => {}
+    }
+
+    let mut config = Config::from_env().context("invalid configuration")?;
+    if seed_file.is_some() {
+        config.seed_file = seed_file;
+    }
+    openapi_integration::run(config).await
+}
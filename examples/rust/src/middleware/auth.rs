@@ -0,0 +1,143 @@
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header::AUTHORIZATION, Method},
+    middleware::Next,
+    response::Response,
+};
+use jsonwebtoken::{Algorithm, Validation};
+use serde::Deserialize;
+
+use crate::error::{AppError, AuthFailure};
+use crate::routes::v1;
+use crate::state::AppState;
+
+pub(crate) const EMPLOYEES_READ: &str = "employees:read";
+pub(crate) const EMPLOYEES_WRITE: &str = "employees:write";
+pub(crate) const DEPARTMENTS_READ: &str = "departments:read";
+pub(crate) const DEPARTMENTS_WRITE: &str = "departments:write";
+// Adds `salary` and `currency` to employee reads; admins see them without it
+pub(crate) const EMPLOYEES_READ_SENSITIVE: &str = "employees:read-sensitive";
+
+pub(crate) const OAUTH_SCOPES: [(&str, &str); 5] = [
+    (
+        EMPLOYEES_READ,
+        "Read employees, their photos, reports and history",
+    ),
+    (
+        EMPLOYEES_READ_SENSITIVE,
+        "Also read employee compensation (`salary` and `currency`)",
+    ),
+    (EMPLOYEES_WRITE, "Create, change and delete employees"),
+    (DEPARTMENTS_READ, "Read departments"),
+    (DEPARTMENTS_WRITE, "Create departments"),
+];
+
+pub(crate) const ADMIN_ROLE: &str = "admin";
+
+// Claims decoded from the bearer token, available to handlers via Extension<Claims>
+#[derive(Clone, Deserialize)]
+pub struct Claims {
+    pub sub: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// OAuth2 scopes, space separated as issued by the authorization server
+    #[serde(default)]
+    pub scope: String,
+}
+
+impl Claims {
+    pub(crate) fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+
+    pub(crate) fn has_scope(&self, scope: &str) -> bool {
+        self.scope.split_whitespace().any(|s| s == scope)
+    }
+
+    pub(crate) fn can_read_sensitive(&self) -> bool {
+        self.has_role(ADMIN_ROLE) || self.has_scope(EMPLOYEES_READ_SENSITIVE)
+    }
+}
+
+pub(crate) async fn require_bearer_auth(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let authorization = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let claims = decode_claims(&state, authorization)?;
+
+    request.extensions_mut().insert(claims);
+    Ok(next.run(request).await)
+}
+
+// Shared by the REST middleware and the gRPC service so both accept the same tokens
+pub(crate) fn decode_claims(
+    state: &AppState,
+    authorization: Option<&str>,
+) -> Result<Claims, AppError> {
+    let token = authorization
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(AppError::Unauthorized(AuthFailure::MissingToken))?;
+
+    // Only set under DEV_AUTH, which debug builds alone accept; the token itself is not checked
+    if let Some(claims) = &state.dev_claims {
+        return Ok(claims.as_ref().clone());
+    }
+
+    // Validation::new checks the signature and `exp`
+    jsonwebtoken::decode::<Claims>(token, &state.jwt_key, &Validation::new(Algorithm::HS256))
+        .map(|data| data.claims)
+        .map_err(|_| AppError::Unauthorized(AuthFailure::InvalidToken))
+}
+
+// POSTs that only read data; the jobs they start are reads too
+pub(crate) const READ_ONLY_POSTS: [&str; 1] = ["/employees/export"];
+
+// Mirrors the scopes in the security(...) blocks: reads (GET, HEAD and READ_ONLY_POSTS) need `<resource>:read`, anything else
+// `<resource>:write`. Must run inside require_bearer_auth so the claims are present.
+pub(crate) async fn require_scope(
+    State(state): State<AppState>,
+    matched_path: MatchedPath,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    if state.require_scopes {
+        let path = matched_path.as_str().trim_start_matches(v1::PREFIX);
+        let read = matches!(*request.method(), Method::GET | Method::HEAD)
+            || (request.method() == Method::POST && READ_ONLY_POSTS.contains(&path));
+        let scope = match (path.starts_with("/departments"), read) {
+            (false, true) => EMPLOYEES_READ,
+            (false, false) => EMPLOYEES_WRITE,
+            (true, true) => DEPARTMENTS_READ,
+            (true, false) => DEPARTMENTS_WRITE,
+        };
+        let claims = request
+            .extensions()
+            .get::<Claims>()
+            .ok_or(AppError::Unauthorized(AuthFailure::MissingToken))?;
+        if !claims.has_scope(scope) {
+            return Err(AppError::MissingScope(scope));
+        }
+    }
+    Ok(next.run(request).await)
+}
+
+// Per-handler layer; must run inside require_bearer_auth so the claims are present
+pub(crate) async fn require_role(
+    State(role): State<&'static str>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let claims = request
+        .extensions()
+        .get::<Claims>()
+        .ok_or(AppError::Unauthorized(AuthFailure::MissingToken))?;
+    if !claims.has_role(role) {
+        return Err(AppError::Forbidden);
+    }
+    Ok(next.run(request).await)
+}
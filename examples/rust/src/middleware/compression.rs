@@ -0,0 +1,11 @@
+use tower_http::compression::predicate::{DefaultPredicate, Predicate, SizeAbove};
+use tower_http::compression::CompressionLayer;
+
+// gzip or brotli per Accept-Encoding. Applies to every route, including /metrics and the
+// OpenAPI JSON; the default predicate still skips images, gRPC and event streams.
+pub(crate) fn compression_layer() -> CompressionLayer<impl Predicate> {
+    CompressionLayer::new()
+        .gzip(true)
+        .br(true)
+        .compress_when(DefaultPredicate::new().and(SizeAbove::new(1024)))
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+use axum::http::{
+    header::{AUTHORIZATION, CONTENT_TYPE, ETAG, IF_MATCH, IF_NONE_MATCH, LINK},
+    HeaderName, HeaderValue, Method,
+};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::idempotency::{IDEMPOTENCY_KEY, IDEMPOTENT_REPLAYED};
+use crate::middleware::logging::X_REQUEST_ID;
+use crate::utils::pagination::X_TOTAL_COUNT;
+
+// Safe by default: with CORS_ALLOWED_ORIGINS unset no browser origin is allowed
+pub(crate) fn cors_layer(origins: &[HeaderValue]) -> CorsLayer {
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins.iter().cloned()))
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+        ])
+        .allow_headers([
+            AUTHORIZATION,
+            CONTENT_TYPE,
+            IF_MATCH,
+            IF_NONE_MATCH,
+            HeaderName::from_static(IDEMPOTENCY_KEY),
+        ])
+        .expose_headers([
+            HeaderName::from_static(X_REQUEST_ID),
+            HeaderName::from_static(X_TOTAL_COUNT),
+            HeaderName::from_static(IDEMPOTENT_REPLAYED),
+            ETAG,
+            LINK,
+        ])
+        .max_age(Duration::from_secs(600))
+}
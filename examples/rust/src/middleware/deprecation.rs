@@ -0,0 +1,43 @@
+use axum::{
+    extract::{Request, State},
+    http::{header::LINK, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+
+// RFC 8594 signals for a route that is going away; attach with
+// `.layer(middleware::from_fn_with_state(deprecation, mark_deprecated))` and mark the handler
+// #[deprecated] so the spec agrees
+#[derive(Clone, Copy)]
+pub(crate) struct Deprecation {
+    // HTTP-date after which the route may be removed
+    pub(crate) sunset: &'static str,
+    // Where the replacement is documented
+    pub(crate) docs: &'static str,
+}
+
+pub(crate) const LEGACY_LIST: Deprecation = Deprecation {
+    sunset: "Wed, 30 Jun 2027 00:00:00 GMT",
+    docs: "/docs/#/employees/list_employees",
+};
+
+pub(crate) const DEPRECATION: &str = "deprecation";
+pub(crate) const SUNSET: &str = "sunset";
+
+pub(crate) async fn mark_deprecated(
+    State(deprecation): State<Deprecation>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert(DEPRECATION, HeaderValue::from_static("true"));
+    headers.insert(SUNSET, HeaderValue::from_static(deprecation.sunset));
+    // Appended so pagination links on the same response survive
+    headers.append(
+        LINK,
+        HeaderValue::from_str(&format!("<{}>; rel=\"deprecation\"", deprecation.docs))
+            .expect("valid Link header"),
+    );
+    response
+}
@@ -0,0 +1,86 @@
+use axum::{
+    extract::{OriginalUri, Request, State},
+    http::{
+        header::{ALLOW, CONTENT_TYPE, LOCATION},
+        StatusCode,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::error::AppError;
+use crate::middleware::logging::current_request_id;
+use crate::routes::v1;
+
+// RequestBodyLimitLayer, and extractors hitting the limit mid-stream, answer with a plain-text
+// 413; replace it with the documented error body
+pub(crate) async fn payload_too_large_as_api_error(
+    State(limit): State<usize>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+    let is_json = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|content_type| content_type.contains("json"));
+    if response.status() == StatusCode::PAYLOAD_TOO_LARGE && !is_json {
+        return AppError::PayloadTooLarge(format!("Request body must not exceed {limit} bytes"))
+            .into_response();
+    }
+    response
+}
+
+// Gives the empty responses produced by the framework the documented error body.
+// TimeoutLayer gives up with a 408; from the client's side the server failed to answer in
+// time, which is a 504. Method routers answer unknown methods with a bare 405.
+pub(crate) async fn framework_errors_as_api_error(request: Request, next: Next) -> Response {
+    let response = next.run(request).await;
+    match response.status() {
+        StatusCode::REQUEST_TIMEOUT => AppError::GatewayTimeout.into_response(),
+        StatusCode::METHOD_NOT_ALLOWED => {
+            let allow = response.headers().get(ALLOW).cloned();
+            let mut error = AppError::MethodNotAllowed.into_response();
+            if let Some(allow) = allow {
+                error.headers_mut().insert(ALLOW, allow);
+            }
+            error
+        }
+        _ => response,
+    }
+}
+
+// A panicking handler fails only its own request. The payload can hold internal details,
+// so it goes to the log and the client gets a generic 500.
+pub fn panic_as_api_error(panic: Box<dyn std::any::Any + Send + 'static>) -> Response {
+    let message = panic
+        .downcast_ref::<String>()
+        .map(String::as_str)
+        .or_else(|| panic.downcast_ref::<&str>().copied())
+        .unwrap_or("non-string panic payload");
+    let request_id = current_request_id();
+    tracing::error!(
+        request_id = request_id.as_deref(),
+        panic = message,
+        "handler panicked"
+    );
+    AppError::Internal("Internal server error".to_string()).into_response()
+}
+
+// The unversioned paths from before /v1. 308 keeps the method and body, so clients that follow
+// redirects keep working while they move to the versioned URLs.
+pub(crate) async fn redirect_to_v1(OriginalUri(uri): OriginalUri) -> Response {
+    let target = match uri.query() {
+        Some(query) => format!("{}{}?{query}", v1::PREFIX, uri.path()),
+        None => format!("{}{}", v1::PREFIX, uri.path()),
+    };
+    (StatusCode::PERMANENT_REDIRECT, [(LOCATION, target)]).into_response()
+}
+
+pub(crate) async fn route_not_found(OriginalUri(uri): OriginalUri) -> AppError {
+    AppError::NotFound(format!(
+        "No route for {}; see /docs for the available endpoints",
+        uri.path()
+    ))
+}
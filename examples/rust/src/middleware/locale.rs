@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+
+use axum::{
+    extract::Request,
+    http::{
+        header::{ACCEPT_LANGUAGE, CONTENT_LANGUAGE, VARY},
+        HeaderValue, StatusCode,
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use crate::middleware::logging::REQUEST_LOCALE;
+
+// Languages validation messages are translated into. Clients wanting others can key their own
+// texts on `ValidationError.code`, which never changes with the language.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Locale {
+    En,
+    Ja,
+}
+
+impl Locale {
+    pub(crate) fn tag(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Ja => "ja",
+        }
+    }
+
+    // The supported language with the highest q-value in Accept-Language; English when there
+    // is none, e.g. for `fr` or a missing header
+    pub(crate) fn negotiate(accept_language: Option<&str>) -> Self {
+        accept_language
+            .unwrap_or_default()
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';');
+                let language = parts.next()?.trim().split('-').next()?;
+                let quality = match parts.find_map(|param| param.trim().strip_prefix("q=")) {
+                    Some(q) => q.parse::<f32>().ok()?,
+                    None => 1.0,
+                };
+                let locale = match language.to_ascii_lowercase().as_str() {
+                    "en" => Locale::En,
+                    "ja" => Locale::Ja,
+                    _ => return None,
+                };
+                (quality > 0.0).then_some((locale, quality))
+            })
+            .fold(
+                None,
+                |best: Option<(Locale, f32)>, (locale, quality)| match best {
+                    Some((_, best_quality)) if best_quality >= quality => best,
+                    _ => Some((locale, quality)),
+                },
+            )
+            .map_or(Locale::En, |(locale, _)| locale)
+    }
+
+    // Texts by validation code, with `{param}` standing for the rule's parameter. English
+    // comes from the rules themselves, so only other languages are listed.
+    pub(crate) fn template(self, code: &str) -> Option<&'static str> {
+        match (self, code) {
+            (Locale::En, _) => None,
+            (Locale::Ja, "validation_error") => Some("入力内容に誤りがあります"),
+            (Locale::Ja, "length_between") => Some("{min}〜{max}文字で入力してください"),
+            (Locale::Ja, "too_long") => Some("{max}文字以内で入力してください"),
+            (Locale::Ja, "invalid_email") => Some("有効なメールアドレスを入力してください"),
+            (Locale::Ja, "invalid_employee_id") => Some("英大文字3文字と数字3桁で入力してください"),
+            (Locale::Ja, "invalid_department_code") => Some("英大文字2〜10文字で入力してください"),
+            (Locale::Ja, "unsupported_currency") => Some("{allowed} のいずれかを指定してください"),
+            (Locale::Ja, "negative_salary") => Some("0以上の値を入力してください"),
+            (Locale::Ja, "invalid_decimal") => Some("数値を入力してください"),
+            (Locale::Ja, "required") => Some("必須項目のため null は指定できません"),
+            (Locale::Ja, "invalid_date") => Some("YYYY-MM-DD形式の日付を入力してください"),
+            (Locale::Ja, "unknown_department") => Some("存在する部署を指定してください"),
+            (Locale::Ja, "unknown_manager") => Some("存在する従業員を指定してください"),
+            (Locale::Ja, "self_manager") => Some("本人を上長に指定することはできません"),
+            (Locale::Ja, "reporting_cycle") => Some("上長の関係が循環します"),
+            (Locale::Ja, "idempotency_key_reused") => {
+                Some("このキーは別のリクエスト本文で使用済みです")
+            }
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn current_locale() -> Locale {
+    REQUEST_LOCALE
+        .try_with(|locale| *locale)
+        .unwrap_or(Locale::En)
+}
+
+pub(crate) fn localized(
+    code: &str,
+    params: &HashMap<std::borrow::Cow<'static, str>, serde_json::Value>,
+) -> Option<String> {
+    let template = current_locale().template(code)?;
+    Some(
+        params
+            .iter()
+            .fold(template.to_string(), |text, (name, value)| {
+                let value = value
+                    .as_str()
+                    .map_or_else(|| value.to_string(), str::to_string);
+                text.replace(&format!("{{{name}}}"), &value)
+            }),
+    )
+}
+
+// Validation messages depend on Accept-Language, so 422s say which language they are in
+pub(crate) async fn negotiate_locale(request: Request, next: Next) -> Response {
+    let locale = Locale::negotiate(
+        request
+            .headers()
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok()),
+    );
+    let mut response = REQUEST_LOCALE.scope(locale, next.run(request)).await;
+    if response.status() == StatusCode::UNPROCESSABLE_ENTITY {
+        let headers = response.headers_mut();
+        headers.insert(CONTENT_LANGUAGE, HeaderValue::from_static(locale.tag()));
+        headers.append(VARY, HeaderValue::from_static("accept-language"));
+    }
+    response
+}
@@ -0,0 +1,94 @@
+use axum::{
+    extract::{MatchedPath, Request},
+    http::HeaderValue,
+    middleware::Next,
+    response::Response,
+};
+use tracing::Span;
+use tracing_subscriber::EnvFilter;
+use uuid::Uuid;
+
+use crate::middleware::locale::Locale;
+
+pub(crate) fn current_request_id() -> Option<String> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+pub(crate) const X_REQUEST_ID: &str = "x-request-id";
+
+tokio::task_local! {
+    // Lets error responses echo the id without threading it through every handler
+    pub(crate) static CURRENT_REQUEST_ID: String;
+    // Language of validation messages, from Accept-Language
+    pub(crate) static REQUEST_LOCALE: Locale;
+}
+
+// Request extension holding the id for this request
+#[derive(Clone)]
+pub(crate) struct RequestId(pub(crate) String);
+
+// Reuses a client-supplied X-Request-Id when it is a UUID or ULID, otherwise mints a UUID
+pub(crate) async fn assign_request_id(mut request: Request, next: Next) -> Response {
+    let id = request
+        .headers()
+        .get(X_REQUEST_ID)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| is_valid_request_id(value))
+        .map_or_else(|| Uuid::new_v4().to_string(), str::to_string);
+
+    request.extensions_mut().insert(RequestId(id.clone()));
+    let mut response = CURRENT_REQUEST_ID
+        .scope(id.clone(), next.run(request))
+        .await;
+    if let Ok(value) = HeaderValue::from_str(&id) {
+        response.headers_mut().insert(X_REQUEST_ID, value);
+    }
+    response
+}
+
+pub(crate) fn is_valid_request_id(value: &str) -> bool {
+    const ULID_ALPHABET: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+    let is_ulid = value.len() == 26
+        && value
+            .chars()
+            .all(|c| ULID_ALPHABET.contains(c.to_ascii_uppercase()));
+    is_ulid || Uuid::parse_str(value).is_ok()
+}
+
+pub(crate) fn request_span(request: &Request) -> Span {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or("unmatched", MatchedPath::as_str);
+    let request_id = request
+        .extensions()
+        .get::<RequestId>()
+        .map_or("", |id| id.0.as_str());
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        route,
+        request_id,
+    )
+}
+
+// LOG_FORMAT=json for machine-readable production logs; anything else gets the pretty dev format
+pub fn init_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let builder = tracing_subscriber::fmt().with_env_filter(filter);
+    match std::env::var("LOG_FORMAT").as_deref() {
+        Ok("json") => builder.json().init(),
+        _ => builder.pretty().init(),
+    }
+}
+
+// axum's "/employees/:id" becomes the OpenAPI-style "/employees/{id}"
+pub(crate) fn route_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| match segment.strip_prefix(':') {
+            Some(param) => format!("{{{param}}}"),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
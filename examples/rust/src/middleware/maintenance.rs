@@ -0,0 +1,42 @@
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::Method,
+    middleware::Next,
+    response::Response,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::error::AppError;
+use crate::middleware::auth::READ_ONLY_POSTS;
+use crate::routes::v1;
+use crate::state::AppState;
+
+// Maintenance mode keeps reads going but refuses writes, e.g. while a migration runs. It starts
+// from MAINTENANCE_MODE and admins can flip it at runtime with POST /admin/maintenance.
+pub(crate) const MAINTENANCE_PATH: &str = "/admin/maintenance";
+
+#[derive(Deserialize, Serialize, ToSchema)]
+#[schema(example = json!({ "enabled": true }))]
+pub(crate) struct MaintenanceMode {
+    /// Whether writes are refused
+    pub(crate) enabled: bool,
+}
+
+// Runs after authentication, so callers without a valid token still get their 401
+pub(crate) async fn reject_writes_in_maintenance(
+    State(state): State<AppState>,
+    matched_path: MatchedPath,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let path = matched_path.as_str().trim_start_matches(v1::PREFIX);
+    let read = matches!(
+        *request.method(),
+        Method::GET | Method::HEAD | Method::OPTIONS
+    ) || (request.method() == Method::POST && READ_ONLY_POSTS.contains(&path));
+    if !read && path != MAINTENANCE_PATH {
+        state.ensure_writable()?;
+    }
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,69 @@
+use std::sync::OnceLock;
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+
+use crate::middleware::logging::route_template;
+
+// The recorder is process-global, so install it once no matter how many apps are built
+pub fn prometheus_handle() -> &'static PrometheusHandle {
+    static HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+    HANDLE.get_or_init(|| {
+        PrometheusBuilder::new()
+            .install_recorder()
+            .expect("failed to install Prometheus recorder")
+    })
+}
+
+// Holds one in-flight request on the gauge until dropped, so a request whose future is cancelled
+// (client disconnect, timeout) or panics is still taken off it
+pub(crate) struct InFlight(metrics::Gauge);
+
+impl InFlight {
+    pub(crate) fn start(gauge: metrics::Gauge) -> Self {
+        gauge.increment(1.0);
+        Self(gauge)
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.decrement(1.0);
+    }
+}
+
+pub async fn track_metrics(request: Request, next: Next) -> Response {
+    // Recording before a recorder is installed silently drops the sample
+    prometheus_handle();
+    let method = request.method().to_string();
+    // Label by route template, never the raw URL, to keep cardinality bounded
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "unmatched".to_string(), |p| route_template(p.as_str()));
+
+    let in_flight = InFlight::start(metrics::gauge!(
+        "http_requests_in_flight",
+        "method" => method.clone(),
+        "path" => path.clone()
+    ));
+    let start = Instant::now();
+    let response = next.run(request).await;
+    drop(in_flight);
+
+    let labels = [
+        ("method", method),
+        ("path", path),
+        ("status", response.status().as_u16().to_string()),
+    ];
+    metrics::counter!("http_requests_total", &labels).increment(1);
+    metrics::histogram!("http_request_duration_seconds", &labels)
+        .record(start.elapsed().as_secs_f64());
+
+    response
+}
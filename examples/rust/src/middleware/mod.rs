@@ -0,0 +1,10 @@
+pub mod auth;
+pub mod compression;
+pub mod cors;
+pub mod deprecation;
+pub mod errors;
+pub mod locale;
+pub mod logging;
+pub mod maintenance;
+pub mod metrics;
+pub mod rate_limit;
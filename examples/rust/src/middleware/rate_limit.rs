@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::Response,
+};
+use tokio::sync::Semaphore;
+
+use crate::error::AppError;
+use crate::middleware::auth::Claims;
+use crate::state::AppState;
+
+// Token bucket per client: `capacity` requests per `window`, refilled continuously
+pub(crate) struct RateLimiter {
+    pub(crate) capacity: f64,
+    pub(crate) refill_per_sec: f64,
+    pub(crate) buckets: Mutex<HashMap<String, TokenBucket>>,
+}
+
+pub(crate) struct TokenBucket {
+    pub(crate) tokens: f64,
+    pub(crate) refilled_at: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+    pub(crate) fn new(capacity: u32, window: Duration) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_sec: f64::from(capacity) / window.as_secs_f64(),
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    // Takes a token for `key`, or returns how long until one is available
+    pub(crate) fn acquire(&self, key: &str) -> Result<(), Duration> {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap_or_else(PoisonError::into_inner);
+        if buckets.len() >= Self::MAX_TRACKED_CLIENTS {
+            // Idle clients have refilled to capacity, so forgetting them changes nothing
+            buckets.retain(|_, bucket| {
+                let refilled = bucket.tokens
+                    + now.duration_since(bucket.refilled_at).as_secs_f64() * self.refill_per_sec;
+                refilled < self.capacity
+            });
+        }
+
+        let bucket = buckets.entry(key.to_string()).or_insert(TokenBucket {
+            tokens: self.capacity,
+            refilled_at: now,
+        });
+        let elapsed = now.duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        bucket.refilled_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / self.refill_per_sec,
+            ))
+        }
+    }
+}
+
+// Keys on the token subject when authenticated, otherwise on the peer address
+pub(crate) async fn enforce_rate_limit(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let key = match request.extensions().get::<Claims>() {
+        Some(claims) => format!("sub:{}", claims.sub),
+        None => request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map_or_else(
+                || "anonymous".to_string(),
+                |info| format!("ip:{}", info.0.ip()),
+            ),
+    };
+
+    state
+        .rate_limiter
+        .acquire(&key)
+        .map_err(|wait| AppError::RateLimited {
+            retry_after_secs: wait.as_secs().max(1),
+        })?;
+    Ok(next.run(request).await)
+}
+
+// A global ceiling on in-flight API requests, next to the per-client rate limit. Requests over
+// it are refused at once rather than queued, so a burst can't pile up behind a slow backend.
+// The permit is held until the response headers are produced, not while a body streams.
+pub(crate) const OVERLOAD_RETRY_AFTER_SECS: u64 = 1;
+
+pub(crate) async fn shed_load(
+    State(in_flight): State<Arc<Semaphore>>,
+    request: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    let Ok(_permit) = in_flight.try_acquire() else {
+        return Err(AppError::Overloaded {
+            retry_after_secs: OVERLOAD_RETRY_AFTER_SECS,
+        });
+    };
+    Ok(next.run(request).await)
+}
@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Serialize, ToSchema)]
+pub struct AuditEntry {
+    pub id: Uuid,
+    /// The employee's `id`, not its `employee_id` code
+    pub employee_id: Uuid,
+    pub action: AuditAction,
+    /// `sub` claim of the token that made the change
+    pub actor: String,
+    #[schema(format = "date-time")]
+    pub timestamp: chrono::DateTime<Utc>,
+    /// Fields that changed, with their old and new values; empty for creates and deletes
+    pub changes: BTreeMap<String, FieldChange>,
+}
+
+#[derive(Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditAction {
+    Created,
+    Updated,
+    Deleted,
+}
+
+impl AuditAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditAction::Created => "created",
+            AuditAction::Updated => "updated",
+            AuditAction::Deleted => "deleted",
+        }
+    }
+}
+
+#[derive(Clone, Serialize, ToSchema)]
+pub struct FieldChange {
+    #[schema(value_type = Object)]
+    pub old: serde_json::Value,
+    #[schema(value_type = Object)]
+    pub new: serde_json::Value,
+}
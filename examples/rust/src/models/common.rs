@@ -0,0 +1,452 @@
+use std::collections::HashMap;
+
+use axum::http::StatusCode;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use utoipa::openapi::schema::{Discriminator, OneOfBuilder, Ref, Schema};
+use utoipa::openapi::RefOr;
+use utoipa::{IntoResponses, ToSchema};
+
+use crate::middleware::locale::localized;
+use crate::models::audit::AuditEntry;
+use crate::models::departments::Department;
+use crate::models::employees::EmployeeWithDepartment;
+
+#[derive(Serialize, ToSchema)]
+pub struct ApiError {
+    pub error: ErrorDetails,
+}
+
+// Error responses shared by several operations, so their examples are written once. Use them in
+// `responses(...)` in place of a `(status = ..., body = ApiError)` tuple.
+
+/// Employee not found
+#[derive(IntoResponses)]
+#[response(status = 404, examples(
+    ("not_found" = (summary = "No employee with this ID", value = json!({
+        "error": {
+            "code": "not_found",
+            "message": "Employee 123e4567-e89b-12d3-a456-426614174000 not found",
+            "request_id": "9b2f6a4e-3c1d-4f8a-b7e5-2d6c8a1f4e90"
+        }
+    })))
+))]
+pub struct EmployeeNotFound(pub ApiError);
+
+/// Validation error; `details` lists every failing field
+#[derive(IntoResponses)]
+#[response(status = 422, examples(
+    ("validation_error" = (summary = "Fields failed validation", value = json!({
+        "error": {
+            "code": "validation_error",
+            "message": "Invalid input data",
+            "details": [
+                {"field": "email", "code": "invalid_email", "message": "must be a valid email address"},
+                {"field": "first_name", "code": "length_between", "message": "must be 1-100 characters"}
+            ],
+            "request_id": "9b2f6a4e-3c1d-4f8a-b7e5-2d6c8a1f4e90"
+        }
+    })))
+))]
+pub struct InvalidEmployee(pub ApiError);
+
+// Discriminated on `code`: validation errors list the failing fields, conflicts may name the
+// clashing one, and every other code carries only a message
+#[derive(Serialize)]
+#[serde(untagged)]
+pub enum ErrorDetails {
+    Validation(ValidationErrorDetails),
+    Conflict(ConflictErrorDetails),
+    General(GeneralErrorDetails),
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ValidationErrorDetails {
+    #[schema(inline)]
+    pub code: ValidationErrorCode,
+    pub message: String,
+    pub details: Vec<ValidationError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub request_id: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ConflictErrorDetails {
+    #[schema(inline)]
+    pub code: ConflictErrorCode,
+    pub message: String,
+    // Unique field that already holds this value; absent for state conflicts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub field: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub request_id: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct GeneralErrorDetails {
+    #[schema(inline)]
+    pub code: GeneralErrorCode,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub request_id: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationErrorCode {
+    ValidationError,
+}
+
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictErrorCode {
+    Conflict,
+}
+
+// The subset of ErrorCode carried by GeneralErrorDetails
+#[derive(Clone, Copy, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GeneralErrorCode {
+    BadRequest,
+    NotFound,
+    PreconditionFailed,
+    PreconditionRequired,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    NotAcceptable,
+    Unauthorized,
+    Forbidden,
+    RateLimited,
+    MethodNotAllowed,
+    ServiceUnavailable,
+    GatewayTimeout,
+    InternalError,
+}
+
+impl From<GeneralErrorCode> for ErrorCode {
+    fn from(code: GeneralErrorCode) -> Self {
+        match code {
+            GeneralErrorCode::BadRequest => ErrorCode::BadRequest,
+            GeneralErrorCode::NotFound => ErrorCode::NotFound,
+            GeneralErrorCode::PreconditionFailed => ErrorCode::PreconditionFailed,
+            GeneralErrorCode::PreconditionRequired => ErrorCode::PreconditionRequired,
+            GeneralErrorCode::PayloadTooLarge => ErrorCode::PayloadTooLarge,
+            GeneralErrorCode::UnsupportedMediaType => ErrorCode::UnsupportedMediaType,
+            GeneralErrorCode::NotAcceptable => ErrorCode::NotAcceptable,
+            GeneralErrorCode::Unauthorized => ErrorCode::Unauthorized,
+            GeneralErrorCode::Forbidden => ErrorCode::Forbidden,
+            GeneralErrorCode::RateLimited => ErrorCode::RateLimited,
+            GeneralErrorCode::MethodNotAllowed => ErrorCode::MethodNotAllowed,
+            GeneralErrorCode::ServiceUnavailable => ErrorCode::ServiceUnavailable,
+            GeneralErrorCode::GatewayTimeout => ErrorCode::GatewayTimeout,
+            GeneralErrorCode::InternalError => ErrorCode::InternalError,
+        }
+    }
+}
+
+// Every value `error.code` can take, listed at GET /error-codes. The details structs each use a
+// narrower enum so the spec shows which codes go with which shape.
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    ValidationError,
+    Conflict,
+    BadRequest,
+    NotFound,
+    PreconditionFailed,
+    PreconditionRequired,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    NotAcceptable,
+    Unauthorized,
+    Forbidden,
+    RateLimited,
+    MethodNotAllowed,
+    ServiceUnavailable,
+    GatewayTimeout,
+    InternalError,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 16] = [
+        ErrorCode::ValidationError,
+        ErrorCode::Conflict,
+        ErrorCode::BadRequest,
+        ErrorCode::NotFound,
+        ErrorCode::PreconditionFailed,
+        ErrorCode::PreconditionRequired,
+        ErrorCode::PayloadTooLarge,
+        ErrorCode::UnsupportedMediaType,
+        ErrorCode::NotAcceptable,
+        ErrorCode::Unauthorized,
+        ErrorCode::Forbidden,
+        ErrorCode::RateLimited,
+        ErrorCode::MethodNotAllowed,
+        ErrorCode::ServiceUnavailable,
+        ErrorCode::GatewayTimeout,
+        ErrorCode::InternalError,
+    ];
+
+    // Must agree with the serde renaming above; clients match on these strings
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::ValidationError => "validation_error",
+            ErrorCode::Conflict => "conflict",
+            ErrorCode::BadRequest => "bad_request",
+            ErrorCode::NotFound => "not_found",
+            ErrorCode::PreconditionFailed => "precondition_failed",
+            ErrorCode::PreconditionRequired => "precondition_required",
+            ErrorCode::PayloadTooLarge => "payload_too_large",
+            ErrorCode::UnsupportedMediaType => "unsupported_media_type",
+            ErrorCode::NotAcceptable => "not_acceptable",
+            ErrorCode::Unauthorized => "unauthorized",
+            ErrorCode::Forbidden => "forbidden",
+            ErrorCode::RateLimited => "rate_limited",
+            ErrorCode::MethodNotAllowed => "method_not_allowed",
+            ErrorCode::ServiceUnavailable => "service_unavailable",
+            ErrorCode::GatewayTimeout => "gateway_timeout",
+            ErrorCode::InternalError => "internal_error",
+        }
+    }
+
+    pub fn status(self) -> StatusCode {
+        match self {
+            ErrorCode::ValidationError => StatusCode::UNPROCESSABLE_ENTITY,
+            ErrorCode::Conflict => StatusCode::CONFLICT,
+            ErrorCode::BadRequest => StatusCode::BAD_REQUEST,
+            ErrorCode::NotFound => StatusCode::NOT_FOUND,
+            ErrorCode::PreconditionFailed => StatusCode::PRECONDITION_FAILED,
+            ErrorCode::PreconditionRequired => StatusCode::PRECONDITION_REQUIRED,
+            ErrorCode::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            ErrorCode::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            ErrorCode::NotAcceptable => StatusCode::NOT_ACCEPTABLE,
+            ErrorCode::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorCode::Forbidden => StatusCode::FORBIDDEN,
+            ErrorCode::RateLimited => StatusCode::TOO_MANY_REQUESTS,
+            ErrorCode::MethodNotAllowed => StatusCode::METHOD_NOT_ALLOWED,
+            ErrorCode::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorCode::GatewayTimeout => StatusCode::GATEWAY_TIMEOUT,
+            ErrorCode::InternalError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            ErrorCode::ValidationError => "The body failed validation; `details` lists each field and reason",
+            ErrorCode::Conflict => "A unique field already holds this value (named in `field`), or the record's state forbids the change",
+            ErrorCode::BadRequest => "Malformed request, query parameter or header",
+            ErrorCode::NotFound => "No resource with this ID",
+            ErrorCode::PreconditionFailed => "`If-Match` does not match the current ETag",
+            ErrorCode::PreconditionRequired => "This write needs an `If-Match` header",
+            ErrorCode::PayloadTooLarge => "The request body exceeds the size limit",
+            ErrorCode::UnsupportedMediaType => "The body's content type is not accepted here",
+            ErrorCode::NotAcceptable => "None of the `Accept`ed media types can be produced",
+            ErrorCode::Unauthorized => "Missing, invalid or expired bearer token",
+            ErrorCode::Forbidden => "The token lacks the role or scope this operation needs",
+            ErrorCode::RateLimited => "Too many requests; retry after `Retry-After` seconds",
+            ErrorCode::MethodNotAllowed => "The path exists but not with this method; see `Allow`",
+            ErrorCode::ServiceUnavailable => "Overloaded, in maintenance mode or a dependency is down; retry later",
+            ErrorCode::GatewayTimeout => "The request took longer than the server's timeout",
+            ErrorCode::InternalError => "Unexpected server error; report it with the `request_id`",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(example = json!({
+    "code": "not_found",
+    "status": 404,
+    "description": "No resource with this ID"
+}))]
+pub struct ErrorCodeInfo {
+    pub code: ErrorCode,
+    /// HTTP status sent with this code
+    pub status: u16,
+    pub description: &'static str,
+}
+
+// Written by hand because the derive cannot emit a discriminator; the mapping lets typed
+// clients pick the variant from `code` alone
+impl<'s> ToSchema<'s> for ErrorDetails {
+    fn schema() -> (&'s str, RefOr<Schema>) {
+        let mut discriminator = Discriminator::new("code");
+        discriminator.mapping = ErrorCode::ALL
+            .into_iter()
+            .map(|code| {
+                let schema = match code {
+                    ErrorCode::ValidationError => "ValidationErrorDetails",
+                    ErrorCode::Conflict => "ConflictErrorDetails",
+                    _ => "GeneralErrorDetails",
+                };
+                (code.to_string(), format!("#/components/schemas/{schema}"))
+            })
+            .collect();
+
+        let schema = OneOfBuilder::new()
+            .item(Ref::from_schema_name("ValidationErrorDetails"))
+            .item(Ref::from_schema_name("ConflictErrorDetails"))
+            .item(Ref::from_schema_name("GeneralErrorDetails"))
+            .discriminator(Some(discriminator))
+            .build();
+        ("ErrorDetails", Schema::OneOf(schema).into())
+    }
+}
+
+impl ErrorDetails {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ErrorDetails::Validation(_) => ErrorCode::ValidationError,
+            ErrorDetails::Conflict(_) => ErrorCode::Conflict,
+            ErrorDetails::General(details) => details.code.into(),
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ErrorDetails::Validation(details) => &details.message,
+            ErrorDetails::Conflict(details) => &details.message,
+            ErrorDetails::General(details) => &details.message,
+        }
+    }
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ValidationError {
+    pub field: String,
+    /// Stable reason, e.g. `length_between` or `invalid_email`; the same in every language
+    pub code: String,
+    /// Human-readable reason in the language negotiated from `Accept-Language`
+    pub message: String,
+}
+
+impl ValidationError {
+    // The message is translated for the request's locale when the catalog knows `code`;
+    // `message` is the English text and the fallback
+    pub fn new(field: impl Into<String>, code: &str, message: impl Into<String>) -> Self {
+        let message = localized(code, &HashMap::new()).unwrap_or_else(|| message.into());
+        Self {
+            field: field.into(),
+            code: code.to_string(),
+            message,
+        }
+    }
+
+    pub fn from_rule(field: &str, error: &validator::ValidationError) -> Self {
+        let message = localized(&error.code, &error.params).unwrap_or_else(|| {
+            error
+                .message
+                .as_ref()
+                .map_or_else(|| error.code.to_string(), |m| m.to_string())
+        });
+        Self {
+            field: field.to_string(),
+            code: error.code.to_string(),
+            message,
+        }
+    }
+
+    // For batch reports, which prefix the field with the row it came from
+    pub fn with_field(self, field: String) -> Self {
+        Self { field, ..self }
+    }
+}
+
+#[derive(Clone, Copy, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+// utoipa 4 can't register a bare generic; each alias becomes a concrete schema that
+// responses reference by name, so client generators see a resolved `data` item type.
+// Deserialize lets Rust clients and the integration tests read pages back.
+#[derive(Serialize, Deserialize, ToSchema)]
+#[aliases(
+    PaginatedEmployees = PaginatedResponse<EmployeeWithDepartment>,
+    PaginatedDepartments = PaginatedResponse<Department>,
+    PaginatedAuditEntries = PaginatedResponse<AuditEntry>
+)]
+pub struct PaginatedResponse<T> {
+    pub data: Vec<T>,
+    pub pagination: PaginationInfo,
+}
+
+// Shaped by the mode the request used; cursor pages have no page numbers
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum PaginationInfo {
+    Offset(OffsetPaginationInfo),
+    Cursor(CursorPaginationInfo),
+}
+
+impl PaginationInfo {
+    pub fn total(&self) -> u64 {
+        match self {
+            PaginationInfo::Offset(info) => info.total,
+            PaginationInfo::Cursor(info) => info.total,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct OffsetPaginationInfo {
+    pub page: u32,
+    pub per_page: u32,
+    pub total: u64,
+    pub total_pages: u32,
+}
+
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CursorPaginationInfo {
+    pub limit: u32,
+    /// Number of items matching the filters, across all pages
+    pub total: u64,
+    /// Whether items remain after this page
+    pub has_more: bool,
+    /// Opaque cursor for the next page; present when `has_more` is true
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub next_cursor: Option<String>,
+    /// Opaque cursor for the previous page; absent on the first page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub prev_cursor: Option<String>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(example = json!({ "status": "ok", "maintenance": false }))]
+pub struct HealthResponse {
+    pub status: String,
+    /// Whether maintenance mode is refusing writes
+    pub maintenance: bool,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(example = json!({
+    "name": "openapi-integration",
+    "version": "0.1.0",
+    "git_sha": "4968309c1f2e8b7d6a5f4e3d2c1b0a9f8e7d6c5b",
+    "build_time": "2024-04-01T09:30:00Z"
+}))]
+pub struct VersionInfo {
+    /// Crate name
+    pub name: &'static str,
+    /// Crate version from Cargo.toml
+    pub version: &'static str,
+    /// Commit the binary was built from; `unknown` outside a git checkout unless GIT_SHA is set
+    pub git_sha: &'static str,
+    /// When the binary was built, or SOURCE_DATE_EPOCH when set
+    pub build_time: chrono::DateTime<Utc>,
+}
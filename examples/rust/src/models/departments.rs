@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::utils::validation::DEPARTMENT_CODE_PATTERN;
+
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+#[cfg_attr(feature = "postgres", derive(sqlx::FromRow))]
+#[schema(example = json!({
+    "id": "6f1c2c8e-0b7a-4d8e-9a55-3f4a2b1c0d9e",
+    "name": "Engineering",
+    "code": "ENG"
+}))]
+pub struct Department {
+    pub id: Uuid,
+    #[schema(min_length = 1, max_length = 100)]
+    pub name: String,
+    #[schema(pattern = "^[A-Z]{2,10}$")]
+    pub code: String,
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+#[schema(example = json!({
+    "name": "Engineering",
+    "code": "ENG"
+}))]
+pub struct CreateDepartmentRequest {
+    #[schema(min_length = 1, max_length = 100)]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        code = "length_between",
+        message = "must be 1-100 characters"
+    ))]
+    pub name: String,
+    #[schema(pattern = "^[A-Z]{2,10}$")]
+    #[validate(regex(
+        path = "DEPARTMENT_CODE_PATTERN",
+        code = "invalid_department_code",
+        message = "must be 2-10 uppercase letters"
+    ))]
+    pub code: String,
+}
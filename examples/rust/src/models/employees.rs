@@ -0,0 +1,591 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use chrono::{NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::error::AppError;
+use crate::middleware::auth::Claims;
+use crate::models::common::{SortOrder, ValidationError};
+use crate::models::departments::Department;
+use crate::routes::v1;
+use crate::utils::validation::{
+    default_currency, validate_currency, validate_salary, EMPLOYEE_ID_PATTERN,
+};
+
+// How `Option` fields appear in the spec, here and in the other response types: ones that are
+// always serialized are `required = true` and nullable, so generated clients expect the key; ones
+// skipped when None get a non-Option `value_type` plus `serde(default)`, making them optional but
+// never null. Request types keep utoipa's default of optional and nullable, since null is accepted.
+#[derive(Clone, Serialize, Deserialize, ToSchema)]
+#[schema(example = json!({
+    "id": "123e4567-e89b-12d3-a456-426614174000",
+    "employee_id": "EMP001",
+    "first_name": "John",
+    "last_name": "Doe",
+    "email": "john.doe@company.com",
+    "department_id": null,
+    "manager_id": null,
+    "position": "Software Engineer",
+    "salary": "75000.50",
+    "currency": "USD",
+    "employment_status": "active",
+    "hire_date": "2024-01-15",
+    "created_at": "2024-01-15T10:30:00Z",
+    "updated_at": "2024-01-15T10:30:00Z",
+    "version": 1
+}))]
+pub struct Employee {
+    pub id: Uuid,
+    #[schema(pattern = "^[A-Z]{3}[0-9]{3}$")]
+    pub employee_id: String,
+    #[schema(min_length = 1, max_length = 100)]
+    pub first_name: String,
+    #[schema(min_length = 1, max_length = 100)]
+    pub last_name: String,
+    #[schema(format = "email")]
+    pub email: String,
+    #[schema(format = "uuid", required = true)]
+    pub department_id: Option<Uuid>,
+    /// The employee this one reports to
+    #[schema(format = "uuid", required = true)]
+    pub manager_id: Option<Uuid>,
+    #[schema(max_length = 100, required = true)]
+    pub position: Option<String>,
+    // Compensation is hidden by `redacted_for` from readers without `employees:read-sensitive`
+    // (or the admin role), which empties both fields so they are skipped
+    /// Annual gross salary as a decimal string, so no precision is lost. Only returned to admins and
+    /// tokens with the `employees:read-sensitive` scope
+    #[serde(
+        default,
+        with = "rust_decimal::serde::str_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[schema(value_type = String, format = "decimal", example = "75000.50")]
+    pub salary: Option<Decimal>,
+    /// ISO 4217 currency of `salary`; gated like `salary`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    #[schema(format = "currency", example = "USD")]
+    pub currency: String,
+    pub employment_status: EmploymentStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String, max_length = 500)]
+    pub termination_reason: Option<String>,
+    #[schema(format = "date")]
+    pub hire_date: NaiveDate,
+    #[schema(format = "date-time")]
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    #[schema(format = "date-time")]
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Starts at 1 and increments on every write; drives the ETag used by If-Match
+    #[schema(minimum = 1)]
+    pub version: u64,
+}
+
+impl Employee {
+    pub fn from_request(payload: CreateEmployeeRequest, now: chrono::DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            employee_id: payload.employee_id,
+            first_name: payload.first_name,
+            last_name: payload.last_name,
+            email: payload.email,
+            department_id: payload.department_id,
+            manager_id: payload.manager_id,
+            position: payload.position,
+            salary: payload.salary,
+            currency: payload.currency,
+            employment_status: payload.employment_status.unwrap_or_default(),
+            termination_reason: None,
+            hire_date: payload.hire_date,
+            created_at: now,
+            updated_at: now,
+            version: 1,
+        }
+    }
+
+    // A full replacement keeps the identity, status and creation time
+    pub fn replace_with(&mut self, payload: CreateEmployeeRequest) {
+        self.employee_id = payload.employee_id;
+        self.first_name = payload.first_name;
+        self.last_name = payload.last_name;
+        self.email = payload.email;
+        self.department_id = payload.department_id;
+        self.manager_id = payload.manager_id;
+        self.position = payload.position;
+        self.salary = payload.salary;
+        self.currency = payload.currency;
+        self.hire_date = payload.hire_date;
+    }
+
+    // Call after every mutation so the ETag changes with the record
+    pub fn touch(&mut self, now: chrono::DateTime<Utc>) {
+        self.version += 1;
+        self.updated_at = now;
+    }
+
+    // Compensation is only shown to admins and `employees:read-sensitive` tokens
+    pub fn redacted_for(mut self, claims: &Claims) -> Self {
+        if !claims.can_read_sensitive() {
+            self.salary = None;
+            self.currency.clear();
+        }
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EmploymentStatus {
+    #[default]
+    Active,
+    Inactive,
+    Terminated,
+}
+
+impl FromStr for EmploymentStatus {
+    type Err = AppError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "active" => Ok(Self::Active),
+            "inactive" => Ok(Self::Inactive),
+            "terminated" => Ok(Self::Terminated),
+            other => Err(AppError::BadRequest(format!(
+                "Unknown status '{other}'; expected active, inactive or terminated"
+            ))),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum StatusTransition {
+    Terminate,
+    Reactivate,
+}
+
+impl EmploymentStatus {
+    // Same spelling as the serde representation, for places that bypass serde
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EmploymentStatus::Active => "active",
+            EmploymentStatus::Inactive => "inactive",
+            EmploymentStatus::Terminated => "terminated",
+        }
+    }
+
+    // The only legal moves: anyone not terminated can be terminated, anyone not active can be reactivated
+    pub fn apply(self, transition: StatusTransition) -> Result<Self, AppError> {
+        match (self, transition) {
+            (EmploymentStatus::Terminated, StatusTransition::Terminate) => Err(AppError::Conflict(
+                "Employee is already terminated".to_string(),
+            )),
+            (_, StatusTransition::Terminate) => Ok(EmploymentStatus::Terminated),
+            (EmploymentStatus::Active, StatusTransition::Reactivate) => {
+                Err(AppError::Conflict("Employee is already active".to_string()))
+            }
+            (_, StatusTransition::Reactivate) => Ok(EmploymentStatus::Active),
+        }
+    }
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+#[schema(example = json!({
+    "reason": "End of contract"
+}))]
+pub struct TerminateEmployeeRequest {
+    #[schema(max_length = 500)]
+    #[validate(length(
+        max = 500,
+        code = "too_long",
+        message = "must be at most 500 characters"
+    ))]
+    pub reason: Option<String>,
+}
+
+// Serialize gives idempotent creates a canonical fingerprint of the request
+#[derive(Deserialize, Serialize, ToSchema, Validate)]
+#[schema(example = json!({
+    "employee_id": "EMP001",
+    "first_name": "John",
+    "last_name": "Doe",
+    "email": "john.doe@company.com",
+    "hire_date": "2024-01-15"
+}))]
+pub struct CreateEmployeeRequest {
+    #[schema(pattern = "^[A-Z]{3}[0-9]{3}$")]
+    #[validate(regex(
+        path = "EMPLOYEE_ID_PATTERN",
+        code = "invalid_employee_id",
+        message = "must be three uppercase letters followed by three digits"
+    ))]
+    pub employee_id: String,
+    #[schema(min_length = 1, max_length = 100)]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        code = "length_between",
+        message = "must be 1-100 characters"
+    ))]
+    pub first_name: String,
+    #[schema(min_length = 1, max_length = 100)]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        code = "length_between",
+        message = "must be 1-100 characters"
+    ))]
+    pub last_name: String,
+    /// Unique ignoring case; stored with the casing given, minus surrounding whitespace
+    #[serde(deserialize_with = "trimmed")]
+    #[schema(format = "email")]
+    #[validate(email(code = "invalid_email", message = "must be a valid email address"))]
+    pub email: String,
+    #[schema(format = "uuid")]
+    pub department_id: Option<Uuid>,
+    /// Must reference an existing employee other than this one, without forming a cycle
+    #[schema(format = "uuid")]
+    pub manager_id: Option<Uuid>,
+    #[schema(max_length = 100)]
+    #[validate(length(
+        max = 100,
+        code = "too_long",
+        message = "must be at most 100 characters"
+    ))]
+    pub position: Option<String>,
+    /// Annual gross salary as a decimal string, e.g. `"75000.50"`
+    #[serde(default, with = "rust_decimal::serde::str_option")]
+    #[schema(value_type = Option<String>, format = "decimal", example = "75000.50")]
+    #[validate(custom = "validate_salary")]
+    pub salary: Option<Decimal>,
+    /// ISO 4217 currency of `salary`, defaulting to `USD`
+    #[serde(default = "default_currency")]
+    #[schema(format = "currency", default = "USD", example = "USD")]
+    #[validate(custom = "validate_currency")]
+    pub currency: String,
+    /// Initial status, defaulting to `active`; ignored by updates
+    pub employment_status: Option<EmploymentStatus>,
+    #[schema(format = "date")]
+    pub hire_date: NaiveDate,
+}
+
+#[derive(Deserialize, ToSchema, Validate)]
+#[schema(example = json!({
+    "position": "Senior Software Engineer",
+    "department_id": null
+}))]
+pub struct PatchEmployeeRequest {
+    // Required fields can be changed but not removed, so a null here is answered with a 422
+    // by `null_required_fields`; the schema documents them as non-nullable
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = String, pattern = "^[A-Z]{3}[0-9]{3}$")]
+    #[validate(regex(
+        path = "EMPLOYEE_ID_PATTERN",
+        code = "invalid_employee_id",
+        message = "must be three uppercase letters followed by three digits"
+    ))]
+    pub employee_id: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = String, min_length = 1, max_length = 100)]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        code = "length_between",
+        message = "must be 1-100 characters"
+    ))]
+    pub first_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = String, min_length = 1, max_length = 100)]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        code = "length_between",
+        message = "must be 1-100 characters"
+    ))]
+    pub last_name: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null_trimmed")]
+    #[schema(value_type = String, format = "email")]
+    #[validate(email(code = "invalid_email", message = "must be a valid email address"))]
+    pub email: Option<Option<String>>,
+    // Outer None = field omitted, Some(None) = explicitly null (clear the value)
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = Option<Uuid>, format = "uuid")]
+    pub department_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = Option<Uuid>, format = "uuid")]
+    pub manager_id: Option<Option<Uuid>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = Option<String>, max_length = 100)]
+    #[validate(length(
+        max = 100,
+        code = "too_long",
+        message = "must be at most 100 characters"
+    ))]
+    pub position: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null_decimal")]
+    #[schema(value_type = Option<String>, format = "decimal", example = "75000.50")]
+    #[validate(custom = "validate_salary")]
+    pub salary: Option<Option<Decimal>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = String, format = "currency", example = "USD")]
+    #[validate(custom = "validate_currency")]
+    pub currency: Option<Option<String>>,
+    #[serde(default, deserialize_with = "explicit_null")]
+    #[schema(value_type = String, format = "date")]
+    pub hire_date: Option<Option<NaiveDate>>,
+}
+
+impl PatchEmployeeRequest {
+    // RFC 7396 reads null as "remove the member", which an employee can't do without these
+    pub fn null_required_fields(&self) -> Vec<ValidationError> {
+        [
+            ("currency", matches!(self.currency, Some(None))),
+            ("email", matches!(self.email, Some(None))),
+            ("employee_id", matches!(self.employee_id, Some(None))),
+            ("first_name", matches!(self.first_name, Some(None))),
+            ("hire_date", matches!(self.hire_date, Some(None))),
+            ("last_name", matches!(self.last_name, Some(None))),
+        ]
+        .into_iter()
+        .filter(|(_, is_null)| *is_null)
+        .map(|(field, _)| ValidationError::new(field, "required", "is required and cannot be null"))
+        .collect()
+    }
+}
+
+// Only called when the key is present, so a JSON null becomes Some(None)
+pub fn explicit_null<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+// As `explicit_null`, but decimals must be strings so JSON numbers can't lose precision
+pub fn explicit_null_decimal<'de, D>(deserializer: D) -> Result<Option<Option<Decimal>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    rust_decimal::serde::str_option::deserialize(deserializer).map(Some)
+}
+
+// Runs before validation, so " john@company.com" is accepted as "john@company.com"
+pub fn trimmed<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    String::deserialize(deserializer).map(|value| value.trim().to_string())
+}
+
+// `explicit_null` for strings that are trimmed like `trimmed`
+pub fn explicit_null_trimmed<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    trimmed_option(deserializer).map(Some)
+}
+
+pub fn trimmed_option<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)
+        .map(|value| value.map(|value| value.trim().to_string()))
+}
+
+// What email uniqueness is decided on: John.Doe@Company.com and john.doe@company.com are the
+// same person. The postgres schema indexes lower(email) to match.
+pub fn email_key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+// Employee response view; `department` is only present when requested via ?expand=department,
+// `_links` only when HYPERMEDIA_LINKS is enabled
+#[derive(Serialize, ToSchema)]
+pub struct EmployeeWithDepartment {
+    #[serde(flatten)]
+    pub employee: Employee,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Department)]
+    pub department: Option<Department>,
+    #[serde(rename = "_links", default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = EmployeeLinks)]
+    pub links: Option<EmployeeLinks>,
+}
+
+impl EmployeeWithDepartment {
+    pub fn new(employee: Employee, departments: Option<&HashMap<Uuid, Department>>) -> Self {
+        let department = departments
+            .zip(employee.department_id)
+            .and_then(|(departments, id)| departments.get(&id).cloned());
+        Self {
+            employee,
+            department,
+            links: None,
+        }
+    }
+
+    pub fn with_links(mut self, base_url: Option<&str>) -> Self {
+        self.links = base_url.map(|base| EmployeeLinks::new(base, &self.employee));
+        self
+    }
+}
+
+// HAL-style (`{"href": ...}`) links to the employee and the actions available on it
+#[derive(Serialize, ToSchema)]
+pub struct EmployeeLinks {
+    #[serde(rename = "self")]
+    pub self_link: Link,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = Link)]
+    pub department: Option<Link>,
+    pub terminate: Link,
+    pub photo: Link,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct Link {
+    pub href: String,
+}
+
+impl EmployeeLinks {
+    pub fn new(base_url: &str, employee: &Employee) -> Self {
+        let link = |path: String| Link {
+            href: format!("{base_url}{path}"),
+        };
+        let employee_path = format!("{}/employees/{}", v1::PREFIX, employee.id);
+        Self {
+            self_link: link(employee_path.clone()),
+            department: employee
+                .department_id
+                .map(|id| link(format!("{}/departments/{id}", v1::PREFIX))),
+            terminate: link(format!("{employee_path}/terminate")),
+            photo: link(format!("{employee_path}/photo")),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SearchParams {
+    pub q: Option<String>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(example = json!({ "count": 42 }))]
+pub struct EmployeeCount {
+    /// Number of employees matching the filters
+    pub count: u64,
+}
+
+#[derive(Deserialize)]
+pub struct DryRunParams {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// What a dry-run create or update would have stored
+#[derive(Serialize, ToSchema)]
+pub struct DryRunResult {
+    /// Always `true`; nothing was stored
+    pub dry_run: bool,
+    #[serde(flatten)]
+    pub employee: EmployeeWithDepartment,
+}
+
+// Documentation-only schema for one RFC 6902 operation
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct JsonPatchOperation {
+    /// `add`, `remove`, `replace`, `move`, `copy` or `test`
+    #[schema(example = "replace")]
+    pub op: String,
+    #[schema(example = "/position")]
+    pub path: String,
+    /// Source path for `move` and `copy`
+    pub from: Option<String>,
+    /// New value for `add` and `replace`, expected value for `test`
+    #[schema(value_type = Option<Object>)]
+    pub value: Option<serde_json::Value>,
+}
+
+// Every query parameter of GET /employees; the path docs are generated from this struct. The
+// maximum and default of `per_page` and `limit` are filled in from the configured PageLimits.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListEmployeesQuery {
+    /// Page number (offset mode)
+    #[param(minimum = 1, default = 1)]
+    pub page: Option<u32>,
+    /// Items per page (offset mode)
+    #[param(minimum = 1)]
+    pub per_page: Option<u32>,
+    /// Opaque `next_cursor` or `prev_cursor` from a previous response (cursor mode)
+    pub cursor: Option<String>,
+    /// Items per page (cursor mode)
+    #[param(minimum = 1)]
+    pub limit: Option<u32>,
+    /// Sort field: `last_name`, `hire_date` or `created_at`
+    #[param(default = "last_name")]
+    pub sort_by: Option<String>,
+    /// Sort direction
+    pub order: Option<SortOrder>,
+    /// Related data to embed; only `department` is supported
+    pub expand: Option<String>,
+    /// Filter by department code or name (case-insensitive)
+    pub department: Option<String>,
+    /// Filter by employment status; comma-separated for several, e.g. `active,inactive`
+    pub status: Option<String>,
+    /// Only employees hired on or after this date
+    #[param(value_type = Option<String>, format = Date)]
+    pub hired_after: Option<NaiveDate>,
+    /// Only employees hired on or before this date
+    #[param(value_type = Option<String>, format = Date)]
+    pub hired_before: Option<NaiveDate>,
+    /// Comma-separated properties to return per employee; `id` is always included
+    pub fields: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct ExpandParams {
+    pub expand: Option<String>,
+    pub fields: Option<String>,
+}
+
+#[derive(Clone, Copy)]
+pub enum SortField {
+    LastName,
+    HireDate,
+    CreatedAt,
+}
+
+impl SortField {
+    pub fn parse(value: Option<&str>) -> Result<Self, AppError> {
+        match value.unwrap_or("last_name") {
+            "last_name" => Ok(Self::LastName),
+            "hire_date" => Ok(Self::HireDate),
+            "created_at" => Ok(Self::CreatedAt),
+            other => Err(AppError::BadRequest(format!(
+                "Unknown sort_by '{other}'; expected last_name, hire_date or created_at"
+            ))),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PageParams {
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct DeleteParams {
+    #[serde(default)]
+    pub soft: bool,
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::models::common::ValidationError;
+use crate::models::employees::EmployeeWithDepartment;
+
+#[derive(Serialize, ToSchema)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    pub succeeded: Vec<ImportedRow>,
+    pub failed: Vec<FailedRow>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct ImportedRow {
+    /// Line in the CSV file; the header is line 1
+    pub line: u64,
+    /// Id of the created employee; not stored on a dry run
+    pub id: Uuid,
+    pub employee_id: String,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct FailedRow {
+    /// Line in the CSV file; the header is line 1
+    pub line: u64,
+    pub errors: Vec<ValidationError>,
+}
+
+pub const MAX_BULK_ROWS: usize = 1000;
+
+#[derive(Deserialize)]
+pub struct BulkParams {
+    #[serde(default)]
+    pub mode: BulkMode,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkMode {
+    #[default]
+    Atomic,
+    Partial,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct BulkCreateReport {
+    pub created: Vec<EmployeeWithDepartment>,
+    /// Always empty in atomic mode
+    pub errors: Vec<BulkRowError>,
+}
+
+#[derive(Serialize, ToSchema)]
+pub struct BulkRowError {
+    /// Position of the row in the request array, starting at 0
+    pub index: usize,
+    pub errors: Vec<ValidationError>,
+}
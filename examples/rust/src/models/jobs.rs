@@ -0,0 +1,55 @@
+use chrono::Utc;
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Clone, Copy, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    /// Queued; the worker has not picked it up yet
+    Pending,
+    /// The worker is generating the file
+    Running,
+    /// The file is ready at `download_url`
+    Completed,
+    /// Gave up; `error` says why
+    Failed,
+}
+
+impl JobStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Clone, Serialize, ToSchema)]
+#[schema(example = json!({
+    "id": "5f0c2a9e-8d4b-4c61-9a3e-7b1f2d6e8c40",
+    "status": "completed",
+    "created_at": "2024-01-15T10:30:00Z",
+    "finished_at": "2024-01-15T10:30:02Z",
+    "download_url": "https://api.example.com/v1/jobs/5f0c2a9e-8d4b-4c61-9a3e-7b1f2d6e8c40/download"
+}))]
+pub struct Job {
+    pub id: Uuid,
+    pub status: JobStatus,
+    #[schema(format = "date-time")]
+    pub created_at: chrono::DateTime<Utc>,
+    /// When the job completed or failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String, format = "date-time")]
+    pub finished_at: Option<chrono::DateTime<Utc>>,
+    /// Where to fetch the file; only once `completed`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub download_url: Option<String>,
+    /// Why the job failed; only once `failed`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schema(value_type = String)]
+    pub error: Option<String>,
+}
@@ -0,0 +1,7 @@
+pub mod audit;
+pub mod common;
+pub mod departments;
+pub mod employees;
+pub mod imports;
+pub mod jobs;
+pub mod photos;
@@ -0,0 +1,25 @@
+use chrono::Utc;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+// Documentation-only schemas for the binary photo payloads
+#[allow(dead_code)]
+#[derive(ToSchema)]
+pub struct PhotoUpload {
+    /// JPEG or PNG image, at most 5 MiB
+    #[schema(value_type = String, format = Binary)]
+    pub photo: Vec<u8>,
+}
+
+#[derive(Serialize, ToSchema)]
+#[schema(example = json!({
+    "url": "https://photos.s3.amazonaws.com/photos/123e4567-e89b-12d3-a456-426614174000?X-Amz-Expires=300&X-Amz-Signature=...",
+    "expires_at": "2024-01-15T10:35:00Z"
+}))]
+pub struct PhotoUrl {
+    /// Presigned storage URL, or the API's own photo endpoint when storage cannot presign
+    pub url: String,
+    /// When a presigned `url` stops working; null for the photo endpoint, which takes the usual bearer token
+    #[schema(format = "date-time", required = true)]
+    pub expires_at: Option<chrono::DateTime<Utc>>,
+}
@@ -0,0 +1,74 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::models::employees::Employee;
+use crate::repository::{EmployeeRepository, RepoError};
+
+// Read-through cache for single-employee lookups, in front of any repository. Writes made
+// through it evict what they touch; writes by other instances are only seen once the TTL
+// expires, so keep CACHE_TTL_SECS short when running more than one.
+pub(crate) struct CachingRepository {
+    pub(crate) inner: Arc<dyn EmployeeRepository>,
+    pub(crate) cache: moka::future::Cache<Uuid, Employee>,
+}
+
+impl CachingRepository {
+    pub(crate) fn new(
+        inner: Arc<dyn EmployeeRepository>,
+        ttl: Duration,
+        max_capacity: u64,
+    ) -> Self {
+        Self {
+            inner,
+            cache: moka::future::Cache::builder()
+                .time_to_live(ttl)
+                .max_capacity(max_capacity)
+                .build(),
+        }
+    }
+}
+
+#[async_trait]
+impl EmployeeRepository for CachingRepository {
+    async fn list(&self) -> Result<Vec<Employee>, RepoError> {
+        self.inner.list().await
+    }
+
+    // Misses aren't cached, so a just-created employee is visible right away
+    async fn get(&self, id: Uuid) -> Result<Option<Employee>, RepoError> {
+        if let Some(employee) = self.cache.get(&id).await {
+            return Ok(Some(employee));
+        }
+        let employee = self.inner.get(id).await?;
+        if let Some(employee) = &employee {
+            self.cache.insert(id, employee.clone()).await;
+        }
+        Ok(employee)
+    }
+
+    async fn create(&self, employee: Employee) -> Result<Employee, RepoError> {
+        self.inner.create(employee).await
+    }
+
+    async fn update(&self, employee: Employee) -> Result<Employee, RepoError> {
+        let id = employee.id;
+        let result = self.inner.update(employee).await;
+        // Also on failure: a version mismatch means the cached copy is stale
+        self.cache.invalidate(&id).await;
+        result
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        self.inner.delete(id).await?;
+        // Deleting a manager rewrites their reports too; deletes are rare enough to clear all
+        self.cache.invalidate_all();
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), RepoError> {
+        self.inner.ping().await
+    }
+}
@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::clock::Clock;
+use crate::models::departments::Department;
+use crate::models::employees::{email_key, Employee};
+use crate::repository::{DepartmentRepository, EmployeeRepository, RepoError};
+
+pub(crate) struct InMemoryRepository {
+    pub(crate) employees: RwLock<HashMap<Uuid, Employee>>,
+    pub(crate) clock: Arc<dyn Clock>,
+}
+
+impl InMemoryRepository {
+    pub(crate) fn new(
+        employees: impl IntoIterator<Item = Employee>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            employees: RwLock::new(employees.into_iter().map(|e| (e.id, e)).collect()),
+            clock,
+        }
+    }
+
+    // employee_id and email are logically unique even though the store is keyed by id; emails
+    // are compared by `email_key`
+    pub(crate) fn ensure_unique(
+        employees: &HashMap<Uuid, Employee>,
+        candidate: &Employee,
+    ) -> Result<(), RepoError> {
+        let email = email_key(&candidate.email);
+        let existing = employees
+            .values()
+            .filter(|e| e.id != candidate.id)
+            .find(|e| e.employee_id == candidate.employee_id || email_key(&e.email) == email);
+        match existing {
+            Some(existing) if existing.employee_id == candidate.employee_id => {
+                Err(RepoError::Conflict("employee_id"))
+            }
+            Some(_) => Err(RepoError::Conflict("email")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[async_trait]
+impl EmployeeRepository for InMemoryRepository {
+    async fn list(&self) -> Result<Vec<Employee>, RepoError> {
+        Ok(self.employees.read()?.values().cloned().collect())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Employee>, RepoError> {
+        Ok(self.employees.read()?.get(&id).cloned())
+    }
+
+    async fn create(&self, employee: Employee) -> Result<Employee, RepoError> {
+        let mut employees = self.employees.write()?;
+        Self::ensure_unique(&employees, &employee)?;
+        employees.insert(employee.id, employee.clone());
+        Ok(employee)
+    }
+
+    async fn update(&self, mut employee: Employee) -> Result<Employee, RepoError> {
+        let mut employees = self.employees.write()?;
+        let stored = employees
+            .get(&employee.id)
+            .ok_or(RepoError::NotFound(employee.id))?;
+        if stored.version != employee.version {
+            return Err(RepoError::VersionMismatch(employee.id));
+        }
+        Self::ensure_unique(&employees, &employee)?;
+
+        employee.touch(self.clock.now());
+        employees.insert(employee.id, employee.clone());
+        Ok(employee)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        let mut employees = self.employees.write()?;
+        employees.remove(&id).ok_or(RepoError::NotFound(id))?;
+        // Direct reports are left without a manager rather than pointing at nobody
+        let now = self.clock.now();
+        for report in employees.values_mut().filter(|e| e.manager_id == Some(id)) {
+            report.manager_id = None;
+            report.touch(now);
+        }
+        Ok(())
+    }
+
+    async fn ping(&self) -> Result<(), RepoError> {
+        if self.employees.is_poisoned() {
+            Err(RepoError::Unavailable(
+                "Data store is unavailable".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct InMemoryDepartmentRepository {
+    pub(crate) departments: RwLock<HashMap<Uuid, Department>>,
+}
+
+impl InMemoryDepartmentRepository {
+    pub(crate) fn new(departments: impl IntoIterator<Item = Department>) -> Self {
+        Self {
+            departments: RwLock::new(departments.into_iter().map(|d| (d.id, d)).collect()),
+        }
+    }
+}
+
+#[async_trait]
+impl DepartmentRepository for InMemoryDepartmentRepository {
+    async fn list(&self) -> Result<Vec<Department>, RepoError> {
+        Ok(self.departments.read()?.values().cloned().collect())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Department>, RepoError> {
+        Ok(self.departments.read()?.get(&id).cloned())
+    }
+
+    async fn create(&self, department: Department) -> Result<Department, RepoError> {
+        let mut departments = self.departments.write()?;
+        if departments.values().any(|d| d.code == department.code) {
+            return Err(RepoError::Conflict("code"));
+        }
+        departments.insert(department.id, department.clone());
+        Ok(department)
+    }
+
+    async fn ping(&self) -> Result<(), RepoError> {
+        if self.departments.is_poisoned() {
+            Err(RepoError::Unavailable(
+                "Data store is unavailable".to_string(),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
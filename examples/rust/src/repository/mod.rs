@@ -0,0 +1,63 @@
+pub mod cache;
+pub mod memory;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+use std::sync::PoisonError;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::models::departments::Department;
+use crate::models::employees::Employee;
+
+// Storage behind the employee handlers. Implementations own the invariants that need
+// atomicity: unique employee_id/email, and rejecting stale writes via `version`.
+#[async_trait]
+pub trait EmployeeRepository: Send + Sync {
+    async fn list(&self) -> Result<Vec<Employee>, RepoError>;
+
+    async fn get(&self, id: Uuid) -> Result<Option<Employee>, RepoError>;
+
+    async fn create(&self, employee: Employee) -> Result<Employee, RepoError>;
+
+    // Stores `employee` only if the stored version still equals `employee.version`, then
+    // returns it with the version bumped and updated_at refreshed
+    async fn update(&self, employee: Employee) -> Result<Employee, RepoError>;
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError>;
+
+    // Readiness probe; backends with a connection to check should override it
+    async fn ping(&self) -> Result<(), RepoError> {
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum RepoError {
+    NotFound(Uuid),
+    // Names the unique field that clashed: `employee_id` or `email`
+    Conflict(&'static str),
+    VersionMismatch(Uuid),
+    Unavailable(String),
+}
+
+impl<T> From<PoisonError<T>> for RepoError {
+    fn from(_: PoisonError<T>) -> Self {
+        RepoError::Unavailable("Data store is unavailable".to_string())
+    }
+}
+
+#[async_trait]
+pub trait DepartmentRepository: Send + Sync {
+    async fn list(&self) -> Result<Vec<Department>, RepoError>;
+
+    async fn get(&self, id: Uuid) -> Result<Option<Department>, RepoError>;
+
+    // Department codes are unique; a clash is `RepoError::Conflict("code")`
+    async fn create(&self, department: Department) -> Result<Department, RepoError>;
+
+    async fn ping(&self) -> Result<(), RepoError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,280 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::clock::Clock;
+use crate::models::departments::Department;
+use crate::models::employees::Employee;
+use crate::repository::{DepartmentRepository, EmployeeRepository, RepoError};
+
+// Production reference backend. Enable with `--features postgres`; DATABASE_URL selects it
+// at startup, and the schema lives in migrations/.
+
+use sqlx::migrate::Migrate;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::Row;
+
+// Applies pending migrations from migrations/ and returns how many ran
+pub async fn run_migrations(pool: &PgPool) -> anyhow::Result<usize> {
+    let migrator = sqlx::migrate!("./migrations");
+
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    let applied: HashSet<i64> = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|migration| migration.version)
+        .collect();
+    drop(conn);
+    let pending = migrator
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .filter(|migration| !applied.contains(&migration.version))
+        .count();
+
+    migrator
+        .run(pool)
+        .await
+        .context("failed to run database migrations")?;
+    Ok(pending)
+}
+
+pub(crate) const EMPLOYEE_COLUMNS: &str =
+    "id, employee_id, first_name, last_name, email, department_id, \
+    manager_id, position, salary, currency, employment_status, termination_reason, hire_date, \
+    created_at, updated_at, version";
+
+pub struct PgEmployeeRepository {
+    pub(crate) pool: PgPool,
+    // Stamps updated_at, rather than the database's now(), so both stores agree with AppState
+    pub(crate) clock: Arc<dyn Clock>,
+}
+
+impl PgEmployeeRepository {
+    pub fn new(pool: PgPool, clock: Arc<dyn Clock>) -> Self {
+        Self { pool, clock }
+    }
+}
+
+pub(crate) fn employee_from_row(row: &PgRow) -> Result<Employee, sqlx::Error> {
+    let status: String = row.try_get("employment_status")?;
+    let version: i64 = row.try_get("version")?;
+    Ok(Employee {
+        id: row.try_get("id")?,
+        employee_id: row.try_get("employee_id")?,
+        first_name: row.try_get("first_name")?,
+        last_name: row.try_get("last_name")?,
+        email: row.try_get("email")?,
+        department_id: row.try_get("department_id")?,
+        manager_id: row.try_get("manager_id")?,
+        position: row.try_get("position")?,
+        salary: row.try_get("salary")?,
+        currency: row.try_get("currency")?,
+        employment_status: status.parse().map_err(|_| sqlx::Error::ColumnDecode {
+            index: "employment_status".to_string(),
+            source: format!("unknown employment status {status:?}").into(),
+        })?,
+        termination_reason: row.try_get("termination_reason")?,
+        hire_date: row.try_get("hire_date")?,
+        created_at: row.try_get("created_at")?,
+        updated_at: row.try_get("updated_at")?,
+        version: version.try_into().unwrap_or_default(),
+    })
+}
+
+// Unique violations become Conflict naming the column; anything else is logged and
+// reported without leaking database details to the client
+pub(crate) fn repo_error(err: sqlx::Error) -> RepoError {
+    if let sqlx::Error::Database(db) = &err {
+        if db.code().as_deref() == Some("23505") {
+            match db.constraint() {
+                Some("employees_employee_id_key") => return RepoError::Conflict("employee_id"),
+                Some("employees_email_key") => return RepoError::Conflict("email"),
+                Some("departments_code_key") => return RepoError::Conflict("code"),
+                _ => {}
+            }
+        }
+    }
+    tracing::error!(error = %err, "database query failed");
+    RepoError::Unavailable("Data store is unavailable".to_string())
+}
+
+#[async_trait]
+impl EmployeeRepository for PgEmployeeRepository {
+    async fn list(&self) -> Result<Vec<Employee>, RepoError> {
+        let rows = sqlx::query(&format!("SELECT {EMPLOYEE_COLUMNS} FROM employees"))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(repo_error)?;
+        rows.iter()
+            .map(employee_from_row)
+            .collect::<Result<_, _>>()
+            .map_err(repo_error)
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Employee>, RepoError> {
+        let row = sqlx::query(&format!(
+            "SELECT {EMPLOYEE_COLUMNS} FROM employees WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(repo_error)?;
+        row.as_ref()
+            .map(employee_from_row)
+            .transpose()
+            .map_err(repo_error)
+    }
+
+    async fn create(&self, employee: Employee) -> Result<Employee, RepoError> {
+        let row = sqlx::query(&format!(
+            "INSERT INTO employees ({EMPLOYEE_COLUMNS}) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16) \
+             RETURNING {EMPLOYEE_COLUMNS}"
+        ))
+        .bind(employee.id)
+        .bind(&employee.employee_id)
+        .bind(&employee.first_name)
+        .bind(&employee.last_name)
+        .bind(&employee.email)
+        .bind(employee.department_id)
+        .bind(employee.manager_id)
+        .bind(&employee.position)
+        .bind(employee.salary)
+        .bind(&employee.currency)
+        .bind(employee.employment_status.as_str())
+        .bind(&employee.termination_reason)
+        .bind(employee.hire_date)
+        .bind(employee.created_at)
+        .bind(employee.updated_at)
+        .bind(employee.version as i64)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(repo_error)?;
+        employee_from_row(&row).map_err(repo_error)
+    }
+
+    async fn update(&self, employee: Employee) -> Result<Employee, RepoError> {
+        // The version check and bump happen in one statement, so concurrent writers
+        // cannot both succeed
+        let row = sqlx::query(&format!(
+            "UPDATE employees SET employee_id = $3, first_name = $4, last_name = $5, \
+             email = $6, department_id = $7, manager_id = $8, position = $9, salary = $10, \
+             currency = $11, employment_status = $12, termination_reason = $13, \
+             hire_date = $14, updated_at = $15, \
+             version = version + 1 \
+             WHERE id = $1 AND version = $2 \
+             RETURNING {EMPLOYEE_COLUMNS}"
+        ))
+        .bind(employee.id)
+        .bind(employee.version as i64)
+        .bind(&employee.employee_id)
+        .bind(&employee.first_name)
+        .bind(&employee.last_name)
+        .bind(&employee.email)
+        .bind(employee.department_id)
+        .bind(employee.manager_id)
+        .bind(&employee.position)
+        .bind(employee.salary)
+        .bind(&employee.currency)
+        .bind(employee.employment_status.as_str())
+        .bind(&employee.termination_reason)
+        .bind(employee.hire_date)
+        .bind(self.clock.now())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(repo_error)?;
+
+        match row {
+            Some(row) => employee_from_row(&row).map_err(repo_error),
+            None if self.get(employee.id).await?.is_some() => {
+                Err(RepoError::VersionMismatch(employee.id))
+            }
+            None => Err(RepoError::NotFound(employee.id)),
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        // Detaching the reports bumps their versions, so their ETags change as in memory
+        let mut tx = self.pool.begin().await.map_err(repo_error)?;
+        sqlx::query(
+            "UPDATE employees SET manager_id = NULL, updated_at = $2, \
+             version = version + 1 WHERE manager_id = $1",
+        )
+        .bind(id)
+        .bind(self.clock.now())
+        .execute(&mut *tx)
+        .await
+        .map_err(repo_error)?;
+        let result = sqlx::query("DELETE FROM employees WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await
+            .map_err(repo_error)?;
+        if result.rows_affected() == 0 {
+            return Err(RepoError::NotFound(id));
+        }
+        tx.commit().await.map_err(repo_error)
+    }
+
+    async fn ping(&self) -> Result<(), RepoError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(drop)
+            .map_err(repo_error)
+    }
+}
+
+pub struct PgDepartmentRepository {
+    pub(crate) pool: PgPool,
+}
+
+impl PgDepartmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DepartmentRepository for PgDepartmentRepository {
+    async fn list(&self) -> Result<Vec<Department>, RepoError> {
+        sqlx::query_as("SELECT id, name, code FROM departments")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(repo_error)
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Department>, RepoError> {
+        sqlx::query_as("SELECT id, name, code FROM departments WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(repo_error)
+    }
+
+    async fn create(&self, department: Department) -> Result<Department, RepoError> {
+        sqlx::query_as(
+            "INSERT INTO departments (id, name, code) VALUES ($1, $2, $3) \
+             RETURNING id, name, code",
+        )
+        .bind(department.id)
+        .bind(&department.name)
+        .bind(&department.code)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(repo_error)
+    }
+
+    async fn ping(&self) -> Result<(), RepoError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(drop)
+            .map_err(repo_error)
+    }
+}
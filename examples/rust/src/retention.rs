@@ -0,0 +1,82 @@
+use std::future::Future;
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::models::common::ApiError;
+use crate::models::employees::EmploymentStatus;
+use crate::repository::RepoError;
+use crate::state::AppState;
+
+// Data retention: terminated employees are deleted once their record has gone untouched for
+// TERMINATED_RETENTION_DAYS. The deletions are audited and published like any other, under this
+// subject, so downstream systems learn about them.
+pub(crate) const RETENTION_ACTOR: &str = "system:retention";
+
+// Sweeps every `interval` until `shutdown` resolves; a sweep already under way is finished first
+pub(crate) async fn run_retention_sweeps(
+    state: AppState,
+    retention: Duration,
+    interval: Duration,
+    shutdown: impl Future<Output = ()>,
+) {
+    let retention =
+        chrono::Duration::from_std(retention).expect("retention period fits in a chrono::Duration");
+    let mut ticks = tokio::time::interval(interval);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            () = &mut shutdown => break,
+            _ = ticks.tick() => {}
+        }
+        if state.ensure_writable().is_err() {
+            tracing::info!("maintenance mode is on, skipping the retention sweep");
+            continue;
+        }
+        match purge_terminated(&state, state.clock.now() - retention).await {
+            Ok(purged) if purged.is_empty() => {
+                tracing::debug!("retention sweep found no terminated employees to purge")
+            }
+            Ok(purged) => tracing::info!(
+                count = purged.len(),
+                ids = ?purged,
+                "purged terminated employees past the retention period"
+            ),
+            Err(err) => tracing::warn!(
+                error = %ApiError::from(err).error.message(),
+                "retention sweep failed"
+            ),
+        }
+    }
+    tracing::info!("retention sweeps stopped");
+}
+
+// Deletes terminated employees last updated before `cutoff`, returning their IDs. The cutoff is
+// passed in rather than read from the clock so callers decide what "now" is.
+pub async fn purge_terminated(
+    state: &AppState,
+    cutoff: chrono::DateTime<Utc>,
+) -> Result<Vec<Uuid>, AppError> {
+    let mut purged = Vec::new();
+    for employee in state.employees.list().await? {
+        if employee.employment_status != EmploymentStatus::Terminated
+            || employee.updated_at >= cutoff
+        {
+            continue;
+        }
+        match state.employees.delete(employee.id).await {
+            Ok(()) => {}
+            // Deleted through the API since the snapshot was taken
+            Err(RepoError::NotFound(_)) => continue,
+            Err(err) => return Err(err.into()),
+        }
+        state.discard_photo(employee.id).await;
+        state.record_deleted(RETENTION_ACTOR, employee.id);
+        purged.push(employee.id);
+    }
+    Ok(purged)
+}
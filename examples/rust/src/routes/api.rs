@@ -0,0 +1,470 @@
+use std::path::Path as FsPath;
+use std::sync::Arc;
+
+use anyhow::Context;
+use axum::{
+    http::header::CONTENT_TYPE,
+    response::{IntoResponse, Json, Response},
+};
+use utoipa::openapi::path::{ParameterIn, PathItemType};
+use utoipa::openapi::schema::{ArrayBuilder, Ref, Schema};
+use utoipa::openapi::security::{
+    AuthorizationCode, Flow, HttpAuthScheme, HttpBuilder, OAuth2, Scopes, SecurityScheme,
+};
+use utoipa::openapi::server::{Server, ServerBuilder};
+use utoipa::openapi::{Content, RefOr};
+use utoipa::{Modify, OpenApi};
+
+use crate::config::app::PageLimits;
+use crate::error::ProblemDetails;
+use crate::events::EmployeeEvent;
+use crate::handlers;
+use crate::handlers::extract::JSON_PATCH;
+use crate::handlers::photos::PhotoFile;
+use crate::middleware::auth::OAUTH_SCOPES;
+use crate::middleware::maintenance::MaintenanceMode;
+use crate::models::audit::{AuditAction, AuditEntry, FieldChange};
+use crate::models::common::{
+    ApiError, ConflictErrorDetails, CursorPaginationInfo, ErrorCode, ErrorCodeInfo, ErrorDetails,
+    GeneralErrorDetails, HealthResponse, OffsetPaginationInfo, PaginationInfo, SortOrder,
+    ValidationError, ValidationErrorDetails, VersionInfo,
+};
+use crate::models::common::{PaginatedAuditEntries, PaginatedDepartments, PaginatedEmployees};
+use crate::models::departments::{CreateDepartmentRequest, Department};
+use crate::models::employees::{
+    CreateEmployeeRequest, DryRunResult, Employee, EmployeeCount, EmployeeLinks,
+    EmployeeWithDepartment, EmploymentStatus, JsonPatchOperation, Link, PatchEmployeeRequest,
+    TerminateEmployeeRequest,
+};
+use crate::models::imports::{
+    BulkCreateReport, BulkMode, BulkRowError, FailedRow, ImportReport, ImportedRow,
+};
+use crate::models::jobs::{Job, JobStatus};
+use crate::models::photos::{PhotoUpload, PhotoUrl};
+use crate::routes::v1;
+use crate::webhooks::WebhookPayload;
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::employees::list_employees,
+        handlers::exports::export_employees,
+        handlers::exports::start_export_job,
+        handlers::exports::get_job,
+        handlers::exports::download_job_file,
+        handlers::employees::stream_employees_ndjson,
+        handlers::employees::count_employees,
+        handlers::employees::create_employee,
+        handlers::imports::import_employees,
+        handlers::imports::bulk_create_employees,
+        handlers::employees::search_employees,
+        handlers::employees::list_all_employees,
+        handlers::employees::list_reports,
+        handlers::audit::list_audit_entries,
+        handlers::events::employee_events,
+        handlers::employees::get_employee,
+        handlers::employees::update_employee,
+        handlers::employees::upsert_employee,
+        handlers::employees::patch_employee,
+        handlers::employees::delete_employee,
+        handlers::employees::terminate_employee,
+        handlers::employees::reactivate_employee,
+        handlers::photos::upload_employee_photo,
+        handlers::photos::get_employee_photo,
+        handlers::photos::get_employee_photo_url,
+        handlers::departments::list_departments,
+        handlers::departments::create_department,
+        handlers::departments::get_department,
+        handlers::system::health,
+        handlers::system::ready,
+        handlers::system::metrics,
+        handlers::system::version,
+        handlers::system::list_error_codes,
+        handlers::admin::set_maintenance_mode
+    ),
+    components(
+        schemas(
+            Employee,
+            EmploymentStatus,
+            EmployeeWithDepartment,
+            EmployeeLinks,
+            Link,
+            CreateEmployeeRequest,
+            PatchEmployeeRequest,
+            TerminateEmployeeRequest,
+            Department,
+            CreateDepartmentRequest,
+            ApiError,
+            ValidationErrorDetails,
+            ConflictErrorDetails,
+            GeneralErrorDetails,
+            ProblemDetails,
+            VersionInfo,
+            ErrorCode,
+            ErrorCodeInfo,
+            HealthResponse,
+            PaginatedEmployees,
+            PaginatedDepartments,
+            PaginatedAuditEntries,
+            AuditEntry,
+            AuditAction,
+            FieldChange,
+            EmployeeEvent,
+            WebhookPayload,
+            JsonPatchOperation,
+            PaginationInfo,
+            EmployeeCount,
+            OffsetPaginationInfo,
+            CursorPaginationInfo,
+            ErrorDetails,
+            SortOrder,
+            ImportReport,
+            DryRunResult,
+            BulkCreateReport,
+            BulkRowError,
+            BulkMode,
+            ImportedRow,
+            FailedRow,
+            ValidationError,
+            PhotoUpload,
+            PhotoFile,
+            PhotoUrl,
+            Job,
+            JobStatus,
+            MaintenanceMode
+        )
+    ),
+    tags(
+        (name = "employees", description = "Employee management endpoints"),
+        (name = "departments", description = "Department management endpoints"),
+        (name = "system", description = "Operational endpoints; no authentication required"),
+        (name = "admin", description = "Operational controls for admins"),
+        (name = "webhooks", description = "When WEBHOOK_URL is set, every employee create, update and delete is \
+POSTed there as a `WebhookPayload`. The `X-Signature` header is `sha256=` followed by the hex HMAC-SHA256 of the raw \
+body keyed with WEBHOOK_SECRET; compare it in constant time before trusting the payload. Failed deliveries are \
+retried up to 5 times with exponential backoff, so receivers should deduplicate on `id`.")
+    ),
+    security(
+        ("BearerAuth" = [])
+    ),
+    modifiers(
+        &SecurityAddon,
+        &ServersAddon,
+        &JsonPatchAddon,
+        &HeadAddon,
+        &VersionPrefixAddon
+    )
+)]
+pub(crate) struct ApiDoc;
+
+// Registers the BearerAuth and OAuth2 schemes referenced by security(...) so Swagger UI shows
+// "Authorize". The OAuth2 endpoints come from OAUTH_AUTHORIZE_URL and OAUTH_TOKEN_URL.
+pub(crate) struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "BearerAuth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+
+        let url = |name: &str, default: &str| {
+            std::env::var(name)
+                .ok()
+                .filter(|url| !url.is_empty())
+                .unwrap_or_else(|| default.to_string())
+        };
+        components.add_security_scheme(
+            "OAuth2",
+            SecurityScheme::OAuth2(OAuth2::new([Flow::AuthorizationCode(
+                AuthorizationCode::new(
+                    url(
+                        "OAUTH_AUTHORIZE_URL",
+                        "https://auth.company.com/oauth2/authorize",
+                    ),
+                    url("OAUTH_TOKEN_URL", "https://auth.company.com/oauth2/token"),
+                    Scopes::from_iter(OAUTH_SCOPES),
+                ),
+            )])),
+        );
+    }
+}
+
+// Server list comes from API_SERVERS (comma separated) so one binary documents every environment
+pub(crate) struct ServersAddon;
+
+impl Modify for ServersAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let configured = std::env::var("API_SERVERS").unwrap_or_default();
+        let mut servers: Vec<Server> = configured
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(server_entry)
+            .collect();
+        if servers.is_empty() {
+            servers.push(server_entry("http://localhost:3000"));
+        }
+        openapi.servers = Some(servers);
+    }
+}
+
+pub(crate) fn server_entry(url: &str) -> Server {
+    let is_local = ["://localhost", "://127.0.0.1", "://0.0.0.0"]
+        .iter()
+        .any(|host| url.contains(host));
+    ServerBuilder::new()
+        .url(url)
+        .description(Some(if is_local { "Local" } else { "Production" }))
+        .build()
+}
+
+// Handlers document paths relative to their version; this adds the /v1 prefix the router
+// mounts them under. Runs last so the other addons can look paths up unprefixed.
+pub(crate) struct VersionPrefixAddon;
+
+impl Modify for VersionPrefixAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let paths = std::mem::take(&mut openapi.paths.paths);
+        openapi.paths.paths = paths
+            .into_iter()
+            .map(|(path, item)| {
+                if UNVERSIONED_PATHS.contains(&path.as_str()) {
+                    (path, item)
+                } else {
+                    (format!("{}{path}", v1::PREFIX), item)
+                }
+            })
+            .collect();
+    }
+}
+
+// Operational endpoints stay at the root for probes and scrapers
+pub(crate) const UNVERSIONED_PATHS: [&str; 5] =
+    ["/health", "/ready", "/metrics", "/version", "/error-codes"];
+
+// utoipa 4 allows one schema per request body, so the JSON Patch content type is added here
+pub(crate) struct JsonPatchAddon;
+
+impl Modify for JsonPatchAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let request_body = openapi
+            .paths
+            .paths
+            .get_mut("/employees/{id}")
+            .and_then(|item| item.operations.get_mut(&PathItemType::Patch))
+            .and_then(|operation| operation.request_body.as_mut());
+        if let Some(request_body) = request_body {
+            let operations = ArrayBuilder::new()
+                .items(Ref::from_schema_name("JsonPatchOperation"))
+                .build();
+            request_body
+                .content
+                .insert(JSON_PATCH.to_string(), Content::new(operations));
+        }
+    }
+}
+
+// axum answers HEAD on every GET route with the GET's status and headers and an empty body. The
+// spec lists it for the single-employee read, where clients use it to check existence and fetch
+// the ETag; the operation is the GET's with its response bodies removed.
+pub(crate) struct HeadAddon;
+
+impl Modify for HeadAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let Some(item) = openapi.paths.paths.get_mut("/employees/{id}") else {
+            return;
+        };
+        let Some(mut head) = item.operations.get(&PathItemType::Get).cloned() else {
+            return;
+        };
+        head.operation_id = Some("head_employee".to_string());
+        head.summary = Some("Check employee".to_string());
+        head.description = Some(
+            "Same status and headers as `GET /employees/{id}`, including `ETag` and \
+`Content-Length`, without a body"
+                .to_string(),
+        );
+        for response in head.responses.responses.values_mut() {
+            if let RefOr::T(response) = response {
+                response.content.clear();
+            }
+        }
+        item.operations.insert(PathItemType::Head, head);
+    }
+}
+
+// Fills in the maximum and default of every `per_page` and `limit` query parameter from the
+// configured limits, so served and dumped specs match what `resolve` enforces
+pub(crate) struct PageLimitsAddon(pub(crate) PageLimits);
+
+impl Modify for PageLimitsAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let parameters = openapi
+            .paths
+            .paths
+            .values_mut()
+            .flat_map(|item| item.operations.values_mut())
+            .filter_map(|operation| operation.parameters.as_mut())
+            .flatten()
+            .filter(|parameter| {
+                parameter.parameter_in == ParameterIn::Query
+                    && ["per_page", "limit"].contains(&parameter.name.as_str())
+            });
+        for parameter in parameters {
+            if let Some(RefOr::T(Schema::Object(schema))) = parameter.schema.as_mut() {
+                schema.maximum = Some(f64::from(self.0.max_per_page));
+                schema.default = Some(serde_json::json!(self.0.default_per_page));
+            }
+        }
+    }
+}
+
+// The spec served by this process, with the configured page limits
+pub(crate) fn served_openapi(page_limits: PageLimits) -> utoipa::openapi::OpenApi {
+    let mut openapi = ApiDoc::openapi();
+    PageLimitsAddon(page_limits).modify(&mut openapi);
+    openapi
+}
+
+// utoipa 4 only emits OpenAPI 3.0, so 3.1 is produced by rewriting its output. The only
+// construct that differs for us is `nullable`, which 3.1 (JSON Schema 2020-12) spells as a
+// `null` type.
+#[derive(Clone, Copy, PartialEq)]
+pub enum SpecVersion {
+    V3_0,
+    V3_1,
+}
+
+impl SpecVersion {
+    // The spec a service with these page limits would serve
+    pub(crate) fn render(self, page_limits: PageLimits) -> anyhow::Result<String> {
+        let openapi = served_openapi(page_limits);
+        let spec = match self {
+            SpecVersion::V3_0 => serde_json::to_value(openapi),
+            SpecVersion::V3_1 => serde_json::to_value(openapi).map(upgrade_to_3_1),
+        };
+        spec.and_then(|spec| serde_json::to_string_pretty(&spec))
+            .context("failed to serialize OpenAPI spec")
+    }
+}
+
+pub(crate) fn upgrade_to_3_1(mut spec: serde_json::Value) -> serde_json::Value {
+    spec["openapi"] = serde_json::json!("3.1.0");
+    rewrite_nullable(&mut spec);
+    spec
+}
+
+// `type: T, nullable: true` becomes `type: [T, "null"]` (with null added to any enum); a
+// nullable `$ref` or composite schema becomes a oneOf with `{"type": "null"}`
+pub(crate) fn rewrite_nullable(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(schema) => {
+            schema.values_mut().for_each(rewrite_nullable);
+            // Compared as a bool so a property that happens to be named `nullable` is left alone
+            if schema.get("nullable") != Some(&serde_json::Value::Bool(true)) {
+                return;
+            }
+            schema.remove("nullable");
+            match schema
+                .get("type")
+                .and_then(|ty| ty.as_str())
+                .map(str::to_string)
+            {
+                Some(ty) => {
+                    schema.insert("type".to_string(), serde_json::json!([ty, "null"]));
+                    if let Some(serde_json::Value::Array(values)) = schema.get_mut("enum") {
+                        values.push(serde_json::Value::Null);
+                    }
+                }
+                None => {
+                    let inner = std::mem::take(schema);
+                    schema.insert(
+                        "oneOf".to_string(),
+                        serde_json::json!([serde_json::Value::Object(inner), { "type": "null" }]),
+                    );
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(rewrite_nullable),
+        _ => {}
+    }
+}
+
+// The 3.1 rendering of the spec at /api-docs/openapi.json, for tooling that requires it
+pub(crate) async fn openapi_3_1_json(spec: Arc<serde_json::Value>) -> Json<serde_json::Value> {
+    Json(spec.as_ref().clone())
+}
+
+// The spec at /api-docs/openapi.json, as YAML for tools that prefer it
+pub(crate) async fn openapi_yaml(spec: Arc<str>) -> Response {
+    ([(CONTENT_TYPE, "application/yaml")], spec.to_string()).into_response()
+}
+
+pub fn dump_openapi(
+    path: &FsPath,
+    version: SpecVersion,
+    page_limits: PageLimits,
+) -> anyhow::Result<()> {
+    let json = version.render(page_limits)?;
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("failed to create {}", dir.display()))?;
+    }
+    std::fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))
+}
+
+pub fn check_openapi(
+    snapshot: &FsPath,
+    version: SpecVersion,
+    page_limits: PageLimits,
+) -> anyhow::Result<()> {
+    if std::env::var("UPDATE_SNAPSHOTS").as_deref() == Ok("1") {
+        dump_openapi(snapshot, version, page_limits)?;
+        tracing::info!("📸 OpenAPI snapshot updated at {}", snapshot.display());
+        return Ok(());
+    }
+
+    let current = version.render(page_limits)?;
+    let committed = std::fs::read_to_string(snapshot).with_context(|| {
+        format!(
+            "failed to read {}; create it with UPDATE_SNAPSHOTS=1",
+            snapshot.display()
+        )
+    })?;
+    if committed.trim_end() == current.trim_end() {
+        return Ok(());
+    }
+
+    let diff: Vec<String> = committed
+        .lines()
+        .map(Some)
+        .chain(std::iter::repeat(None))
+        .zip(current.lines().map(Some).chain(std::iter::repeat(None)))
+        .take_while(|pair| *pair != (None, None))
+        .enumerate()
+        .filter(|(_, (old, new))| old != new)
+        .take(20)
+        .map(|(index, (old, new))| {
+            format!(
+                "line {}:\n  - {}\n  + {}",
+                index + 1,
+                old.unwrap_or("<missing>"),
+                new.unwrap_or("<missing>")
+            )
+        })
+        .collect();
+    anyhow::bail!(
+        "OpenAPI spec differs from {} (first {} differing lines shown); \
+         rerun with UPDATE_SNAPSHOTS=1 if the change is intended\n{}",
+        snapshot.display(),
+        diff.len(),
+        diff.join("\n")
+    )
+}
@@ -0,0 +1,190 @@
+pub mod api;
+pub mod v1;
+
+use std::sync::Arc;
+
+use axum::{
+    extract::Request,
+    http::Uri,
+    middleware::{self},
+    routing::{any, get},
+    Router,
+};
+use tokio::sync::Semaphore;
+use tower_http::catch_panic::CatchPanicLayer;
+use tower_http::trace::{DefaultOnResponse, TraceLayer};
+use tower_http::LatencyUnit;
+use tracing::Level;
+#[cfg(feature = "redoc")]
+use utoipa_redoc::{Redoc, Servable};
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::clock::{Clock, SystemClock};
+use crate::config::app::Config;
+#[cfg(feature = "grpc")]
+use crate::grpc::{pb::employee_service_server::EmployeeServiceServer, EmployeeGrpc};
+use crate::handlers::system::list_error_codes;
+use crate::handlers::system::{health, metrics, ready, version};
+use crate::jobs::spawn_job_worker;
+use crate::middleware::compression::compression_layer;
+use crate::middleware::cors::cors_layer;
+use crate::middleware::errors::{
+    framework_errors_as_api_error, panic_as_api_error, redirect_to_v1, route_not_found,
+};
+use crate::middleware::locale::negotiate_locale;
+use crate::middleware::logging::{assign_request_id, request_span};
+use crate::middleware::metrics::track_metrics;
+use crate::middleware::rate_limit::{enforce_rate_limit, shed_load};
+use crate::repository::memory::InMemoryDepartmentRepository;
+use crate::repository::EmployeeRepository;
+use crate::routes::api::{openapi_3_1_json, openapi_yaml, served_openapi, upgrade_to_3_1};
+use crate::seed::seed_departments;
+use crate::state::AppState;
+use crate::webhooks::spawn_webhook_delivery;
+
+// The router over seeded in-memory stores, for embedding and tests; main builds its state
+// itself so it can share it with the gRPC service
+pub fn create_app(config: &Config) -> Router {
+    create_app_with_clock(config, Arc::new(SystemClock))
+}
+
+// Same as create_app, with every timestamp taken from `clock` (e.g. a FixedClock in tests)
+pub fn create_app_with_clock(config: &Config, clock: Arc<dyn Clock>) -> Router {
+    app_router(config, AppState::seeded(config, clock))
+}
+
+// Same as create_app, with employees kept in `employees` (your own backend, or a mock in tests);
+// departments are the seeded in-memory ones
+pub fn create_app_with_repository(
+    config: &Config,
+    employees: Arc<dyn EmployeeRepository>,
+) -> Router {
+    let departments = Arc::new(InMemoryDepartmentRepository::new(seed_departments()));
+    let state = AppState::new(config, Arc::new(SystemClock), employees, departments);
+    app_router(config, state)
+}
+
+// The router and the gRPC service over one seeded state, so a write through either is seen
+// by both; serve the service with `tonic::transport::Server`
+#[cfg(feature = "grpc")]
+pub fn create_app_with_grpc(config: &Config) -> (Router, EmployeeServiceServer<EmployeeGrpc>) {
+    let state = AppState::seeded(config, Arc::new(SystemClock));
+    let grpc = EmployeeServiceServer::new(EmployeeGrpc {
+        state: state.clone(),
+    });
+    (app_router(config, state), grpc)
+}
+
+// The router over an existing state, for callers that also run background tasks on it
+pub fn app_router(config: &Config, state: AppState) -> Router {
+    if let Some(webhook) = &config.webhook {
+        spawn_webhook_delivery(
+            webhook.clone(),
+            state.events.subscribe(),
+            state.clock.clone(),
+        );
+    }
+    spawn_job_worker(state.clone());
+
+    // Each version nests its own route table, so a v2 module can be added next to v1
+    // without touching it. All versions share one concurrency limit; probes and docs are outside it.
+    let api = Router::new()
+        .nest(v1::PREFIX, v1::router(config, &state))
+        .layer(middleware::from_fn_with_state(
+            Arc::new(Semaphore::new(config.max_concurrent_requests)),
+            shed_load,
+        ));
+
+    // Both UIs read the same spec; /api-docs/openapi.json stays available either way
+    let openapi = served_openapi(config.page_limits);
+    let yaml: Arc<str> = serde_yaml::to_string(&openapi)
+        .expect("spec serializes")
+        .into();
+    let json_3_1 = Arc::new(upgrade_to_3_1(
+        serde_json::to_value(&openapi).expect("spec serializes"),
+    ));
+    let docs =
+        Router::new().merge(SwaggerUi::new("/docs").url("/api-docs/openapi.json", openapi.clone()));
+    let docs = docs
+        .route(
+            "/api-docs/openapi.yaml",
+            get(move || openapi_yaml(yaml.clone())),
+        )
+        .route(
+            "/api-docs/openapi-3.1.json",
+            get(move || openapi_3_1_json(json_3_1.clone())),
+        );
+    #[cfg(feature = "redoc")]
+    let docs = docs.merge(Redoc::with_url("/redoc", openapi));
+    let docs = docs.route_layer(middleware::from_fn_with_state(
+        state.clone(),
+        enforce_rate_limit,
+    ));
+
+    // Probes and metrics are polled by infrastructure and stay outside the rate limiter
+    let app = Router::new()
+        .route("/health", get(health))
+        .route("/ready", get(ready))
+        .route("/version", get(version))
+        .route("/error-codes", get(list_error_codes))
+        .merge(docs)
+        .merge(api)
+        .route("/employees", any(redirect_to_v1))
+        .route("/employees/*rest", any(redirect_to_v1))
+        .route("/employees.ndjson", any(redirect_to_v1))
+        .route("/departments", any(redirect_to_v1))
+        .route("/departments/*rest", any(redirect_to_v1));
+
+    // route_layer runs after routing, so MatchedPath is available for the route label.
+    // The request id is assigned before the trace span opens so the span can carry it.
+    let app = app
+        .route("/metrics", get(metrics))
+        .route_layer(middleware::from_fn(track_metrics))
+        .fallback(route_not_found)
+        // Listed innermost first: the request id is assigned before anything else runs
+        .layer(CatchPanicLayer::custom(panic_as_api_error))
+        .layer(middleware::from_fn(framework_errors_as_api_error))
+        .layer(compression_layer())
+        .layer(cors_layer(&config.cors_allowed_origins))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(request_span)
+                .on_response(
+                    DefaultOnResponse::new()
+                        .level(Level::INFO)
+                        .latency_unit(LatencyUnit::Millis),
+                ),
+        )
+        .layer(middleware::from_fn(negotiate_locale))
+        .layer(middleware::from_fn(assign_request_id))
+        .with_state(state);
+
+    // A router's own layers run after it has matched the path, so the slash is trimmed by an
+    // outer router that hands every request to this one
+    Router::new()
+        .fallback_service(app)
+        .layer(middleware::map_request(trim_trailing_slash))
+}
+
+// `/employees/` is served as `/employees`. Swagger UI is left alone: it redirects `/docs` to
+// `/docs/` and loads its assets relative to the slash.
+pub(crate) async fn trim_trailing_slash(mut request: Request) -> Request {
+    let path = request.uri().path();
+    if path.len() <= 1 || !path.ends_with('/') || path.starts_with("/docs/") {
+        return request;
+    }
+    let trimmed = match path.trim_end_matches('/') {
+        "" => "/",
+        trimmed => trimmed,
+    };
+    let path_and_query = match request.uri().query() {
+        Some(query) => format!("{trimmed}?{query}"),
+        None => trimmed.to_string(),
+    };
+    let mut parts = request.uri().clone().into_parts();
+    parts.path_and_query = path_and_query.parse().ok();
+    if let Ok(uri) = Uri::from_parts(parts) {
+        *request.uri_mut() = uri;
+    }
+    request
+}
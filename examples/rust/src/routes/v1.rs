@@ -0,0 +1,141 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::DefaultBodyLimit,
+    handler::Handler,
+    middleware::{self},
+    routing::{get, post, put},
+    Router,
+};
+use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::timeout::TimeoutLayer;
+
+use crate::config::app::Config;
+use crate::handlers;
+use crate::handlers::admin::set_maintenance_mode;
+use crate::handlers::audit::list_audit_entries;
+use crate::handlers::departments::{create_department, get_department, list_departments};
+use crate::handlers::employees::{
+    count_employees, create_employee, delete_employee, get_employee, list_employees, list_reports,
+    patch_employee, reactivate_employee, search_employees, stream_employees_ndjson,
+    terminate_employee, update_employee, upsert_employee,
+};
+use crate::handlers::events::employee_events;
+use crate::handlers::exports::{download_job_file, export_employees, get_job, start_export_job};
+use crate::handlers::imports::{bulk_create_employees, import_employees};
+use crate::handlers::photos::{get_employee_photo, get_employee_photo_url, upload_employee_photo};
+use crate::middleware::auth::{require_bearer_auth, require_role, require_scope, ADMIN_ROLE};
+use crate::middleware::deprecation::{mark_deprecated, LEGACY_LIST};
+use crate::middleware::errors::payload_too_large_as_api_error;
+use crate::middleware::maintenance::{reject_writes_in_maintenance, MAINTENANCE_PATH};
+use crate::middleware::rate_limit::enforce_rate_limit;
+use crate::state::AppState;
+use crate::storage::MAX_PHOTO_BYTES;
+
+// Version 1 of the employee and department API, served under /v1
+
+pub(crate) const PREFIX: &str = "/v1";
+
+// Routes relative to PREFIX, behind authentication, rate limiting and scope checks
+pub(crate) fn router(config: &Config, state: &AppState) -> Router<AppState> {
+    let admin_only = middleware::from_fn_with_state(ADMIN_ROLE, require_role);
+    #[allow(deprecated)]
+    let legacy_list = get(handlers::employees::list_all_employees
+        .layer(middleware::from_fn_with_state(LEGACY_LIST, mark_deprecated)));
+
+    // Imports and bulk creates write row by row, so they get longer than other requests
+    let batch = Router::new()
+        .route(
+            "/employees/import",
+            post(import_employees.layer(admin_only.clone())),
+        )
+        .route(
+            "/employees/bulk",
+            post(bulk_create_employees.layer(admin_only.clone())),
+        )
+        .layer(TimeoutLayer::new(config.batch_request_timeout));
+
+    Router::new()
+        .route(
+            "/employees",
+            get(list_employees).post(create_employee.layer(admin_only.clone())),
+        )
+        .route("/employees/export.csv", get(export_employees))
+        .route("/employees/export", post(start_export_job))
+        .route("/jobs/:id", get(get_job))
+        .route("/jobs/:id/download", get(download_job_file))
+        .route("/employees.ndjson", get(stream_employees_ndjson))
+        .route("/employees/search", get(search_employees))
+        .route("/employees/count", get(count_employees))
+        .route("/employees/all", legacy_list)
+        .route("/employees/events", get(employee_events))
+        .route(
+            "/employees/:id",
+            get(get_employee)
+                .put(update_employee.layer(admin_only.clone()))
+                .patch(patch_employee.layer(admin_only.clone()))
+                .delete(delete_employee.layer(admin_only.clone())),
+        )
+        .route(
+            "/employees/by-employee-id/:employee_id",
+            put(upsert_employee.layer(admin_only.clone())),
+        )
+        .route(
+            "/employees/:id/terminate",
+            post(terminate_employee.layer(admin_only.clone())),
+        )
+        .route("/employees/:id/reports", get(list_reports))
+        .route("/employees/:id/photo-url", get(get_employee_photo_url))
+        .route(
+            "/employees/:id/audit",
+            get(list_audit_entries.layer(admin_only.clone())),
+        )
+        .route(
+            "/employees/:id/reactivate",
+            post(reactivate_employee.layer(admin_only.clone())),
+        )
+        .route(
+            "/departments",
+            get(list_departments).post(create_department.layer(admin_only.clone())),
+        )
+        .route("/departments/:id", get(get_department))
+        .route(
+            MAINTENANCE_PATH,
+            post(set_maintenance_mode.layer(admin_only.clone())),
+        )
+        // Only the time to produce response headers counts, so the SSE and CSV streams
+        // are not cut off
+        .layer(TimeoutLayer::new(config.request_timeout))
+        .merge(batch)
+        // Applies to the routes above; photo uploads below have their own, larger limit
+        .layer(DefaultBodyLimit::disable())
+        .layer(RequestBodyLimitLayer::new(config.max_body_bytes))
+        .layer(middleware::from_fn_with_state(
+            config.max_body_bytes,
+            payload_too_large_as_api_error,
+        ))
+        .route(
+            "/employees/:id/photo",
+            get(get_employee_photo)
+                .post(upload_employee_photo.layer(admin_only))
+                // Room for the multipart framing around a maximum-size photo. The error type is
+                // spelled out because the next layer leaves it open to inference.
+                .layer::<_, Infallible>(DefaultBodyLimit::max(MAX_PHOTO_BYTES + 64 * 1024))
+                .layer(TimeoutLayer::new(config.request_timeout)),
+        )
+        // Later layers run first: authenticate, rate limit by the token subject, check the
+        // OAuth2 scope, then refuse writes during maintenance
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            reject_writes_in_maintenance,
+        ))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_scope))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            enforce_rate_limit,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_bearer_auth,
+        ))
+}
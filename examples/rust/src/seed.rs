@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+use std::path::Path as FsPath;
+
+use anyhow::Context;
+use chrono::{NaiveDate, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::builders;
+use crate::error::validation_details;
+use crate::models::departments::{CreateDepartmentRequest, Department};
+use crate::models::employees::{CreateEmployeeRequest, Employee};
+use crate::repository::memory::InMemoryRepository;
+use crate::repository::RepoError;
+
+// Demo data so Swagger UI's "Try it out" returns something useful
+pub(crate) fn seed_departments() -> Vec<Department> {
+    [
+        ("Engineering", "ENG"),
+        ("Human Resources", "HR"),
+        ("Finance", "FIN"),
+    ]
+    .into_iter()
+    .map(|(name, code)| Department {
+        id: Uuid::new_v4(),
+        name: name.to_string(),
+        code: code.to_string(),
+    })
+    .collect()
+}
+
+pub(crate) fn seed_employees(
+    departments: &[Department],
+    now: chrono::DateTime<Utc>,
+) -> Vec<Employee> {
+    let department_id = |code: &str| departments.iter().find(|d| d.code == code).map(|d| d.id);
+    let seed = |employee_id: &str,
+                first_name: &str,
+                last_name: &str,
+                department: &str,
+                manager_id: Option<Uuid>,
+                (year, month, day): (i32, u32, u32)| {
+        builders::EmployeeBuilder::new(employee_id, first_name, last_name, now)
+            .with_department(department_id(department))
+            .with_manager(manager_id)
+            .hired_on(NaiveDate::from_ymd_opt(year, month, day).expect("valid seed date"))
+            .build()
+    };
+
+    // Jane leads engineering, so /employees/{id}/reports has something to show
+    let lead = seed("EMP002", "Jane", "Smith", "ENG", None, (2023, 6, 1));
+    let lead_id = Some(lead.id);
+    vec![
+        seed("EMP001", "John", "Doe", "ENG", lead_id, (2024, 1, 15)),
+        lead,
+        seed("EMP003", "Alex", "Tanaka", "HR", None, (2022, 3, 10)),
+        seed("EMP004", "Maria", "Garcia", "FIN", None, (2021, 11, 22)),
+        seed("EMP005", "Sam", "Lee", "ENG", lead_id, (2024, 8, 5)),
+    ]
+}
+
+// Replaces the built-in demo data when SEED_FILE or `--seed <path>` is given: JSON, or YAML
+// for a .yaml/.yml path. Records take the bodies of the create endpoints plus an optional `id`,
+// so employees can name their department and manager.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SeedFile {
+    #[serde(default)]
+    pub(crate) departments: Vec<SeedDepartment>,
+    #[serde(default)]
+    pub(crate) employees: Vec<SeedEmployee>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SeedDepartment {
+    #[serde(default = "Uuid::new_v4")]
+    pub(crate) id: Uuid,
+    #[serde(flatten)]
+    pub(crate) department: CreateDepartmentRequest,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct SeedEmployee {
+    #[serde(default = "Uuid::new_v4")]
+    pub(crate) id: Uuid,
+    #[serde(flatten)]
+    pub(crate) employee: CreateEmployeeRequest,
+}
+
+pub(crate) fn load_seed_file(
+    path: &FsPath,
+    now: chrono::DateTime<Utc>,
+) -> anyhow::Result<(Vec<Department>, Vec<Employee>)> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read seed file {}", path.display()))?;
+    let seed: SeedFile = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("yaml" | "yml") => serde_yaml::from_str(&text).map_err(anyhow::Error::from),
+        _ => serde_json::from_str(&text).map_err(anyhow::Error::from),
+    }
+    .with_context(|| format!("failed to parse seed file {}", path.display()))?;
+    seed.into_records(now)
+        .with_context(|| format!("invalid seed file {}", path.display()))
+}
+
+impl SeedFile {
+    // The same checks the create endpoints make, failing on the first bad record
+    pub(crate) fn into_records(
+        self,
+        now: chrono::DateTime<Utc>,
+    ) -> anyhow::Result<(Vec<Department>, Vec<Employee>)> {
+        let mut departments: HashMap<Uuid, Department> = HashMap::new();
+        for (index, SeedDepartment { id, department }) in self.departments.into_iter().enumerate() {
+            let at = format!("departments[{index}] ({})", department.code);
+            department
+                .validate()
+                .map_err(|errors| invalid_seed_record(&at, &errors))?;
+            anyhow::ensure!(
+                !departments.contains_key(&id),
+                "{at}: id {id} is already used"
+            );
+            anyhow::ensure!(
+                departments.values().all(|d| d.code != department.code),
+                "{at}: code is already used by another department"
+            );
+            departments.insert(
+                id,
+                Department {
+                    id,
+                    name: department.name,
+                    code: department.code,
+                },
+            );
+        }
+
+        let mut employees: HashMap<Uuid, Employee> = HashMap::new();
+        let mut managers = Vec::new();
+        for (index, SeedEmployee { id, employee }) in self.employees.into_iter().enumerate() {
+            let at = format!("employees[{index}] ({})", employee.employee_id);
+            employee
+                .validate()
+                .map_err(|errors| invalid_seed_record(&at, &errors))?;
+            if let Some(department_id) = employee.department_id {
+                anyhow::ensure!(
+                    departments.contains_key(&department_id),
+                    "{at}: department_id {department_id} is not a seeded department"
+                );
+            }
+            anyhow::ensure!(
+                !employees.contains_key(&id),
+                "{at}: id {id} is already used"
+            );
+
+            let mut employee = Employee::from_request(employee, now);
+            employee.id = id;
+            if let Err(RepoError::Conflict(field)) =
+                InMemoryRepository::ensure_unique(&employees, &employee)
+            {
+                anyhow::bail!("{at}: {field} is already used by another employee");
+            }
+            if let Some(manager_id) = employee.manager_id {
+                managers.push((at, id, manager_id));
+            }
+            employees.insert(id, employee);
+        }
+        // Managers may appear later in the file than their reports
+        for (at, id, manager_id) in managers {
+            anyhow::ensure!(
+                id != manager_id,
+                "{at}: an employee cannot manage themselves"
+            );
+            anyhow::ensure!(
+                employees.contains_key(&manager_id),
+                "{at}: manager_id {manager_id} is not a seeded employee"
+            );
+        }
+
+        Ok((
+            departments.into_values().collect(),
+            employees.into_values().collect(),
+        ))
+    }
+}
+
+pub(crate) fn invalid_seed_record(at: &str, errors: &validator::ValidationErrors) -> anyhow::Error {
+    let reasons: Vec<String> = validation_details(errors)
+        .into_iter()
+        .map(|detail| format!("{} {}", detail.field, detail.message))
+        .collect();
+    anyhow::anyhow!("{at}: {}", reasons.join("; "))
+}
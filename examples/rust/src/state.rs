@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use axum::http::{header::LOCATION, HeaderName, HeaderValue};
+use jsonwebtoken::DecodingKey;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::audit::AuditLog;
+use crate::clock::Clock;
+use crate::config::app::{Config, PageLimits};
+use crate::error::AppError;
+use crate::events::{EmployeeEvent, EVENT_BUFFER};
+use crate::idempotency::IdempotencyStore;
+use crate::jobs::JobRegistry;
+use crate::middleware::auth::Claims;
+use crate::middleware::rate_limit::RateLimiter;
+use crate::models::audit::AuditAction;
+use crate::models::departments::Department;
+use crate::models::employees::{Employee, EmployeeWithDepartment};
+use crate::repository::cache::CachingRepository;
+use crate::repository::memory::{InMemoryDepartmentRepository, InMemoryRepository};
+use crate::repository::{DepartmentRepository, EmployeeRepository};
+use crate::routes::v1;
+use crate::seed::{load_seed_file, seed_departments, seed_employees};
+use crate::storage::PhotoStorage;
+
+// Shared across handlers; opaque outside the crate, where it is only built and handed to
+// app_router or the background tasks
+#[derive(Clone)]
+pub struct AppState {
+    pub(crate) employees: Arc<dyn EmployeeRepository>,
+    pub(crate) departments: Arc<dyn DepartmentRepository>,
+    pub(crate) photos: Arc<dyn PhotoStorage>,
+    pub(crate) jwt_key: Arc<DecodingKey>,
+    pub(crate) dev_claims: Option<Arc<Claims>>,
+    pub(crate) rate_limiter: Arc<RateLimiter>,
+    pub(crate) idempotency: Arc<IdempotencyStore>,
+    pub(crate) audit: Arc<AuditLog>,
+    pub(crate) events: broadcast::Sender<EmployeeEvent>,
+    pub(crate) jobs: Arc<JobRegistry>,
+    pub(crate) maintenance: Arc<AtomicBool>,
+    pub(crate) page_limits: PageLimits,
+    pub(crate) require_if_match: bool,
+    pub(crate) require_scopes: bool,
+    pub(crate) links_base_url: Option<Arc<str>>,
+    pub(crate) public_base_url: Arc<str>,
+    pub(crate) photo_url_ttl: Duration,
+    pub(crate) clock: Arc<dyn Clock>,
+}
+
+impl AppState {
+    // `clock` should be the one the repositories were built with
+    pub(crate) fn new(
+        config: &Config,
+        clock: Arc<dyn Clock>,
+        employees: Arc<dyn EmployeeRepository>,
+        departments: Arc<dyn DepartmentRepository>,
+    ) -> Self {
+        Self {
+            employees: Arc::new(CachingRepository::new(
+                employees,
+                config.cache_ttl,
+                config.cache_max_capacity,
+            )),
+            departments,
+            photos: config.photo_storage.build(),
+            jwt_key: Arc::new(DecodingKey::from_secret(config.jwt_secret.as_bytes())),
+            dev_claims: config.dev_identity.as_ref().map(|identity| {
+                Arc::new(Claims {
+                    sub: identity.sub.clone(),
+                    roles: identity.roles.clone(),
+                    scope: identity.scopes.join(" "),
+                })
+            }),
+            rate_limiter: Arc::new(RateLimiter::new(
+                config.rate_limit_requests,
+                config.rate_limit_window,
+            )),
+            idempotency: Arc::new(IdempotencyStore::new(config.idempotency_ttl, clock.clone())),
+            audit: Arc::new(AuditLog::new(clock.clone())),
+            events: broadcast::channel(EVENT_BUFFER).0,
+            jobs: Arc::new(JobRegistry::new(clock.clone())),
+            maintenance: Arc::new(AtomicBool::new(config.maintenance_mode)),
+            page_limits: config.page_limits,
+            require_if_match: config.require_if_match,
+            require_scopes: config.require_scopes,
+            links_base_url: config
+                .hypermedia_links
+                .then(|| Arc::from(config.public_base_url.as_str())),
+            public_base_url: Arc::from(config.public_base_url.as_str()),
+            photo_url_ttl: config.photo_url_ttl,
+            clock,
+        }
+    }
+
+    // Every employee mutation goes through these, so the audit log and the event stream
+    // always see the same changes
+    pub(crate) fn record_created(&self, actor: &str, employee: &Employee) {
+        self.audit.created(actor, employee);
+        self.publish(AuditAction::Created, employee.id, Some(employee));
+    }
+
+    pub(crate) fn record_updated(&self, actor: &str, before: &Employee, after: &Employee) {
+        self.audit.updated(actor, before, after);
+        self.publish(AuditAction::Updated, after.id, Some(after));
+    }
+
+    pub(crate) fn record_deleted(&self, actor: &str, id: Uuid) {
+        self.audit.deleted(actor, id);
+        self.publish(AuditAction::Deleted, id, None);
+    }
+
+    // Call once the employee row is gone. The deletion has already happened, so a storage failure
+    // (logged by the storage) leaves an orphaned photo rather than failing the delete or skipping
+    // its audit entry.
+    pub(crate) async fn discard_photo(&self, id: Uuid) {
+        if self.photos.delete(id).await.is_err() {
+            tracing::warn!(employee_id = %id, "photo of deleted employee left in storage");
+        }
+    }
+
+    pub(crate) fn publish(
+        &self,
+        action: AuditAction,
+        employee_id: Uuid,
+        employee: Option<&Employee>,
+    ) {
+        // Fails only when nobody is subscribed, which is fine
+        let _ = self.events.send(EmployeeEvent {
+            action,
+            employee_id,
+            employee: employee.cloned(),
+        });
+    }
+
+    pub(crate) fn employee_view(
+        &self,
+        employee: Employee,
+        departments: Option<&HashMap<Uuid, Department>>,
+    ) -> EmployeeWithDepartment {
+        EmployeeWithDepartment::new(employee, departments)
+            .with_links(self.links_base_url.as_deref())
+    }
+
+    pub(crate) fn ensure_writable(&self) -> Result<(), AppError> {
+        if self.maintenance.load(Ordering::Relaxed) {
+            Err(AppError::Maintenance)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Absolute URL of a path under the current API version
+    pub(crate) fn api_url(&self, path: &str) -> String {
+        format!("{}{}{path}", self.public_base_url, v1::PREFIX)
+    }
+
+    pub(crate) fn employee_location(&self, id: Uuid) -> (HeaderName, HeaderValue) {
+        let location = self.api_url(&format!("/employees/{id}"));
+        // PUBLIC_BASE_URL is checked to be an http(s) URL at startup
+        (
+            LOCATION,
+            HeaderValue::from_str(&location).expect("valid Location header"),
+        )
+    }
+
+    // In-memory stores with demo data; the default when no database is configured
+    pub fn seeded(config: &Config, clock: Arc<dyn Clock>) -> Self {
+        let departments = seed_departments();
+        let employees = seed_employees(&departments, clock.now());
+        Self::in_memory(config, clock, departments, employees)
+    }
+
+    // In-memory stores holding config.seed_file's records, or the built-in demo data
+    pub fn from_seed(config: &Config, clock: Arc<dyn Clock>) -> anyhow::Result<Self> {
+        let Some(path) = &config.seed_file else {
+            return Ok(Self::seeded(config, clock));
+        };
+        let (departments, employees) = load_seed_file(path, clock.now())?;
+        tracing::info!(
+            departments = departments.len(),
+            employees = employees.len(),
+            "🌱 loaded seed data from {}",
+            path.display()
+        );
+        Ok(Self::in_memory(config, clock, departments, employees))
+    }
+
+    pub(crate) fn in_memory(
+        config: &Config,
+        clock: Arc<dyn Clock>,
+        departments: Vec<Department>,
+        employees: Vec<Employee>,
+    ) -> Self {
+        Self::new(
+            config,
+            clock.clone(),
+            Arc::new(InMemoryRepository::new(employees, clock)),
+            Arc::new(InMemoryDepartmentRepository::new(departments)),
+        )
+    }
+}
@@ -0,0 +1,160 @@
+#[cfg(feature = "s3")]
+pub mod s3;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::body::Bytes;
+use uuid::Uuid;
+
+use crate::handlers::photos::sniff_photo_type;
+use crate::repository::RepoError;
+
+pub(crate) const MAX_PHOTO_BYTES: usize = 5 * 1024 * 1024;
+
+#[derive(Clone)]
+pub(crate) struct Photo {
+    pub(crate) content_type: &'static str,
+    pub(crate) bytes: Bytes,
+}
+
+// Where photo bytes live, chosen by PHOTO_STORAGE; the photo endpoints only see this trait
+#[async_trait]
+pub(crate) trait PhotoStorage: Send + Sync {
+    // Replaces any existing photo for the employee
+    async fn put(&self, id: Uuid, photo: Photo) -> Result<(), RepoError>;
+
+    async fn get(&self, id: Uuid) -> Result<Option<Photo>, RepoError>;
+
+    // Deleting a photo that does not exist is not an error
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError>;
+
+    async fn exists(&self, id: Uuid) -> Result<bool, RepoError> {
+        Ok(self.get(id).await?.is_some())
+    }
+
+    // A URL that downloads the photo without the API for `ttl`, or None when the backend
+    // cannot issue one and clients should use the photo endpoint
+    async fn presigned_url(&self, _id: Uuid, _ttl: Duration) -> Result<Option<String>, RepoError> {
+        Ok(None)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum PhotoStorageConfig {
+    // Lost on restart; the default for the demo
+    Memory,
+    Local {
+        dir: PathBuf,
+    },
+    #[cfg(feature = "s3")]
+    S3 {
+        bucket: String,
+        // Prepended to the employee id to form the object key
+        prefix: String,
+    },
+}
+
+impl PhotoStorageConfig {
+    pub(crate) fn build(&self) -> Arc<dyn PhotoStorage> {
+        match self {
+            PhotoStorageConfig::Memory => Arc::new(InMemoryPhotoStorage::default()),
+            PhotoStorageConfig::Local { dir } => Arc::new(LocalFsStorage::new(dir.clone())),
+            #[cfg(feature = "s3")]
+            PhotoStorageConfig::S3 { bucket, prefix } => {
+                Arc::new(s3::S3Storage::new(bucket.clone(), prefix.clone()))
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct InMemoryPhotoStorage {
+    pub(crate) photos: RwLock<HashMap<Uuid, Photo>>,
+}
+
+#[async_trait]
+impl PhotoStorage for InMemoryPhotoStorage {
+    async fn put(&self, id: Uuid, photo: Photo) -> Result<(), RepoError> {
+        self.photos.write()?.insert(id, photo);
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Photo>, RepoError> {
+        Ok(self.photos.read()?.get(&id).cloned())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        self.photos.write()?.remove(&id);
+        Ok(())
+    }
+}
+
+// One file per employee, named by id. The content type is sniffed again on read, since only
+// JPEG and PNG are ever accepted.
+pub(crate) struct LocalFsStorage {
+    pub(crate) dir: PathBuf,
+}
+
+impl LocalFsStorage {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    pub(crate) fn path(&self, id: Uuid) -> PathBuf {
+        self.dir.join(id.to_string())
+    }
+}
+
+pub(crate) fn storage_error(err: impl std::fmt::Display) -> RepoError {
+    tracing::error!(error = %err, "photo storage failed");
+    RepoError::Unavailable("Photo storage is unavailable".to_string())
+}
+
+#[async_trait]
+impl PhotoStorage for LocalFsStorage {
+    async fn put(&self, id: Uuid, photo: Photo) -> Result<(), RepoError> {
+        tokio::fs::create_dir_all(&self.dir)
+            .await
+            .map_err(storage_error)?;
+        // Written aside and renamed so a reader never sees a half-written file
+        let partial = self.dir.join(format!("{id}.partial"));
+        tokio::fs::write(&partial, &photo.bytes)
+            .await
+            .map_err(storage_error)?;
+        tokio::fs::rename(&partial, self.path(id))
+            .await
+            .map_err(storage_error)
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Photo>, RepoError> {
+        match tokio::fs::read(self.path(id)).await {
+            Ok(bytes) => {
+                let content_type = sniff_photo_type(&bytes)
+                    .ok_or_else(|| storage_error(format!("photo for {id} is not JPEG or PNG")))?;
+                Ok(Some(Photo {
+                    content_type,
+                    bytes: Bytes::from(bytes),
+                }))
+            }
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(storage_error(err)),
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        match tokio::fs::remove_file(self.path(id)).await {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(storage_error(err)),
+            _ => Ok(()),
+        }
+    }
+
+    async fn exists(&self, id: Uuid) -> Result<bool, RepoError> {
+        tokio::fs::try_exists(self.path(id))
+            .await
+            .map_err(storage_error)
+    }
+}
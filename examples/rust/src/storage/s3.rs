@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::handlers::photos::sniff_photo_type;
+use crate::repository::RepoError;
+use crate::storage::{storage_error, Photo, PhotoStorage};
+
+// Credentials and region come from the standard AWS chain (env, profile, instance role)
+
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
+use tokio::sync::OnceCell;
+
+pub(crate) struct S3Storage {
+    pub(crate) bucket: String,
+    pub(crate) prefix: String,
+    // Loading the AWS config is async, so the client is made on first use
+    pub(crate) client: OnceCell<aws_sdk_s3::Client>,
+}
+
+impl S3Storage {
+    pub(crate) fn new(bucket: String, prefix: String) -> Self {
+        Self {
+            bucket,
+            prefix,
+            client: OnceCell::new(),
+        }
+    }
+
+    pub(crate) async fn client(&self) -> &aws_sdk_s3::Client {
+        self.client
+            .get_or_init(|| async {
+                let config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+                aws_sdk_s3::Client::new(&config)
+            })
+            .await
+    }
+
+    pub(crate) fn key(&self, id: Uuid) -> String {
+        format!("{}{id}", self.prefix)
+    }
+}
+
+#[async_trait]
+impl PhotoStorage for S3Storage {
+    async fn put(&self, id: Uuid, photo: Photo) -> Result<(), RepoError> {
+        self.client()
+            .await
+            .put_object()
+            .bucket(&self.bucket)
+            .key(self.key(id))
+            .content_type(photo.content_type)
+            .body(ByteStream::from(photo.bytes.to_vec()))
+            .send()
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Photo>, RepoError> {
+        let output = match self
+            .client()
+            .await
+            .get_object()
+            .bucket(&self.bucket)
+            .key(self.key(id))
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(err) if err.as_service_error().is_some_and(|e| e.is_no_such_key()) => {
+                return Ok(None)
+            }
+            Err(err) => return Err(storage_error(err)),
+        };
+        let bytes = output
+            .body
+            .collect()
+            .await
+            .map_err(storage_error)?
+            .into_bytes();
+        let content_type = sniff_photo_type(&bytes)
+            .ok_or_else(|| storage_error(format!("photo for {id} is not JPEG or PNG")))?;
+        Ok(Some(Photo {
+            content_type,
+            bytes,
+        }))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        // S3 deletes succeed for missing keys too
+        self.client()
+            .await
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(self.key(id))
+            .send()
+            .await
+            .map_err(storage_error)?;
+        Ok(())
+    }
+
+    async fn exists(&self, id: Uuid) -> Result<bool, RepoError> {
+        match self
+            .client()
+            .await
+            .head_object()
+            .bucket(&self.bucket)
+            .key(self.key(id))
+            .send()
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(err) if err.as_service_error().is_some_and(|e| e.is_not_found()) => Ok(false),
+            Err(err) => Err(storage_error(err)),
+        }
+    }
+
+    // Signed locally with the loaded credentials; no request is sent to S3
+    async fn presigned_url(&self, id: Uuid, ttl: Duration) -> Result<Option<String>, RepoError> {
+        let presigning = PresigningConfig::expires_in(ttl).map_err(storage_error)?;
+        let request = self
+            .client()
+            .await
+            .get_object()
+            .bucket(&self.bucket)
+            .key(self.key(id))
+            .presigned(presigning)
+            .await
+            .map_err(storage_error)?;
+        Ok(Some(request.uri().to_string()))
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::error::AppError;
+use crate::middleware::auth::Claims;
+use crate::models::departments::Department;
+use crate::models::employees::{Employee, EmploymentStatus, ListEmployeesQuery};
+
+// List filters parsed and validated once, then applied per employee
+pub(crate) struct EmployeeFilter {
+    pub(crate) statuses: Option<Vec<EmploymentStatus>>,
+    pub(crate) department_ids: Option<HashSet<Uuid>>,
+    pub(crate) hired_after: Option<NaiveDate>,
+    pub(crate) hired_before: Option<NaiveDate>,
+    pub(crate) comparisons: Vec<Comparison>,
+}
+
+impl EmployeeFilter {
+    pub(crate) fn from_params(
+        params: &ListEmployeesQuery,
+        departments: &HashMap<Uuid, Department>,
+    ) -> Result<Self, AppError> {
+        if let (Some(after), Some(before)) = (params.hired_after, params.hired_before) {
+            if after > before {
+                return Err(AppError::BadRequest(
+                    "hired_after must not be later than hired_before".to_string(),
+                ));
+            }
+        }
+
+        // `?status=` or `?status=,` names no status, so it filters nothing out
+        let statuses = params
+            .status
+            .as_deref()
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|status| !status.is_empty())
+                    .map(str::parse)
+                    .collect::<Result<Vec<EmploymentStatus>, _>>()
+            })
+            .transpose()?
+            .filter(|statuses| !statuses.is_empty());
+
+        // An unknown department is not an error; it simply matches nobody. An empty one matches
+        // everybody, like `?status=`
+        let department = params.department.as_deref().map(str::trim);
+        let department_ids = department
+            .filter(|wanted| !wanted.is_empty())
+            .map(|wanted| {
+                departments
+                    .values()
+                    .filter(|d| {
+                        d.code.eq_ignore_ascii_case(wanted) || d.name.eq_ignore_ascii_case(wanted)
+                    })
+                    .map(|d| d.id)
+                    .collect()
+            });
+
+        Ok(Self {
+            statuses,
+            department_ids,
+            hired_after: params.hired_after,
+            hired_before: params.hired_before,
+            comparisons: Vec::new(),
+        })
+    }
+
+    pub(crate) fn with_comparisons(self, comparisons: Vec<Comparison>) -> Self {
+        Self {
+            comparisons,
+            ..self
+        }
+    }
+
+    pub(crate) fn matches(&self, employee: &Employee) -> bool {
+        self.statuses
+            .as_ref()
+            .is_none_or(|statuses| statuses.contains(&employee.employment_status))
+            && self
+                .department_ids
+                .as_ref()
+                .is_none_or(|ids| employee.department_id.is_some_and(|id| ids.contains(&id)))
+            && self
+                .hired_after
+                .is_none_or(|after| employee.hire_date >= after)
+            && self
+                .hired_before
+                .is_none_or(|before| employee.hire_date <= before)
+            && self
+                .comparisons
+                .iter()
+                .all(|comparison| comparison.matches(employee))
+    }
+}
+
+// `field[op]=value` query parameters, e.g. `hire_date[gte]=2020-01-01`. Each one narrows the
+// result further, so a `gte` and an `lte` on the same field give a range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum CompareOp {
+    Eq,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+impl CompareOp {
+    pub(crate) fn parse(field: &str, op: &str) -> Result<Self, AppError> {
+        match op {
+            "eq" => Ok(CompareOp::Eq),
+            "gt" => Ok(CompareOp::Gt),
+            "gte" => Ok(CompareOp::Gte),
+            "lt" => Ok(CompareOp::Lt),
+            "lte" => Ok(CompareOp::Lte),
+            other => Err(AppError::BadRequest(format!(
+                "Unknown operator '{other}' in {field}[{other}]; expected eq, gt, gte, lt or lte"
+            ))),
+        }
+    }
+
+    pub(crate) fn holds<T: PartialOrd>(self, actual: &T, operand: &T) -> bool {
+        match self {
+            CompareOp::Eq => actual == operand,
+            CompareOp::Gt => actual > operand,
+            CompareOp::Gte => actual >= operand,
+            CompareOp::Lt => actual < operand,
+            CompareOp::Lte => actual <= operand,
+        }
+    }
+}
+
+pub(crate) enum Comparison {
+    HireDate(CompareOp, NaiveDate),
+    Salary(CompareOp, Decimal),
+}
+
+impl Comparison {
+    // Picks the `field[op]` pairs out of the query; plain parameters are left to
+    // ListEmployeesQuery. Salary is gated, so filtering on it is too.
+    pub(crate) fn parse_all(
+        query: &[(String, String)],
+        claims: &Claims,
+    ) -> Result<Vec<Self>, AppError> {
+        let mut comparisons = Vec::new();
+        for (key, value) in query {
+            let Some((field, op)) = key.strip_suffix(']').and_then(|key| key.split_once('['))
+            else {
+                continue;
+            };
+            let op = CompareOp::parse(field, op)?;
+            let invalid = |expected: &str| {
+                AppError::BadRequest(format!("{key} must be {expected}, got '{value}'"))
+            };
+            let comparison = match field {
+                "hire_date" => Comparison::HireDate(
+                    op,
+                    value.parse().map_err(|_| invalid("a date (YYYY-MM-DD)"))?,
+                ),
+                "salary" if !claims.can_read_sensitive() => return Err(AppError::Forbidden),
+                "salary" => {
+                    Comparison::Salary(op, value.parse().map_err(|_| invalid("a decimal number"))?)
+                }
+                other => {
+                    return Err(AppError::BadRequest(format!(
+                        "Cannot compare on '{other}'; expected hire_date or salary"
+                    )))
+                }
+            };
+            comparisons.push(comparison);
+        }
+        Ok(comparisons)
+    }
+
+    // Employees without a salary never match a salary comparison
+    pub(crate) fn matches(&self, employee: &Employee) -> bool {
+        match self {
+            Comparison::HireDate(op, date) => op.holds(&employee.hire_date, date),
+            Comparison::Salary(op, amount) => employee
+                .salary
+                .is_some_and(|salary| op.holds(&salary, amount)),
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use axum::http::{header::LINK, HeaderMap, HeaderValue, Uri};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use uuid::Uuid;
+
+use crate::config::app::PageLimits;
+use crate::error::AppError;
+use crate::models::common::{
+    CursorPaginationInfo, OffsetPaginationInfo, PaginatedResponse, PaginationInfo,
+};
+use crate::models::employees::Employee;
+
+pub(crate) const X_TOTAL_COUNT: &str = "x-total-count";
+
+pub(crate) fn insert_pagination_headers(headers: &mut HeaderMap, total: u64, links: &[String]) {
+    headers.insert(X_TOTAL_COUNT, HeaderValue::from(total));
+    // Built from the request URI, which only holds valid header characters
+    if let Some(value) = (!links.is_empty())
+        .then(|| HeaderValue::from_str(&links.join(", ")).ok())
+        .flatten()
+    {
+        headers.insert(LINK, value);
+    }
+}
+
+// Each link repeats the request's query string with only the page (or cursor) replaced
+pub(crate) fn pagination_links(uri: &Uri, pagination: &PaginationInfo) -> Vec<String> {
+    let link = |key: &str, value: String, rel: &str| {
+        let param = format!("{key}={value}");
+        let query = uri
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty() && pair.split('=').next() != Some(key))
+            .chain([param.as_str()])
+            .collect::<Vec<_>>()
+            .join("&");
+        format!("<{}?{query}>; rel=\"{rel}\"", uri.path())
+    };
+
+    let pagination = match pagination {
+        PaginationInfo::Offset(pagination) => pagination,
+        PaginationInfo::Cursor(pagination) => {
+            return [
+                (&pagination.prev_cursor, "prev"),
+                (&pagination.next_cursor, "next"),
+            ]
+            .into_iter()
+            .filter_map(|(cursor, rel)| Some(link("cursor", cursor.clone()?, rel)))
+            .collect();
+        }
+    };
+
+    let page = pagination.page;
+    let last = pagination.total_pages.max(1);
+    let mut links = vec![link("page", "1".to_string(), "first")];
+    if page > 1 {
+        links.push(link("page", (page - 1).min(last).to_string(), "prev"));
+    }
+    if page < pagination.total_pages {
+        links.push(link("page", (page + 1).to_string(), "next"));
+    }
+    links.push(link("page", last.to_string(), "last"));
+    links
+}
+
+pub(crate) fn offset_page<T: Clone>(
+    matching: Vec<&T>,
+    page: Option<u32>,
+    per_page: Option<u32>,
+    limits: PageLimits,
+) -> Result<PaginatedResponse<T>, AppError> {
+    let page = page.unwrap_or(1);
+    if page == 0 {
+        return Err(AppError::BadRequest(
+            "page must be at least 1, got 0".to_string(),
+        ));
+    }
+    let per_page = limits.resolve("per_page", per_page)?;
+
+    let total = matching.len() as u64;
+    let total_pages = total.div_ceil(u64::from(per_page)) as u32;
+
+    // Pages past the end are valid requests that simply have no data
+    let offset = (page as usize - 1).saturating_mul(per_page as usize);
+    let data = matching
+        .into_iter()
+        .skip(offset)
+        .take(per_page as usize)
+        .cloned()
+        .collect();
+
+    Ok(PaginatedResponse {
+        data,
+        pagination: PaginationInfo::Offset(OffsetPaginationInfo {
+            page,
+            per_page,
+            total,
+            total_pages,
+        }),
+    })
+}
+
+// Keyset pagination ordered by id: stable even when employees are added or removed mid-walk
+pub(crate) fn cursor_page(
+    mut matching: Vec<&Employee>,
+    cursor: Option<&str>,
+    limit: Option<u32>,
+    limits: PageLimits,
+) -> Result<PaginatedResponse<Employee>, AppError> {
+    let limit = limits.resolve("limit", limit)?;
+    matching.sort_by_key(|e| e.id);
+
+    let start = match cursor {
+        Some(cursor) => {
+            let after = decode_cursor(cursor)?;
+            matching.partition_point(|e| e.id <= after)
+        }
+        None => 0,
+    };
+    let end = matching.len().min(start + limit as usize);
+    let has_more = end < matching.len();
+    let next_cursor = has_more.then(|| encode_cursor(matching[end - 1].id));
+    // A cursor means "after this id", so the nil id, which sorts first, leads back to page one
+    let prev_cursor = (start > 0).then(|| {
+        let prev_start = start.saturating_sub(limit as usize);
+        encode_cursor(
+            prev_start
+                .checked_sub(1)
+                .map_or(Uuid::nil(), |i| matching[i].id),
+        )
+    });
+
+    Ok(PaginatedResponse {
+        data: matching[start..end].iter().copied().cloned().collect(),
+        pagination: PaginationInfo::Cursor(CursorPaginationInfo {
+            limit,
+            total: matching.len() as u64,
+            has_more,
+            next_cursor,
+            prev_cursor,
+        }),
+    })
+}
+
+pub(crate) fn encode_cursor(id: Uuid) -> String {
+    URL_SAFE_NO_PAD.encode(id.as_bytes())
+}
+
+pub(crate) fn decode_cursor(cursor: &str) -> Result<Uuid, AppError> {
+    URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| Uuid::from_slice(&bytes).ok())
+        .ok_or_else(|| AppError::BadRequest("Invalid cursor".to_string()))
+}
@@ -0,0 +1,39 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use rust_decimal::Decimal;
+
+pub(crate) static EMPLOYEE_ID_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[A-Z]{3}[0-9]{3}$").expect("valid employee_id pattern"));
+pub(crate) static DEPARTMENT_CODE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[A-Z]{2,10}$").expect("valid department code pattern"));
+
+// ISO 4217 codes accepted for salaries; extend as payroll supports more
+pub(crate) const SUPPORTED_CURRENCIES: [&str; 8] =
+    ["AUD", "CAD", "CHF", "EUR", "GBP", "JPY", "SGD", "USD"];
+
+pub(crate) fn validate_currency(currency: &str) -> Result<(), validator::ValidationError> {
+    if SUPPORTED_CURRENCIES.contains(&currency) {
+        Ok(())
+    } else {
+        let allowed = SUPPORTED_CURRENCIES.join(", ");
+        let mut error = validator::ValidationError::new("unsupported_currency");
+        error.message = Some(format!("must be one of {allowed}").into());
+        error.add_param("allowed".into(), &allowed);
+        Err(error)
+    }
+}
+
+pub(crate) fn validate_salary(salary: &Decimal) -> Result<(), validator::ValidationError> {
+    if salary.is_sign_negative() {
+        let mut error = validator::ValidationError::new("negative_salary");
+        error.message = Some("must not be negative".into());
+        Err(error)
+    } else {
+        Ok(())
+    }
+}
+
+pub(crate) fn default_currency() -> String {
+    "USD".to_string()
+}
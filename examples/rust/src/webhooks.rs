@@ -0,0 +1,123 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use serde::Serialize;
+use tokio::sync::broadcast;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::clock::Clock;
+use crate::config::app::WebhookConfig;
+use crate::events::EmployeeEvent;
+
+// Body POSTed to WEBHOOK_URL. utoipa 4 emits OpenAPI 3.0, which has no `webhooks` section,
+// so the shape is published as a component schema and described under the `webhooks` tag.
+#[derive(Serialize, ToSchema)]
+pub(crate) struct WebhookPayload {
+    /// Unique per event; repeated on every retry so receivers can deduplicate
+    pub(crate) id: Uuid,
+    /// `employee.created`, `employee.updated` or `employee.deleted`
+    #[schema(example = "employee.created")]
+    pub(crate) event: String,
+    #[schema(format = "date-time")]
+    pub(crate) occurred_at: chrono::DateTime<Utc>,
+    pub(crate) data: EmployeeEvent,
+}
+
+pub(crate) const WEBHOOK_ATTEMPTS: u32 = 5;
+pub(crate) const WEBHOOK_SIGNATURE: &str = "x-signature";
+
+// Forwards every published event to the webhook. Each delivery runs on its own task so one
+// slow or failing receiver can't hold up the events behind it.
+pub(crate) fn spawn_webhook_delivery(
+    config: WebhookConfig,
+    mut events: broadcast::Receiver<EmployeeEvent>,
+    clock: Arc<dyn Clock>,
+) {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("static reqwest configuration is valid");
+    let config = Arc::new(config);
+    tokio::spawn(async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(broadcast::error::RecvError::Lagged(missed)) => {
+                    tracing::warn!(missed, "webhook delivery fell behind, events dropped");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+            let payload = WebhookPayload {
+                id: Uuid::new_v4(),
+                event: format!("employee.{}", event.action.as_str()),
+                occurred_at: clock.now(),
+                data: event,
+            };
+            match serde_json::to_vec(&payload) {
+                Ok(body) => {
+                    tokio::spawn(deliver_webhook(
+                        client.clone(),
+                        config.clone(),
+                        payload.id,
+                        body,
+                    ));
+                }
+                Err(err) => tracing::error!(error = %err, "failed to serialize webhook payload"),
+            }
+        }
+    });
+}
+
+// Retries network errors, 429s and 5xx with exponential backoff (1s, 2s, 4s, ...); any other
+// response is final
+pub(crate) async fn deliver_webhook(
+    client: reqwest::Client,
+    config: Arc<WebhookConfig>,
+    delivery: Uuid,
+    body: Vec<u8>,
+) {
+    let signature = webhook_signature(&config.secret, &body);
+    for attempt in 1..=WEBHOOK_ATTEMPTS {
+        let result = client
+            .post(&config.url)
+            .header("content-type", "application/json")
+            .header(WEBHOOK_SIGNATURE, &signature)
+            .body(body.clone())
+            .send()
+            .await;
+        let retry = match result {
+            Ok(response) if response.status().is_success() => return,
+            Ok(response) => {
+                let status = response.status();
+                tracing::warn!(%delivery, attempt, %status, "webhook rejected");
+                status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+            }
+            Err(err) => {
+                tracing::warn!(%delivery, attempt, error = %err, "webhook delivery failed");
+                true
+            }
+        };
+        if !retry {
+            return;
+        }
+        if attempt < WEBHOOK_ATTEMPTS {
+            tokio::time::sleep(Duration::from_secs(1 << (attempt - 1))).await;
+        }
+    }
+    tracing::error!(%delivery, "giving up on webhook after {WEBHOOK_ATTEMPTS} attempts");
+}
+
+// `sha256=` followed by the hex HMAC-SHA256 of the exact body bytes, keyed with WEBHOOK_SECRET
+pub(crate) fn webhook_signature(secret: &str, body: &[u8]) -> String {
+    use hmac::{Hmac, Mac};
+
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(body);
+    let digest = mac.finalize().into_bytes();
+    let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("sha256={hex}")
+}
@@ -0,0 +1,45 @@
+use axum::body::Body;
+use axum::http::{header, Method, StatusCode};
+
+use crate::common::{app, body_json, get, json_request, new_employee, request, send};
+
+#[tokio::test]
+async fn create_then_update_leaves_two_entries() {
+    let app = app();
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+    let created: serde_json::Value = body_json(response).await;
+    let uri = format!("/v1/employees/{}", created["id"].as_str().unwrap());
+
+    let update = request(Method::PATCH, &uri)
+        .header(header::CONTENT_TYPE, "application/merge-patch+json")
+        .header(header::IF_MATCH, "*")
+        .body(Body::from(r#"{"position":"Staff Engineer"}"#))
+        .unwrap();
+    assert_eq!(send(&app, update).await.status(), StatusCode::OK);
+
+    let response = send(&app, get(&format!("{uri}/audit"))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let audit: serde_json::Value = body_json(response).await;
+    let entries = audit["data"].as_array().unwrap();
+    let actions: Vec<&str> = entries
+        .iter()
+        .map(|entry| entry["action"].as_str().unwrap())
+        .collect();
+    assert_eq!(actions, ["created", "updated"]);
+    assert!(entries
+        .iter()
+        .all(|entry| entry["actor"] == "integration-test"));
+    assert_eq!(entries[0]["changes"], serde_json::json!({}));
+    assert_eq!(
+        entries[1]["changes"],
+        serde_json::json!({ "position": { "old": "Engineer", "new": "Staff Engineer" } })
+    );
+}
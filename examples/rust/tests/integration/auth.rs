@@ -0,0 +1,171 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use openapi_integration::config::app::Config;
+use openapi_integration::routes::create_app;
+
+use crate::common::{
+    admin_token, app, body_json, error_code, json_request_with_token, new_employee,
+    request_with_token, send, token, JWT_SECRET,
+};
+
+#[tokio::test]
+async fn reader_can_list_but_not_create() {
+    let app = app();
+    // Holds the write scope, so only the missing admin role can refuse the create
+    let reader = token(&["reader"], "employees:read employees:write");
+
+    let listed = send(
+        &app,
+        request_with_token(&reader, Method::GET, "/v1/employees")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(listed.status(), StatusCode::OK);
+
+    let body = new_employee("TST001", "test.employee@company.com");
+    let created = send(
+        &app,
+        json_request_with_token(&reader, Method::POST, "/v1/employees", &body),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::FORBIDDEN);
+    let body: serde_json::Value = body_json(created).await;
+    assert_eq!(error_code(&body), "forbidden");
+}
+
+#[tokio::test]
+async fn missing_token_is_an_invalid_request_challenge() {
+    let request = Request::builder()
+        .uri("/v1/employees")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = send(&app(), request).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer realm="api", error="invalid_request""#
+    );
+}
+
+#[tokio::test]
+async fn bad_token_is_an_invalid_token_challenge() {
+    let request = request_with_token("not-a-jwt", Method::GET, "/v1/employees")
+        .body(Body::empty())
+        .unwrap();
+
+    let response = send(&app(), request).await;
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer realm="api", error="invalid_token""#
+    );
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "unauthorized");
+}
+
+#[tokio::test]
+async fn create_without_the_write_scope_is_insufficient_scope() {
+    let app = app();
+    // An admin, so only the missing scope can refuse the create
+    let read_only = token(&["admin"], "employees:read");
+    let body = new_employee("TST001", "test.employee@company.com");
+
+    let response = send(
+        &app,
+        json_request_with_token(&read_only, Method::POST, "/v1/employees", &body),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    assert_eq!(
+        response.headers()[header::WWW_AUTHENTICATE],
+        r#"Bearer error="insufficient_scope", scope="employees:write""#
+    );
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "forbidden");
+    let listed = send(
+        &app,
+        request_with_token(&read_only, Method::GET, "/v1/employees")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    let page: serde_json::Value = body_json(listed).await;
+    assert_eq!(page["pagination"]["total"], 5);
+}
+
+#[tokio::test]
+async fn compensation_is_only_shown_to_privileged_readers() {
+    let app = app();
+    let body = new_employee("TST001", "test.employee@company.com");
+    let created = send(
+        &app,
+        json_request_with_token(&admin_token(), Method::POST, "/v1/employees", &body),
+    )
+    .await;
+    let created: serde_json::Value = body_json(created).await;
+    let uri = format!("/v1/employees/{}", created["id"].as_str().unwrap());
+    let view = |token: String| {
+        let app = app.clone();
+        let uri = uri.clone();
+        async move {
+            let response = send(
+                &app,
+                request_with_token(&token, Method::GET, &uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await;
+            assert_eq!(response.status(), StatusCode::OK);
+            body_json::<serde_json::Value>(response).await
+        }
+    };
+
+    let admin = view(admin_token()).await;
+    let reader = view(token(&["reader"], "employees:read")).await;
+    let sensitive = view(token(
+        &["reader"],
+        "employees:read employees:read-sensitive",
+    ))
+    .await;
+
+    assert_eq!(admin["salary"], "65000.00");
+    assert!(admin["currency"].is_string(), "{admin}");
+    assert!(reader.get("salary").is_none(), "{reader}");
+    assert!(reader.get("currency").is_none(), "{reader}");
+    assert_eq!(reader["employee_id"], admin["employee_id"]);
+    assert_eq!(sensitive["salary"], admin["salary"]);
+}
+
+#[tokio::test]
+async fn dev_auth_accepts_any_token_only_in_debug_builds() {
+    let config = Config::from_lookup(|name| match name {
+        "JWT_SECRET" => Some(JWT_SECRET.to_string()),
+        "DEV_AUTH" => Some("true".to_string()),
+        _ => None,
+    });
+    if !cfg!(debug_assertions) {
+        let Err(error) = config else {
+            panic!("release builds refuse DEV_AUTH");
+        };
+        assert!(error.to_string().contains("DEV_AUTH"), "{error:#}");
+        return;
+    }
+    let dev = create_app(&config.expect("debug builds accept DEV_AUTH"));
+    let dummy = || {
+        request_with_token("dummy", Method::GET, "/v1/employees")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    assert_eq!(send(&dev, dummy()).await.status(), StatusCode::OK);
+    // Without DEV_AUTH the same token is checked like any other
+    assert_eq!(
+        send(&app(), dummy()).await.status(),
+        StatusCode::UNAUTHORIZED
+    );
+}
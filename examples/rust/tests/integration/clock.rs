@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use axum::http::{Method, StatusCode};
+use chrono::{DateTime, Utc};
+use openapi_integration::clock::FixedClock;
+use openapi_integration::models::common::PaginatedResponse;
+use openapi_integration::models::employees::Employee;
+use openapi_integration::routes::create_app_with_clock;
+
+use crate::common::{body_json, config_with, get, json_request, new_employee, send};
+
+fn pinned() -> DateTime<Utc> {
+    "2025-03-01T09:00:00Z".parse().unwrap()
+}
+
+#[tokio::test]
+async fn create_stamps_the_clock_time() {
+    let app = create_app_with_clock(&config_with(&[]), Arc::new(FixedClock::new(pinned())));
+
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: Employee = body_json(response).await;
+    assert_eq!(created.created_at, pinned());
+    assert_eq!(created.updated_at, pinned());
+}
+
+#[tokio::test]
+async fn seed_data_is_stamped_by_the_clock() {
+    let app = create_app_with_clock(&config_with(&[]), Arc::new(FixedClock::new(pinned())));
+
+    let page: PaginatedResponse<Employee> = body_json(send(&app, get("/v1/employees")).await).await;
+
+    assert!(page.data.iter().all(|e| e.created_at == pinned()));
+}
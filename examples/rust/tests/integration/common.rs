@@ -0,0 +1,181 @@
+use axum::body::{to_bytes, Body};
+use axum::http::{header, Method, Request, Response};
+use axum::Router;
+use chrono::NaiveDate;
+use jsonwebtoken::{EncodingKey, Header};
+use openapi_integration::builders::CreateEmployeeRequestBuilder;
+use openapi_integration::config::app::Config;
+use openapi_integration::routes::create_app;
+use rust_decimal::Decimal;
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use tower::ServiceExt;
+
+pub const JWT_SECRET: &str = "integration-test-secret";
+
+pub const ALL_SCOPES: &str =
+    "employees:read employees:read-sensitive employees:write departments:read departments:write";
+
+// Settings as the environment would supply them; anything not listed keeps its default
+pub fn config_with(vars: &[(&str, &str)]) -> Config {
+    Config::from_lookup(|name| match name {
+        "JWT_SECRET" => Some(JWT_SECRET.to_string()),
+        _ => vars
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string()),
+    })
+    .expect("valid test configuration")
+}
+
+pub fn app() -> Router {
+    create_app(&config_with(&[]))
+}
+
+// An HS256 token signed with JWT_SECRET, valid for an hour
+pub fn token(roles: &[&str], scope: &str) -> String {
+    let claims = json!({
+        "sub": "integration-test",
+        "roles": roles,
+        "scope": scope,
+        "exp": chrono::Utc::now().timestamp() + 3600,
+    });
+    jsonwebtoken::encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(JWT_SECRET.as_bytes()),
+    )
+    .expect("token encodes")
+}
+
+pub fn admin_token() -> String {
+    token(&["admin"], ALL_SCOPES)
+}
+
+// A request authenticated as an admin holding every scope
+pub fn request(method: Method, uri: &str) -> axum::http::request::Builder {
+    request_with_token(&admin_token(), method, uri)
+}
+
+// A request sending `token` as its bearer token, e.g. one from `token` or a malformed one
+pub fn request_with_token(token: &str, method: Method, uri: &str) -> axum::http::request::Builder {
+    Request::builder()
+        .method(method)
+        .uri(uri)
+        .header(header::AUTHORIZATION, format!("Bearer {token}"))
+}
+
+pub fn get(uri: &str) -> Request<Body> {
+    request(Method::GET, uri).body(Body::empty()).unwrap()
+}
+
+pub fn json_request(method: Method, uri: &str, body: &serde_json::Value) -> Request<Body> {
+    json_request_with_token(&admin_token(), method, uri, body)
+}
+
+pub fn json_request_with_token(
+    token: &str,
+    method: Method,
+    uri: &str,
+    body: &serde_json::Value,
+) -> Request<Body> {
+    request_with_token(token, method, uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+pub async fn send(app: &Router, request: Request<Body>) -> Response<Body> {
+    app.clone()
+        .oneshot(request)
+        .await
+        .expect("router is infallible")
+}
+
+pub async fn body_bytes(response: Response<Body>) -> Vec<u8> {
+    to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("body reads")
+        .to_vec()
+}
+
+pub async fn body_json<T: DeserializeOwned>(response: Response<Body>) -> T {
+    let bytes = body_bytes(response).await;
+    serde_json::from_slice(&bytes).unwrap_or_else(|error| {
+        panic!(
+            "body is not the expected JSON ({error}): {}",
+            String::from_utf8_lossy(&bytes)
+        )
+    })
+}
+
+// A valid create body; the employee_id and email must be unused in the seed data
+pub fn new_employee(employee_id: &str, email: &str) -> serde_json::Value {
+    let hire_date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    let request = CreateEmployeeRequestBuilder::new(employee_id, "Test", "Employee", hire_date)
+        .with_email(email)
+        .with_position("Engineer")
+        .with_salary(Decimal::new(6_500_000, 2), "USD")
+        .build();
+    serde_json::to_value(request).expect("create requests serialize")
+}
+
+// The machine-readable code of an error body: `error.code`, or the last segment of the problem
+// `type` with the `problem-json` feature
+pub fn error_code(body: &serde_json::Value) -> &str {
+    #[cfg(not(feature = "problem-json"))]
+    let code = body["error"]["code"].as_str();
+    #[cfg(feature = "problem-json")]
+    let code = body["type"]
+        .as_str()
+        .and_then(|problem_type| problem_type.rsplit('/').next());
+    code.unwrap_or_else(|| panic!("not an error body: {body}"))
+}
+
+// The human-readable message of an error body: `error.message`, or the problem `detail`
+pub fn error_message(body: &serde_json::Value) -> &str {
+    #[cfg(not(feature = "problem-json"))]
+    let message = body["error"]["message"].as_str();
+    #[cfg(feature = "problem-json")]
+    let message = body["detail"].as_str();
+    message.unwrap_or_else(|| panic!("not an error body: {body}"))
+}
+
+// The per-field entries of a validation error: `error.details`, or the problem `errors`
+pub fn validation_errors(body: &serde_json::Value) -> &[serde_json::Value] {
+    #[cfg(not(feature = "problem-json"))]
+    let errors = body["error"]["details"].as_array();
+    #[cfg(feature = "problem-json")]
+    let errors = body["errors"].as_array();
+    errors.unwrap_or_else(|| panic!("not a validation error body: {body}"))
+}
+
+// A fresh path under the system temp dir; nothing is created there
+pub fn temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!(
+        "openapi-integration-{name}-{}",
+        uuid::Uuid::new_v4()
+    ))
+}
+
+// The `id` of the seeded employee with this employee_id
+pub async fn seeded_id(app: &Router, employee_id: &str) -> uuid::Uuid {
+    let page: serde_json::Value = body_json(send(app, get("/v1/employees")).await).await;
+    let id = page["data"]
+        .as_array()
+        .and_then(|data| data.iter().find(|e| e["employee_id"] == employee_id))
+        .and_then(|employee| employee["id"].as_str())
+        .unwrap_or_else(|| panic!("{employee_id} is not seeded"));
+    id.parse().expect("ids are UUIDs")
+}
+
+// The `id` of the seeded department with this code
+pub async fn department_id(app: &Router, code: &str) -> uuid::Uuid {
+    let page: serde_json::Value = body_json(send(app, get("/v1/departments")).await).await;
+    let id = page["data"]
+        .as_array()
+        .and_then(|data| data.iter().find(|d| d["code"] == code))
+        .and_then(|department| department["id"].as_str())
+        .unwrap_or_else(|| panic!("{code} is not seeded"));
+    id.parse().expect("ids are UUIDs")
+}
@@ -0,0 +1,31 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+
+use crate::common::{app, request, send};
+
+fn accepting_gzip(builder: axum::http::request::Builder) -> Request<Body> {
+    builder
+        .header(header::ACCEPT_ENCODING, "gzip")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn large_list_is_gzipped() {
+    let response = send(
+        &app(),
+        accepting_gzip(request(Method::GET, "/v1/employees")),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_ENCODING], "gzip");
+}
+
+#[tokio::test]
+async fn tiny_body_is_sent_as_is() {
+    let response = send(&app(), accepting_gzip(Request::builder().uri("/health"))).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+}
@@ -0,0 +1,87 @@
+use axum::body::Body;
+use axum::http::{header, HeaderValue, Method, Request, StatusCode};
+
+use crate::common::{app, body_bytes, body_json, error_code, get, request, seeded_id, send};
+
+fn rename(uri: &str, if_match: Option<&HeaderValue>, first_name: &str) -> Request<Body> {
+    let mut builder =
+        request(Method::PATCH, uri).header(header::CONTENT_TYPE, "application/merge-patch+json");
+    if let Some(etag) = if_match {
+        builder = builder.header(header::IF_MATCH, etag);
+    }
+    builder
+        .body(Body::from(
+            serde_json::json!({ "first_name": first_name }).to_string(),
+        ))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn matching_if_none_match_is_304() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+
+    let fresh = send(&app, get(&uri)).await;
+    assert_eq!(fresh.status(), StatusCode::OK);
+    let etag = fresh.headers()[header::ETAG].clone();
+    assert!(etag.to_str().unwrap().starts_with("W/\""), "{etag:?}");
+
+    let conditional = request(Method::GET, &uri)
+        .header(header::IF_NONE_MATCH, etag.clone())
+        .body(Body::empty())
+        .unwrap();
+    let response = send(&app, conditional).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers()[header::ETAG], etag);
+    assert!(body_bytes(response).await.is_empty());
+}
+
+#[tokio::test]
+async fn stale_if_match_is_412() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let etag = send(&app, get(&uri)).await.headers()[header::ETAG].clone();
+
+    let first = send(&app, rename(&uri, Some(&etag), "Johnny")).await;
+    assert_eq!(first.status(), StatusCode::OK);
+    let updated: serde_json::Value = body_json(first).await;
+    assert_eq!(updated["version"], 2);
+
+    // The second writer still holds the version-1 ETag
+    let second = send(&app, rename(&uri, Some(&etag), "Jon")).await;
+    assert_eq!(second.status(), StatusCode::PRECONDITION_FAILED);
+    let body: serde_json::Value = body_json(second).await;
+    assert_eq!(error_code(&body), "precondition_failed");
+
+    let unconditional = send(&app, rename(&uri, None, "Jon")).await;
+    assert_eq!(unconditional.status(), StatusCode::PRECONDITION_REQUIRED);
+
+    let current: serde_json::Value = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(current["first_name"], "Johnny");
+}
+
+#[tokio::test]
+async fn head_has_the_get_headers_and_no_body() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let head = |uri: &str| request(Method::HEAD, uri).body(Body::empty()).unwrap();
+
+    let full = send(&app, get(&uri)).await;
+    let response = send(&app, head(&uri)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::ETAG],
+        full.headers()[header::ETAG]
+    );
+    assert!(body_bytes(response).await.is_empty());
+
+    let missing = send(
+        &app,
+        head("/v1/employees/123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await;
+    assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    assert!(body_bytes(missing).await.is_empty());
+}
@@ -0,0 +1,57 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request};
+use openapi_integration::routes::create_app;
+
+use crate::common::{app, config_with, send};
+
+const ORIGIN: &str = "https://spa.company.com";
+
+fn preflight(origin: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/v1/employees")
+        .header(header::ORIGIN, origin)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .header(
+            header::ACCESS_CONTROL_REQUEST_HEADERS,
+            "authorization,content-type",
+        )
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn preflight_from_an_allowed_origin() {
+    let app = create_app(&config_with(&[("CORS_ALLOWED_ORIGINS", ORIGIN)]));
+
+    let response = send(&app, preflight(ORIGIN)).await;
+
+    assert!(response.status().is_success());
+    let headers = response.headers();
+    assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], ORIGIN);
+    let methods = headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+        .to_str()
+        .unwrap();
+    assert!(
+        methods.contains("POST") && methods.contains("DELETE"),
+        "{methods}"
+    );
+    let allowed = headers[header::ACCESS_CONTROL_ALLOW_HEADERS]
+        .to_str()
+        .unwrap();
+    assert!(
+        allowed.contains("authorization") && allowed.contains("content-type"),
+        "{allowed}"
+    );
+    assert_eq!(headers[header::ACCESS_CONTROL_MAX_AGE], "600");
+}
+
+#[tokio::test]
+async fn no_origin_is_allowed_by_default() {
+    let response = send(&app(), preflight(ORIGIN)).await;
+
+    assert!(response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        .is_none());
+}
@@ -0,0 +1,414 @@
+use axum::body::Body;
+use axum::http::{header, Method, StatusCode};
+use openapi_integration::models::common::{PaginatedResponse, PaginationInfo};
+use openapi_integration::models::employees::Employee;
+use openapi_integration::routes::create_app;
+
+use crate::common::{
+    app, body_json, config_with, error_code, get, json_request, new_employee, request, seeded_id,
+    send, validation_errors,
+};
+
+#[tokio::test]
+async fn list_returns_the_seeded_employees() {
+    let app = app();
+
+    let response = send(&app, get("/v1/employees")).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let page: PaginatedResponse<Employee> = body_json(response).await;
+    assert_eq!(page.data.len(), 5);
+    let PaginationInfo::Offset(pagination) = page.pagination else {
+        panic!("page-number request returned cursor pagination");
+    };
+    assert_eq!((pagination.page, pagination.total), (1, 5));
+}
+
+#[tokio::test]
+async fn create_returns_201_and_the_employee_can_be_fetched() {
+    let app = app();
+
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: Employee = body_json(response).await;
+    assert_eq!(created.employee_id, "TST001");
+    assert_eq!(created.version, 1);
+
+    let response = send(&app, get(&format!("/v1/employees/{}", created.id))).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let fetched: Employee = body_json(response).await;
+    assert_eq!(fetched.id, created.id);
+    assert_eq!(fetched.email, "test.employee@company.com");
+}
+
+#[tokio::test]
+async fn unknown_id_is_404() {
+    let response = send(
+        &app(),
+        get("/v1/employees/123e4567-e89b-12d3-a456-426614174000"),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "not_found");
+}
+
+#[tokio::test]
+async fn put_checks_the_target_before_the_body_references() {
+    let app = app();
+    let mut body = new_employee("TST001", "test.employee@company.com");
+    body["department_id"] = uuid::Uuid::new_v4().to_string().into();
+
+    let missing = "/v1/employees/123e4567-e89b-12d3-a456-426614174000";
+    let response = send(&app, json_request(Method::PUT, missing, &body)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let error: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&error), "not_found");
+
+    // No If-Match, so the precondition fails before the unknown department is looked at
+    let existing = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let response = send(&app, json_request(Method::PUT, &existing, &body)).await;
+    assert_eq!(response.status(), StatusCode::PRECONDITION_REQUIRED);
+}
+
+#[tokio::test]
+async fn duplicate_employee_id_is_409() {
+    let app = app();
+    let first = new_employee("TST001", "test.employee@company.com");
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &first)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let again = new_employee("TST001", "another.employee@company.com");
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &again)).await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "conflict");
+}
+
+#[tokio::test]
+async fn email_collisions_ignore_case() {
+    let app = app();
+    let clash = new_employee("TST001", "John.Doe@Company.COM");
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &clash)).await;
+
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "conflict");
+
+    let distinct = new_employee("TST002", "john.doe2@company.com");
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &distinct)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+}
+
+#[tokio::test]
+async fn expand_embeds_the_department() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+
+    let plain: serde_json::Value = body_json(send(&app, get(&uri)).await).await;
+    assert!(plain.get("department").is_none());
+    assert!(plain["department_id"].is_string());
+
+    let expanded: serde_json::Value =
+        body_json(send(&app, get(&format!("{uri}?expand=department"))).await).await;
+    assert_eq!(expanded["department"]["code"], "ENG");
+    assert_eq!(expanded["department"]["id"], plain["department_id"]);
+
+    let page: serde_json::Value =
+        body_json(send(&app, get("/v1/employees?expand=department")).await).await;
+    let data = page["data"].as_array().unwrap();
+    assert!(data
+        .iter()
+        .all(|e| e["department"]["id"] == e["department_id"]));
+
+    let response = send(&app, get(&format!("{uri}?expand=manager"))).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn terminate_with_a_malformed_body_changes_nothing() {
+    let app = app();
+    let id = seeded_id(&app, "EMP003").await;
+    let terminate = format!("/v1/employees/{id}/terminate");
+    let with_body = |content_type: &str, body: &str| {
+        request(Method::POST, &terminate)
+            .header(header::CONTENT_TYPE, content_type)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    let cases = [
+        (with_body("application/json", r#"{"reason": 42}"#), 422),
+        (with_body("application/json", r#"{"reason":"#), 400),
+        (with_body("text/plain", r#"{"reason": "Relocated"}"#), 415),
+    ];
+    for (request, status) in cases {
+        let response = send(&app, request).await;
+        assert_eq!(response.status().as_u16(), status);
+        let body: serde_json::Value = body_json(response).await;
+        assert!(!error_code(&body).is_empty());
+    }
+
+    let employee: Employee = body_json(send(&app, get(&format!("/v1/employees/{id}"))).await).await;
+    assert_eq!(employee.employment_status.as_str(), "active");
+}
+
+#[tokio::test]
+async fn status_transitions_reject_repeats() {
+    let app = app();
+    let id = seeded_id(&app, "EMP003").await;
+    let terminate = format!("/v1/employees/{id}/terminate");
+    let reactivate = format!("/v1/employees/{id}/reactivate");
+    let reason = serde_json::json!({ "reason": "Relocated" });
+
+    let response = send(&app, json_request(Method::POST, &terminate, &reason)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let terminated: serde_json::Value = body_json(response).await;
+    assert_eq!(terminated["employment_status"], "terminated");
+
+    let response = send(&app, json_request(Method::POST, &terminate, &reason)).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "conflict");
+
+    let reactivated = || {
+        request(Method::POST, &reactivate)
+            .body(Body::empty())
+            .unwrap()
+    };
+    assert_eq!(send(&app, reactivated()).await.status(), StatusCode::OK);
+    assert_eq!(
+        send(&app, reactivated()).await.status(),
+        StatusCode::CONFLICT
+    );
+}
+
+#[tokio::test]
+async fn soft_delete_terminates_once() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP004").await);
+    let soft_delete = || {
+        request(Method::DELETE, &format!("{uri}?soft=true"))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    assert_eq!(
+        send(&app, soft_delete()).await.status(),
+        StatusCode::NO_CONTENT
+    );
+    let terminated: Employee = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(terminated.employment_status.as_str(), "terminated");
+
+    let response = send(&app, soft_delete()).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "conflict");
+    let after: Employee = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(after.version, terminated.version);
+}
+
+#[tokio::test]
+async fn fields_projects_the_requested_properties() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+
+    let employee: serde_json::Value =
+        body_json(send(&app, get(&format!("{uri}?fields=email,first_name"))).await).await;
+    let mut keys: Vec<&str> = employee
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["email", "first_name", "id"]);
+
+    let page: serde_json::Value =
+        body_json(send(&app, get("/v1/employees?fields=last_name")).await).await;
+    let data = page["data"].as_array().unwrap();
+    assert!(data.iter().all(|e| e.as_object().unwrap().len() == 2));
+
+    let response = send(&app, get(&format!("{uri}?fields=email,nickname"))).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = body_json(response).await;
+    assert!(body.to_string().contains("nickname"));
+}
+
+#[tokio::test]
+async fn hypermedia_links_are_opt_in() {
+    let plain = app();
+    let id = seeded_id(&plain, "EMP001").await;
+    let uri = format!("/v1/employees/{id}");
+    let employee: serde_json::Value = body_json(send(&plain, get(&uri)).await).await;
+    assert!(employee.get("_links").is_none());
+
+    let linked = create_app(&config_with(&[("HYPERMEDIA_LINKS", "true")]));
+    let id = seeded_id(&linked, "EMP001").await;
+    let uri = format!("/v1/employees/{id}");
+    let employee: serde_json::Value = body_json(send(&linked, get(&uri)).await).await;
+    let links = &employee["_links"];
+    assert_eq!(links["self"]["href"], uri);
+    assert_eq!(links["terminate"]["href"], format!("{uri}/terminate"));
+    assert_eq!(links["photo"]["href"], format!("{uri}/photo"));
+    assert_eq!(
+        links["department"]["href"],
+        format!(
+            "/v1/departments/{}",
+            employee["department_id"].as_str().unwrap()
+        )
+    );
+}
+
+#[tokio::test]
+async fn reports_lists_direct_reports_and_cycles_are_rejected() {
+    let app = app();
+    let lead = seeded_id(&app, "EMP002").await;
+    let report = seeded_id(&app, "EMP001").await;
+
+    let page: PaginatedResponse<Employee> =
+        body_json(send(&app, get(&format!("/v1/employees/{lead}/reports"))).await).await;
+    let reports: Vec<&str> = page.data.iter().map(|e| e.employee_id.as_str()).collect();
+    assert_eq!(reports, ["EMP001", "EMP005"]);
+
+    // EMP001 reports to EMP002, so EMP002 cannot report to EMP001
+    let response = send(
+        &app,
+        request(Method::PATCH, &format!("/v1/employees/{lead}"))
+            .header(header::CONTENT_TYPE, "application/merge-patch+json")
+            .header(header::IF_MATCH, "*")
+            .body(Body::from(
+                serde_json::json!({ "manager_id": report }).to_string(),
+            ))
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(validation_errors(&body)[0]["code"], "reporting_cycle");
+}
+
+#[tokio::test]
+async fn salary_round_trips_exactly() {
+    let app = app();
+    let mut body = new_employee("TST001", "test.employee@company.com");
+    body["salary"] = "75000.50".into();
+    body["currency"] = "EUR".into();
+
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &body)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: serde_json::Value = body_json(response).await;
+    assert_eq!(created["salary"], "75000.50");
+
+    let uri = format!("/v1/employees/{}", created["id"].as_str().unwrap());
+    let fetched: Employee = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(
+        fetched.salary,
+        Some(rust_decimal::Decimal::new(7_500_050, 2))
+    );
+    assert_eq!(fetched.salary.unwrap().to_string(), "75000.50");
+    assert_eq!(fetched.currency, "EUR");
+}
+
+#[tokio::test]
+async fn upsert_creates_then_replaces() {
+    let app = app();
+    let uri = "/v1/employees/by-employee-id/TST001";
+    let mut body = new_employee("TST001", "test.employee@company.com");
+
+    let response = send(&app, json_request(Method::PUT, uri, &body)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert!(response.headers().contains_key(header::LOCATION));
+    let created: Employee = body_json(response).await;
+
+    body["position"] = "Staff Engineer".into();
+    let response = send(&app, json_request(Method::PUT, uri, &body)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(!response.headers().contains_key(header::LOCATION));
+    let replaced: Employee = body_json(response).await;
+    assert_eq!(replaced.id, created.id);
+    assert_eq!(replaced.position.as_deref(), Some("Staff Engineer"));
+    assert_eq!(replaced.version, created.version + 1);
+
+    let page: PaginatedResponse<Employee> = body_json(send(&app, get("/v1/employees")).await).await;
+    assert_eq!(page.pagination.total(), 6);
+
+    let response = send(
+        &app,
+        json_request(Method::PUT, "/v1/employees/by-employee-id/tst001", &body),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn location_header_resolves_to_the_created_employee() {
+    let base = "https://api.example.com";
+    let app = create_app(&config_with(&[("PUBLIC_BASE_URL", base)]));
+
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let location = response.headers()[header::LOCATION]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let created: Employee = body_json(response).await;
+    assert_eq!(location, format!("{base}/v1/employees/{}", created.id));
+    let path = location.strip_prefix(base).unwrap();
+    let fetched: Employee = body_json(send(&app, get(path)).await).await;
+    assert_eq!(fetched.id, created.id);
+}
+
+#[tokio::test]
+async fn dry_run_checks_conflicts_without_inserting() {
+    let app = app();
+    let total = || async {
+        let page: PaginatedResponse<Employee> =
+            body_json(send(&app, get("/v1/employees")).await).await;
+        page.pagination.total()
+    };
+
+    let clash = new_employee("TST001", "john.doe@company.com");
+    let response = send(
+        &app,
+        json_request(Method::POST, "/v1/employees?dry_run=true", &clash),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "conflict");
+
+    let valid = new_employee("TST002", "test.employee@company.com");
+    let response = send(
+        &app,
+        json_request(Method::POST, "/v1/employees?dry_run=true", &valid),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(body["dry_run"], true);
+    assert_eq!(body["employee_id"], "TST002");
+
+    assert_eq!(total().await, 5);
+}
@@ -0,0 +1,141 @@
+use axum::body::Body;
+use axum::http::{header, Method, StatusCode};
+use axum::routing::get as get_route;
+use axum::Router;
+use openapi_integration::middleware::errors::panic_as_api_error;
+use tower_http::catch_panic::CatchPanicLayer;
+
+use crate::common::{app, body_json, error_code, get, request, seeded_id, send};
+
+const MISSING: &str = "/v1/employees/123e4567-e89b-12d3-a456-426614174000";
+
+#[cfg(not(feature = "problem-json"))]
+#[tokio::test]
+async fn errors_are_api_error_documents() {
+    let response = send(&app(), get(MISSING)).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(body["error"]["code"], "not_found");
+    assert!(body["error"]["message"].is_string());
+}
+
+#[cfg(feature = "problem-json")]
+#[tokio::test]
+async fn errors_are_problem_documents() {
+    let response = send(&app(), get(MISSING)).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/problem+json"
+    );
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(body["type"], "https://api.company.com/problems/not_found");
+    assert_eq!(body["title"], "Not Found");
+    assert_eq!(body["status"], 404);
+    assert!(body["detail"].as_str().unwrap().contains("not found"));
+    assert!(body["instance"].as_str().unwrap().starts_with("urn:uuid:"));
+    assert!(body.get("error").is_none());
+}
+
+#[tokio::test]
+async fn unknown_route_is_a_json_404() {
+    let response = send(&app(), get("/nope")).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap();
+    assert!(content_type.contains("json"), "{content_type}");
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "not_found");
+}
+
+#[tokio::test]
+async fn unsupported_method_is_a_json_405() {
+    let response = send(
+        &app(),
+        request(Method::DELETE, "/v1/employees")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    let allow = response.headers()[header::ALLOW].to_str().unwrap();
+    assert!(allow.contains("GET") && allow.contains("POST"), "{allow}");
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "method_not_allowed");
+}
+
+async fn panics() -> StatusCode {
+    panic!("secret connection string in the panic message")
+}
+
+#[tokio::test]
+async fn panicking_handler_is_a_generic_500() {
+    let app = Router::new()
+        .route("/panic", get_route(panics))
+        .layer(CatchPanicLayer::custom(panic_as_api_error));
+
+    let response = send(&app, get("/panic")).await;
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap();
+    assert!(content_type.contains("json"), "{content_type}");
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "internal_error");
+    assert!(!body.to_string().contains("secret"), "{body}");
+}
+
+#[tokio::test]
+async fn form_encoded_create_is_415() {
+    let form = request(Method::POST, "/v1/employees")
+        .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .body(Body::from(
+            "employee_id=TST001&first_name=Test&last_name=Employee&email=test%40company.com",
+        ))
+        .unwrap();
+
+    let response = send(&app(), form).await;
+
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "unsupported_media_type");
+}
+
+#[tokio::test]
+async fn extractor_rejections_are_api_errors() {
+    let app = app();
+    let id = seeded_id(&app, "EMP001").await;
+    let raw_json = |body: &str| {
+        request(Method::POST, "/v1/employees")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+    let delete = request(Method::DELETE, &format!("/v1/employees/{id}?soft=maybe"))
+        .body(Body::empty())
+        .unwrap();
+    let cases = [
+        (raw_json("{\"employee_id\":"), StatusCode::BAD_REQUEST),
+        (raw_json("{}"), StatusCode::UNPROCESSABLE_ENTITY),
+        (get("/v1/employees?order=sideways"), StatusCode::BAD_REQUEST),
+        (
+            get("/v1/employees?hired_after=notadate"),
+            StatusCode::BAD_REQUEST,
+        ),
+        (get("/v1/employees/not-a-uuid"), StatusCode::BAD_REQUEST),
+        (delete, StatusCode::BAD_REQUEST),
+    ];
+
+    for (request, status) in cases {
+        let uri = request.uri().to_string();
+        let response = send(&app, request).await;
+        assert_eq!(response.status(), status, "{uri}");
+        let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap();
+        assert!(content_type.contains("json"), "{uri}: {content_type}");
+        let body: serde_json::Value = body_json(response).await;
+        assert!(!error_code(&body).is_empty(), "{uri}");
+    }
+}
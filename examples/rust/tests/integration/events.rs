@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use axum::http::{header, Method, StatusCode};
+use futures::StreamExt;
+
+use crate::common::{app, get, json_request, new_employee, send};
+
+#[tokio::test]
+async fn subscriber_receives_the_create_event() {
+    let app = app();
+    let response = send(&app, get("/v1/employees/events")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/event-stream"
+    );
+    let mut stream = response.into_body().into_data_stream();
+
+    let created = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+
+    // Frames are `event: <action>\ndata: <json>\n\n`; keep-alive comments may come between them
+    let mut received = String::new();
+    let frame = tokio::time::timeout(Duration::from_secs(5), async {
+        while let Some(chunk) = stream.next().await {
+            received.push_str(std::str::from_utf8(&chunk.unwrap()).unwrap());
+            if let Some(end) = received.find("\n\n") {
+                return received[..end].to_string();
+            }
+        }
+        panic!("stream ended before an event arrived");
+    })
+    .await
+    .expect("an event arrives");
+
+    let mut lines = frame.lines();
+    assert_eq!(lines.next(), Some("event: created"));
+    let data: serde_json::Value =
+        serde_json::from_str(lines.next().unwrap().strip_prefix("data: ").unwrap()).unwrap();
+    assert_eq!(data["action"], "created");
+    assert_eq!(data["employee"]["employee_id"], "TST001");
+    assert_eq!(data["employee_id"], data["employee"]["id"]);
+}
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use axum::body::Body;
+use axum::http::{header, Method, StatusCode};
+
+use crate::common::{app, body_bytes, body_json, get, request, send};
+
+#[tokio::test]
+async fn csv_export_has_a_header_and_a_row_per_employee() {
+    let response = send(&app(), get("/v1/employees/export.csv")).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "text/csv; charset=utf-8"
+    );
+    assert_eq!(
+        response.headers()[header::CONTENT_DISPOSITION],
+        r#"attachment; filename="employees.csv""#
+    );
+    let body = body_bytes(response).await;
+    let mut reader = csv::Reader::from_reader(body.as_slice());
+    let headers = reader.headers().unwrap().clone();
+    assert_eq!(&headers[1], "employee_id");
+    assert_eq!(&headers[12], "hire_date");
+
+    let rows: Vec<csv::StringRecord> = reader.records().map(Result::unwrap).collect();
+    let ids: Vec<&str> = rows.iter().map(|row| &row[1]).collect();
+    assert_eq!(ids, ["EMP001", "EMP002", "EMP003", "EMP004", "EMP005"]);
+    assert_eq!(&rows[0][12], "2024-01-15");
+}
+
+#[tokio::test]
+async fn export_job_completes_with_a_downloadable_file() {
+    let app = app();
+
+    let response = send(
+        &app,
+        request(Method::POST, "/v1/employees/export")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+    let location = response.headers()[header::LOCATION]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let queued: serde_json::Value = body_json(response).await;
+    assert!(
+        queued["status"] == "pending" || queued["status"] == "running",
+        "{queued}"
+    );
+
+    let job = tokio::time::timeout(Duration::from_secs(5), async {
+        loop {
+            let job: serde_json::Value = body_json(send(&app, get(&location)).await).await;
+            if job["status"] == "completed" {
+                return job;
+            }
+            assert_ne!(job["status"], "failed", "{job}");
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    })
+    .await
+    .expect("the job completes");
+
+    assert_eq!(job["id"], queued["id"]);
+    assert!(job["finished_at"].is_string());
+    let response = send(&app, get(job["download_url"].as_str().unwrap())).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = body_bytes(response).await;
+    let rows = csv::Reader::from_reader(body.as_slice()).records().count();
+    assert_eq!(rows, 5);
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use openapi_integration::grpc::pb::employee_service_client::EmployeeServiceClient;
+use openapi_integration::grpc::pb::GetEmployeeRequest;
+use openapi_integration::models::employees::Employee;
+use openapi_integration::routes::create_app_with_grpc;
+use rust_decimal::Decimal;
+use tokio::net::TcpListener;
+
+use crate::common::{admin_token, body_json, config_with, get, seeded_id, send};
+
+#[tokio::test]
+async fn get_employee_matches_the_rest_representation() {
+    let (app, service) = create_app_with_grpc(&config_with(&[]));
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let incoming = futures::stream::unfold(listener, |listener| async move {
+        let connection = listener.accept().await.map(|(stream, _)| stream);
+        Some((connection, listener))
+    });
+    tokio::spawn(
+        tonic::transport::Server::builder()
+            .add_service(service)
+            .serve_with_incoming(incoming),
+    );
+
+    let id = seeded_id(&app, "EMP001").await;
+    let rest: Employee = body_json(send(&app, get(&format!("/v1/employees/{id}"))).await).await;
+
+    let mut client = EmployeeServiceClient::connect(format!("http://{address}"))
+        .await
+        .unwrap();
+    let mut request = tonic::Request::new(GetEmployeeRequest { id: id.to_string() });
+    request.metadata_mut().insert(
+        "authorization",
+        format!("Bearer {}", admin_token()).parse().unwrap(),
+    );
+    let grpc = client.get_employee(request).await.unwrap().into_inner();
+
+    assert_eq!(grpc.id, rest.id.to_string());
+    assert_eq!(grpc.employee_id, rest.employee_id);
+    assert_eq!(grpc.first_name, rest.first_name);
+    assert_eq!(grpc.last_name, rest.last_name);
+    assert_eq!(grpc.email, rest.email);
+    assert_eq!(
+        grpc.department_id,
+        rest.department_id.map(|id| id.to_string())
+    );
+    assert_eq!(grpc.manager_id, rest.manager_id.map(|id| id.to_string()));
+    assert_eq!(grpc.position, rest.position);
+    let salary = grpc
+        .salary
+        .map(|salary| Decimal::from_str(&salary).unwrap());
+    assert_eq!(salary, rest.salary);
+    assert_eq!(grpc.currency, rest.currency);
+    assert_eq!(grpc.employment_status, rest.employment_status.as_str());
+    assert_eq!(grpc.hire_date, rest.hire_date.to_string());
+    assert_eq!(grpc.version, rest.version);
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use chrono::{Duration, Utc};
+use openapi_integration::clock::FixedClock;
+use openapi_integration::models::common::PaginatedResponse;
+use openapi_integration::models::employees::Employee;
+use openapi_integration::routes::create_app_with_clock;
+
+use crate::common::{
+    app, body_bytes, body_json, config_with, error_code, get, new_employee, request, send,
+};
+
+fn keyed_create(key: &str, body: &serde_json::Value) -> Request<Body> {
+    request(Method::POST, "/v1/employees")
+        .header(header::CONTENT_TYPE, "application/json")
+        .header("Idempotency-Key", key)
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+async fn employee_count(app: &axum::Router) -> u64 {
+    let page: PaginatedResponse<Employee> = body_json(send(app, get("/v1/employees")).await).await;
+    page.pagination.total()
+}
+
+#[tokio::test]
+async fn repeated_key_creates_one_employee() {
+    let app = app();
+    let before = employee_count(&app).await;
+    let body = new_employee("TST001", "test.employee@company.com");
+
+    let first = send(&app, keyed_create("create-tst001", &body)).await;
+    assert_eq!(first.status(), StatusCode::CREATED);
+    let first_location = first.headers()[header::LOCATION].clone();
+    let first_body = body_bytes(first).await;
+
+    let replay = send(&app, keyed_create("create-tst001", &body)).await;
+    assert_eq!(replay.status(), StatusCode::CREATED);
+    assert_eq!(replay.headers()["idempotent-replayed"], "true");
+    assert_eq!(replay.headers()[header::LOCATION], first_location);
+    assert_eq!(body_bytes(replay).await, first_body);
+
+    assert_eq!(employee_count(&app).await, before + 1);
+}
+
+#[tokio::test]
+async fn key_is_forgotten_once_the_ttl_passes() {
+    let clock = Arc::new(FixedClock::new(Utc::now()));
+    let app = create_app_with_clock(
+        &config_with(&[("IDEMPOTENCY_TTL_SECS", "60")]),
+        clock.clone(),
+    );
+    let first = new_employee("TST001", "test.employee@company.com");
+    let second = new_employee("TST002", "second.employee@company.com");
+    let created = send(&app, keyed_create("create-once", &first)).await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+
+    // A different body under a live key is refused
+    clock.advance(Duration::seconds(59));
+    let reused = send(&app, keyed_create("create-once", &second)).await;
+    assert_eq!(reused.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(reused).await;
+    assert_eq!(error_code(&body), "validation_error");
+
+    clock.advance(Duration::seconds(1));
+    let fresh = send(&app, keyed_create("create-once", &second)).await;
+    assert_eq!(fresh.status(), StatusCode::CREATED);
+    assert!(fresh.headers().get("idempotent-replayed").is_none());
+}
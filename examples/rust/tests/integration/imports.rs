@@ -0,0 +1,141 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+
+use crate::common::{
+    app, body_json, error_code, get, json_request, new_employee, request, send, validation_errors,
+};
+
+// Three rows; the middle one reuses a seeded employee's email
+fn batch_with_a_conflict() -> serde_json::Value {
+    serde_json::json!([
+        new_employee("TST001", "first.bulk@company.com"),
+        new_employee("TST002", "john.doe@company.com"),
+        new_employee("TST003", "third.bulk@company.com"),
+    ])
+}
+
+async fn employee_count(app: &axum::Router) -> u64 {
+    let page: serde_json::Value = body_json(send(app, get("/v1/employees")).await).await;
+    page["pagination"]["total"].as_u64().unwrap()
+}
+
+fn csv_import(uri: &str, content_type: &str, body: impl Into<Body>) -> Request<Body> {
+    request(Method::POST, uri)
+        .header(header::CONTENT_TYPE, content_type)
+        .body(body.into())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn reports_good_and_bad_rows_separately() {
+    let csv = "employee_id,first_name,last_name,email,hire_date\n\
+               IMP001,Ada,Lovelace,ada@company.com,2024-02-01\n\
+               bad,Bad,Row,not-an-email,2024-02-01\n\
+               IMP002,Alan,Turing,alan@company.com,2024-03-01\n";
+
+    let response = send(&app(), csv_import("/v1/employees/import", "text/csv", csv)).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: serde_json::Value = body_json(response).await;
+    let succeeded: Vec<&str> = report["succeeded"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row["employee_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(succeeded, ["IMP001", "IMP002"]);
+    let failed = report["failed"].as_array().unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0]["line"], 3);
+    let fields: Vec<&str> = failed[0]["errors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|error| error["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields.len(), 2, "{fields:?}");
+    assert!(fields.contains(&"employee_id") && fields.contains(&"email"));
+}
+
+#[tokio::test]
+async fn checks_the_content_type_before_reading_the_body() {
+    let response = send(
+        &app(),
+        csv_import("/v1/employees/import", "application/json", vec![0xFF, 0xFE]),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+}
+
+#[tokio::test]
+async fn rejects_a_body_that_is_not_utf8() {
+    let response = send(
+        &app(),
+        csv_import(
+            "/v1/employees/import",
+            "text/csv",
+            b"employee_id\n\xFF\n".to_vec(),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "bad_request");
+}
+
+#[tokio::test]
+async fn atomic_bulk_create_with_a_conflict_creates_nothing() {
+    let app = app();
+
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees/bulk?mode=atomic",
+            &batch_with_a_conflict(),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    let fields: Vec<&str> = validation_errors(&body)
+        .iter()
+        .map(|error| error["field"].as_str().unwrap())
+        .collect();
+    assert_eq!(fields, ["[1].email"]);
+    assert_eq!(employee_count(&app).await, 5);
+}
+
+#[tokio::test]
+async fn partial_bulk_create_keeps_the_valid_rows() {
+    let app = app();
+
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees/bulk?mode=partial",
+            &batch_with_a_conflict(),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let report: serde_json::Value = body_json(response).await;
+    let created: Vec<&str> = report["created"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|employee| employee["employee_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(created, ["TST001", "TST003"]);
+    let errors = report["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["index"], 1);
+    assert_eq!(errors[0]["errors"][0]["field"], "email");
+    assert_eq!(errors[0]["errors"][0]["code"], "conflict");
+    assert_eq!(employee_count(&app).await, 7);
+}
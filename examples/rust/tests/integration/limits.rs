@@ -0,0 +1,130 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use axum::http::{header, Method, StatusCode};
+use openapi_integration::models::employees::Employee;
+use openapi_integration::repository::{EmployeeRepository, RepoError};
+use openapi_integration::routes::{create_app, create_app_with_repository};
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+use crate::common::{body_json, config_with, error_code, error_message, get, json_request, send};
+
+#[tokio::test]
+async fn rate_limit_trips_after_the_allowance() {
+    let app = create_app(&config_with(&[
+        ("RATE_LIMIT_REQUESTS", "3"),
+        ("RATE_LIMIT_WINDOW_SECS", "3600"),
+    ]));
+
+    for _ in 0..3 {
+        assert_eq!(
+            send(&app, get("/v1/employees")).await.status(),
+            StatusCode::OK
+        );
+    }
+    let response = send(&app, get("/v1/employees")).await;
+
+    assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = response.headers()[header::RETRY_AFTER]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!(retry_after > 0);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "rate_limited");
+}
+
+#[tokio::test]
+async fn oversized_body_is_413_with_the_error_body() {
+    let app = create_app(&config_with(&[("MAX_BODY_BYTES", "256")]));
+    let body = serde_json::json!({ "first_name": "x".repeat(1024) });
+
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &body)).await;
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let content_type = response.headers()[header::CONTENT_TYPE].to_str().unwrap();
+    assert!(content_type.contains("json"), "{content_type}");
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "payload_too_large");
+    assert!(!error_message(&body).is_empty());
+}
+
+// Lists wait until `release` is notified, announcing on `entered` that one has started;
+// nothing else is reached
+#[derive(Default)]
+struct BlockingRepository {
+    entered: Notify,
+    release: Notify,
+}
+
+#[async_trait]
+impl EmployeeRepository for BlockingRepository {
+    async fn list(&self) -> Result<Vec<Employee>, RepoError> {
+        self.entered.notify_one();
+        self.release.notified().await;
+        Ok(Vec::new())
+    }
+
+    async fn get(&self, _id: Uuid) -> Result<Option<Employee>, RepoError> {
+        Ok(None)
+    }
+
+    async fn create(&self, employee: Employee) -> Result<Employee, RepoError> {
+        Ok(employee)
+    }
+
+    async fn update(&self, employee: Employee) -> Result<Employee, RepoError> {
+        Err(RepoError::NotFound(employee.id))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        Err(RepoError::NotFound(id))
+    }
+}
+
+#[tokio::test]
+async fn slow_handler_is_504_with_the_error_body() {
+    let app = create_app_with_repository(
+        &config_with(&[("REQUEST_TIMEOUT_SECS", "1")]),
+        Arc::new(BlockingRepository::default()),
+    );
+
+    let response = tokio::time::timeout(Duration::from_secs(10), send(&app, get("/v1/employees")))
+        .await
+        .expect("the server answers before the handler finishes");
+
+    assert_eq!(response.status(), StatusCode::GATEWAY_TIMEOUT);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "gateway_timeout");
+}
+
+#[tokio::test]
+async fn requests_over_the_concurrency_limit_are_shed() {
+    let repository = Arc::new(BlockingRepository::default());
+    let app = create_app_with_repository(
+        &config_with(&[("MAX_CONCURRENT_REQUESTS", "1")]),
+        repository.clone(),
+    );
+    let first = tokio::spawn({
+        let app = app.clone();
+        async move { send(&app, get("/v1/employees")).await }
+    });
+    repository.entered.notified().await;
+
+    let response = send(&app, get("/v1/employees")).await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    assert!(response.headers().contains_key(header::RETRY_AFTER));
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "service_unavailable");
+
+    repository.release.notify_one();
+    assert_eq!(first.await.unwrap().status(), StatusCode::OK);
+    repository.release.notify_one();
+    assert_eq!(
+        send(&app, get("/v1/employees")).await.status(),
+        StatusCode::OK
+    );
+}
@@ -0,0 +1,187 @@
+use axum::body::Body;
+use axum::http::{Method, StatusCode};
+use axum::Router;
+use openapi_integration::models::common::PaginatedResponse;
+use openapi_integration::models::employees::Employee;
+
+use crate::common::{app, body_json, get, request, seeded_id, send};
+
+// The employee_ids on the page `uri` returns, in order
+async fn listed(app: &Router, uri: &str) -> Vec<String> {
+    let response = send(app, get(uri)).await;
+    assert_eq!(response.status(), StatusCode::OK, "{uri}");
+    let page: PaginatedResponse<Employee> = body_json(response).await;
+    page.data.into_iter().map(|e| e.employee_id).collect()
+}
+
+#[tokio::test]
+async fn sorts_by_hire_date_descending() {
+    let app = app();
+    assert_eq!(
+        listed(&app, "/v1/employees?sort_by=hire_date&order=desc").await,
+        ["EMP005", "EMP001", "EMP002", "EMP003", "EMP004"]
+    );
+}
+
+#[tokio::test]
+async fn filters_by_hire_date_range() {
+    let app = app();
+    assert_eq!(
+        listed(&app, "/v1/employees?hired_after=2024-01-01").await,
+        ["EMP001", "EMP005"]
+    );
+    assert_eq!(
+        listed(&app, "/v1/employees?hired_before=2022-12-31").await,
+        ["EMP004", "EMP003"]
+    );
+    assert_eq!(
+        listed(
+            &app,
+            "/v1/employees?hired_after=2022-01-01&hired_before=2023-12-31"
+        )
+        .await,
+        ["EMP002", "EMP003"]
+    );
+    // Both bounds are inclusive
+    assert_eq!(
+        listed(
+            &app,
+            "/v1/employees?hired_after=2024-01-15&hired_before=2024-01-15"
+        )
+        .await,
+        ["EMP001"]
+    );
+
+    let inverted = send(
+        &app,
+        get("/v1/employees?hired_after=2024-01-01&hired_before=2023-01-01"),
+    )
+    .await;
+    assert_eq!(inverted.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn combines_hire_date_comparisons() {
+    let app = app();
+    assert_eq!(
+        listed(
+            &app,
+            "/v1/employees?hire_date%5Bgte%5D=2022-03-10&hire_date%5Blte%5D=2023-06-01"
+        )
+        .await,
+        ["EMP002", "EMP003"]
+    );
+    // gt and lt exclude the bounds themselves
+    assert!(listed(
+        &app,
+        "/v1/employees?hire_date%5Bgt%5D=2022-03-10&hire_date%5Blt%5D=2023-06-01"
+    )
+    .await
+    .is_empty());
+
+    let unknown = send(&app, get("/v1/employees?hire_date%5Bnear%5D=2023-06-01")).await;
+    assert_eq!(unknown.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn filters_by_status_and_department() {
+    let app = app();
+    let id = seeded_id(&app, "EMP005").await;
+    let terminate = request(Method::POST, &format!("/v1/employees/{id}/terminate"))
+        .body(Body::empty())
+        .unwrap();
+    assert_eq!(send(&app, terminate).await.status(), StatusCode::OK);
+
+    assert_eq!(
+        listed(&app, "/v1/employees?status=terminated").await,
+        ["EMP005"]
+    );
+    assert_eq!(
+        listed(&app, "/v1/employees?status=active,terminated")
+            .await
+            .len(),
+        5
+    );
+    // Code or name, ignoring case
+    assert_eq!(
+        listed(&app, "/v1/employees?department=eng").await,
+        ["EMP001", "EMP005", "EMP002"]
+    );
+    assert_eq!(
+        listed(&app, "/v1/employees?department=Human%20Resources").await,
+        ["EMP003"]
+    );
+    assert_eq!(
+        listed(&app, "/v1/employees?department=ENG&status=active").await,
+        ["EMP001", "EMP002"]
+    );
+
+    let unknown = send(&app, get("/v1/employees?status=retired")).await;
+    assert_eq!(unknown.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn empty_status_and_department_filter_nothing() {
+    let app = app();
+
+    for uri in [
+        "/v1/employees?status=",
+        "/v1/employees?status=,",
+        "/v1/employees?department=",
+    ] {
+        assert_eq!(listed(&app, uri).await.len(), 5, "{uri}");
+    }
+}
+
+#[tokio::test]
+async fn search_matches_substrings_ignoring_case() {
+    let app = app();
+
+    assert_eq!(
+        listed(&app, "/v1/employees/search?q=smith%40comp").await,
+        ["EMP002"]
+    );
+    assert_eq!(
+        listed(&app, "/v1/employees/search?q=tAnAkA").await,
+        ["EMP003"]
+    );
+    assert_eq!(
+        listed(&app, "/v1/employees/search?q=emp00&per_page=2&page=2").await,
+        ["EMP005", "EMP002"]
+    );
+
+    let blank = send(&app, get("/v1/employees/search?q=%20")).await;
+    assert_eq!(blank.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn count_equals_the_list_total() {
+    let app = app();
+
+    for filters in [
+        "",
+        "department=ENG",
+        "status=active&hired_after=2023-01-01",
+        "hire_date%5Blt%5D=2024-01-01",
+    ] {
+        let count: serde_json::Value =
+            body_json(send(&app, get(&format!("/v1/employees/count?{filters}"))).await).await;
+        let page: PaginatedResponse<Employee> =
+            body_json(send(&app, get(&format!("/v1/employees?{filters}&per_page=1"))).await).await;
+
+        assert_eq!(count["count"], page.pagination.total(), "{filters}");
+    }
+}
+
+#[tokio::test]
+async fn trailing_slash_is_the_same_resource() {
+    let app = app();
+
+    assert_eq!(
+        listed(&app, "/v1/employees/?sort_by=hire_date").await,
+        listed(&app, "/v1/employees?sort_by=hire_date").await
+    );
+    let id = seeded_id(&app, "EMP001").await;
+    let response = send(&app, get(&format!("/v1/employees/{id}/"))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
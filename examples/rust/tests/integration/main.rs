@@ -0,0 +1,40 @@
+// End-to-end tests: every request goes through the full router from `create_app`, seeded with
+// the demo data, via `tower::ServiceExt::oneshot`. They double as usage examples for the API.
+mod audit;
+mod auth;
+mod clock;
+mod common;
+mod compression;
+mod conditional;
+mod cors;
+mod employees;
+mod errors;
+mod events;
+mod exports;
+#[cfg(feature = "grpc")]
+mod grpc;
+mod idempotency;
+mod imports;
+mod limits;
+mod listing;
+mod maintenance;
+mod metrics;
+mod ndjson;
+mod negotiation;
+mod openapi;
+mod pagination;
+mod patch;
+mod photos;
+#[cfg(feature = "postgres")]
+mod postgres;
+mod repository;
+mod request_id;
+mod retention;
+#[cfg(feature = "s3")]
+mod s3;
+mod seed;
+mod serve;
+mod system;
+mod validation;
+mod versioning;
+mod webhooks;
@@ -0,0 +1,43 @@
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use crate::common::{app, body_json, error_code, get, json_request, new_employee, send};
+
+#[tokio::test]
+async fn maintenance_refuses_writes_but_serves_reads() {
+    let app = app();
+    let toggle = |enabled: bool| {
+        json_request(
+            Method::POST,
+            "/v1/admin/maintenance",
+            &json!({ "enabled": enabled }),
+        )
+    };
+    let create = |employee_id: &str, email: &str| {
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee(employee_id, email),
+        )
+    };
+    assert_eq!(send(&app, toggle(true)).await.status(), StatusCode::OK);
+
+    let response = send(&app, create("TST001", "test.one@company.com")).await;
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "service_unavailable");
+    assert_eq!(
+        send(&app, get("/v1/employees")).await.status(),
+        StatusCode::OK
+    );
+    let health: serde_json::Value = body_json(send(&app, get("/health")).await).await;
+    assert_eq!(health["maintenance"], true);
+
+    assert_eq!(send(&app, toggle(false)).await.status(), StatusCode::OK);
+    assert_eq!(
+        send(&app, create("TST002", "test.two@company.com"))
+            .await
+            .status(),
+        StatusCode::CREATED
+    );
+}
@@ -0,0 +1,54 @@
+use axum::http::StatusCode;
+use axum::routing::get;
+use axum::{middleware, Router};
+use openapi_integration::middleware::errors::panic_as_api_error;
+use openapi_integration::middleware::metrics::{prometheus_handle, track_metrics};
+use tower_http::catch_panic::CatchPanicLayer;
+
+use crate::common::{app, body_bytes, get as get_request, send};
+
+// The value of one sample in the Prometheus text output, 0 if it has not been recorded yet
+fn sample(exposition: &str, series: &str) -> f64 {
+    exposition
+        .lines()
+        .find_map(|line| line.strip_prefix(series))
+        .map_or(0.0, |value| value.trim().parse().expect("numeric sample"))
+}
+
+// The recorder is shared by every test in this binary, so only compare before and after
+#[tokio::test]
+async fn counts_each_request_by_route_and_status() {
+    let app = app();
+    let series = r#"http_requests_total{method="GET",path="/v1/departments/{id}",status="404"}"#;
+    let before = sample(&prometheus_handle().render(), series);
+
+    let response = send(
+        &app,
+        get_request("/v1/departments/00000000-0000-0000-0000-000000000000"),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = send(&app, get_request("/metrics")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let exposition = String::from_utf8(body_bytes(response).await).unwrap();
+    assert!(sample(&exposition, series) >= before + 1.0);
+}
+
+async fn panics() -> StatusCode {
+    panic!("handler failed")
+}
+
+#[tokio::test]
+async fn in_flight_gauge_is_released_when_a_handler_panics() {
+    let app = Router::new()
+        .route("/metrics-panic", get(panics))
+        .route_layer(middleware::from_fn(track_metrics))
+        .layer(CatchPanicLayer::custom(panic_as_api_error));
+
+    let response = send(&app, get_request("/metrics-panic")).await;
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    let series = r#"http_requests_in_flight{method="GET",path="/metrics-panic"}"#;
+    assert_eq!(sample(&prometheus_handle().render(), series), 0.0);
+}
@@ -0,0 +1,34 @@
+use axum::http::{header, StatusCode};
+use openapi_integration::models::employees::Employee;
+
+use crate::common::{app, body_bytes, get, send};
+
+async fn ndjson_lines(uri: &str) -> Vec<Employee> {
+    let response = send(&app(), get(uri)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/x-ndjson"
+    );
+    let body = String::from_utf8(body_bytes(response).await).expect("UTF-8 body");
+    assert!(body.ends_with('\n'), "every line is terminated");
+    body.lines()
+        .map(|line| serde_json::from_str(line).expect("each line is one employee"))
+        .collect()
+}
+
+#[tokio::test]
+async fn streams_one_employee_per_line() {
+    let employees = ndjson_lines("/v1/employees.ndjson").await;
+
+    assert_eq!(employees.len(), 5);
+}
+
+#[tokio::test]
+async fn applies_comparison_filters_like_the_list() {
+    let employees = ndjson_lines("/v1/employees.ndjson?hire_date%5Bgte%5D=2024-01-01").await;
+
+    let mut ids: Vec<&str> = employees.iter().map(|e| e.employee_id.as_str()).collect();
+    ids.sort();
+    assert_eq!(ids, ["EMP001", "EMP005"]);
+}
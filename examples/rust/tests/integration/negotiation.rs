@@ -0,0 +1,50 @@
+use axum::body::Body;
+use axum::http::{header, Method, StatusCode};
+use axum::Router;
+
+use crate::common::{app, body_bytes, body_json, error_code, request, seeded_id, send};
+
+async fn get_as(app: &Router, uri: &str, accept: &str) -> axum::http::Response<Body> {
+    send(
+        app,
+        request(Method::GET, uri)
+            .header(header::ACCEPT, accept)
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn accept_picks_json_or_xml() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+
+    let response = get_as(&app, &uri, "application/json").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    let json: serde_json::Value = body_json(response).await;
+    assert_eq!(json["employee_id"], "EMP001");
+
+    let response = get_as(&app, &uri, "application/json;q=0.5, application/xml").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        response.headers()[header::CONTENT_TYPE],
+        "application/xml; charset=utf-8"
+    );
+    let xml = String::from_utf8(body_bytes(response).await).unwrap();
+    assert!(xml.starts_with("<employee>"), "{xml}");
+    assert!(xml.contains("<employee_id>EMP001</employee_id>"), "{xml}");
+}
+
+#[tokio::test]
+async fn unsupported_accept_is_406() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+
+    let response = get_as(&app, &uri, "text/csv").await;
+
+    assert_eq!(response.status(), StatusCode::NOT_ACCEPTABLE);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "not_acceptable");
+}
@@ -0,0 +1,181 @@
+use std::path::Path;
+
+use axum::http::header;
+use openapi_integration::config::app::PageLimits;
+use openapi_integration::routes::api::{check_openapi, dump_openapi, SpecVersion};
+use serde_json::Value;
+
+use crate::common::{app, body_bytes, body_json, get, send, temp_path};
+
+async fn served_spec() -> Value {
+    body_json(send(&app(), get("/api-docs/openapi.json")).await).await
+}
+
+// Follows a `#/components/schemas/...` reference, or returns the schema when it is inline
+fn resolve<'a>(spec: &'a Value, schema: &'a Value) -> &'a Value {
+    match schema["$ref"].as_str() {
+        Some(reference) => {
+            let name = reference
+                .strip_prefix("#/components/schemas/")
+                .unwrap_or_else(|| panic!("not a schema reference: {reference}"));
+            &spec["components"]["schemas"][name]
+        }
+        None => schema,
+    }
+}
+
+// Any change to the generated spec fails here until the snapshot is regenerated with
+// `UPDATE_SNAPSHOTS=1 cargo test`, so API changes show up in review as a snapshot diff
+#[test]
+#[cfg_attr(
+    feature = "problem-json",
+    ignore = "the snapshot documents the default ApiError responses"
+)]
+fn spec_matches_committed_snapshot() {
+    let snapshot = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/openapi.snapshot.json");
+
+    // The default DEFAULT_PER_PAGE and MAX_PER_PAGE
+    let page_limits = PageLimits::from_lookup(|_| None).unwrap();
+
+    if let Err(error) = check_openapi(&snapshot, SpecVersion::V3_0, page_limits) {
+        panic!("{error:#}");
+    }
+}
+
+#[test]
+fn dumped_spec_documents_the_configured_page_limits() {
+    let path = temp_path("openapi.json");
+    let page_limits = PageLimits {
+        default_per_page: 10,
+        max_per_page: 50,
+    };
+
+    dump_openapi(&path, SpecVersion::V3_0, page_limits).unwrap();
+
+    let spec: serde_json::Value = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+    let per_page = spec["paths"]["/v1/employees"]["get"]["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|parameter| parameter["name"] == "per_page")
+        .unwrap();
+    assert_eq!(per_page["schema"]["maximum"], 50.0);
+    assert_eq!(per_page["schema"]["default"], 10);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn list_response_is_an_array_of_employees() {
+    let spec = served_spec().await;
+    let response = &spec["paths"]["/v1/employees"]["get"]["responses"]["200"];
+
+    let page = resolve(&spec, &response["content"]["application/json"]["schema"]);
+    assert_eq!(page["properties"]["data"]["type"], "array");
+    let item = resolve(&spec, &page["properties"]["data"]["items"]);
+    let employee = resolve(&spec, &item["allOf"][0]);
+    assert!(
+        employee["properties"]["employee_id"].is_object(),
+        "{employee}"
+    );
+    assert!(resolve(&spec, &page["properties"]["pagination"]).is_object());
+
+    // Generic names such as `PaginatedResponse<Employee>` break client generators
+    let schemas = spec["components"]["schemas"].as_object().unwrap();
+    assert!(schemas.keys().all(|name| !name.contains('<')));
+    assert!(!schemas.contains_key("PaginatedResponse"));
+}
+
+// The named examples on one response, whatever media type it is documented under
+fn response_examples<'a>(spec: &'a Value, path: &str, method: &str, status: &str) -> &'a Value {
+    let content = spec["paths"][path][method]["responses"][status]["content"]
+        .as_object()
+        .unwrap_or_else(|| panic!("{method} {path} documents no {status} body"));
+    &content.values().next().unwrap()["examples"]
+}
+
+#[tokio::test]
+async fn error_responses_carry_named_examples() {
+    let spec = served_spec().await;
+
+    let not_found = response_examples(&spec, "/v1/employees/{id}", "get", "404");
+    assert!(not_found["not_found"]["summary"].is_string());
+    assert!(not_found["not_found"]["value"].is_object());
+    let invalid = response_examples(&spec, "/v1/employees", "post", "422");
+    assert!(invalid["validation_error"]["summary"].is_string());
+    assert!(invalid["validation_error"]["value"].is_object());
+}
+
+#[tokio::test]
+async fn error_details_are_discriminated_on_code() {
+    let spec = served_spec().await;
+    let details = &spec["components"]["schemas"]["ErrorDetails"];
+
+    assert_eq!(details["oneOf"].as_array().unwrap().len(), 3);
+    assert_eq!(details["discriminator"]["propertyName"], "code");
+    let mapping = &details["discriminator"]["mapping"];
+    assert_eq!(
+        mapping["validation_error"],
+        "#/components/schemas/ValidationErrorDetails"
+    );
+    assert_eq!(
+        mapping["conflict"],
+        "#/components/schemas/ConflictErrorDetails"
+    );
+    assert_eq!(
+        mapping["not_found"],
+        "#/components/schemas/GeneralErrorDetails"
+    );
+}
+
+#[tokio::test]
+async fn openapi_3_1_uses_null_types_instead_of_nullable() {
+    let spec: Value = body_json(send(&app(), get("/api-docs/openapi-3.1.json")).await).await;
+
+    assert_eq!(spec["openapi"], "3.1.0");
+    let employee = &spec["components"]["schemas"]["Employee"]["properties"];
+    assert_eq!(
+        employee["position"]["type"],
+        serde_json::json!(["string", "null"])
+    );
+    assert_eq!(
+        employee["department_id"]["type"],
+        serde_json::json!(["string", "null"])
+    );
+    assert_eq!(employee["department_id"]["format"], "uuid");
+    assert_eq!(employee["employee_id"]["type"], "string");
+    assert!(
+        !spec.to_string().contains("\"nullable\":true"),
+        "3.1 has no nullable keyword"
+    );
+}
+
+#[tokio::test]
+async fn create_request_requires_only_the_mandatory_fields() {
+    let spec = served_spec().await;
+    let create = &spec["components"]["schemas"]["CreateEmployeeRequest"];
+    let required: Vec<&str> = create["required"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|field| field.as_str().unwrap())
+        .collect();
+
+    assert!(required.contains(&"employee_id"), "{required:?}");
+    assert!(!required.contains(&"department_id"), "{required:?}");
+    assert!(create["properties"]["department_id"].is_object());
+}
+
+#[tokio::test]
+async fn yaml_spec_parses_and_matches_the_json_one() {
+    let response = send(&app(), get("/api-docs/openapi.yaml")).await;
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "application/yaml");
+    let yaml: Value = serde_yaml::from_slice(&body_bytes(response).await).expect("valid YAML");
+
+    let json = served_spec().await;
+    assert_eq!(yaml["info"]["title"], "openapi-integration");
+    assert_eq!(yaml["info"]["title"], json["info"]["title"]);
+    assert_eq!(
+        yaml["paths"].as_object().unwrap().len(),
+        json["paths"].as_object().unwrap().len()
+    );
+}
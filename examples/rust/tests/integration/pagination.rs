@@ -0,0 +1,152 @@
+use std::collections::{HashMap, HashSet};
+
+use axum::http::{header, StatusCode};
+use openapi_integration::models::common::{PaginatedResponse, PaginationInfo};
+use openapi_integration::models::employees::Employee;
+use openapi_integration::routes::create_app;
+
+use crate::common::{app, body_json, config_with, error_code, error_message, get, send};
+
+#[tokio::test]
+async fn unparseable_page_is_a_json_400() {
+    let response = send(&app(), get("/v1/employees?page=abc")).await;
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .ends_with("json"));
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "bad_request");
+}
+
+#[tokio::test]
+async fn cursors_visit_every_employee_once() {
+    let app = app();
+    let mut seen = HashSet::new();
+    let mut uri = "/v1/employees?limit=2".to_string();
+
+    loop {
+        let page: PaginatedResponse<Employee> = body_json(send(&app, get(&uri)).await).await;
+        let PaginationInfo::Cursor(pagination) = page.pagination else {
+            panic!("limit did not switch to cursor pagination");
+        };
+        assert!(page.data.len() <= 2);
+        for employee in page.data {
+            assert!(
+                seen.insert(employee.id),
+                "{} seen twice",
+                employee.employee_id
+            );
+        }
+        match pagination.next_cursor {
+            Some(cursor) => uri = format!("/v1/employees?limit=2&cursor={cursor}"),
+            None => {
+                assert!(!pagination.has_more);
+                assert_eq!(seen.len() as u64, pagination.total);
+                break;
+            }
+        }
+    }
+    assert_eq!(seen.len(), 5);
+}
+
+// `Link` header relations mapped to their URLs
+fn links(header: &str) -> HashMap<String, String> {
+    header
+        .split(", ")
+        .map(|link| {
+            let (url, rel) = link.split_once("; ").expect("link has parameters");
+            let rel = rel.strip_prefix("rel=").expect("link has a rel");
+            (
+                rel.trim_matches('"').to_string(),
+                url.trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string(),
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn middle_page_links_every_relation() {
+    let response = send(
+        &app(),
+        get("/v1/employees?sort_by=hire_date&per_page=2&page=2"),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()["x-total-count"], "5");
+    let links = links(response.headers()[header::LINK].to_str().unwrap());
+    let expected = |page: u32| format!("/v1/employees?sort_by=hire_date&per_page=2&page={page}");
+    assert_eq!(links["first"], expected(1));
+    assert_eq!(links["prev"], expected(1));
+    assert_eq!(links["next"], expected(3));
+    assert_eq!(links["last"], expected(3));
+    assert_eq!(links.len(), 4);
+}
+
+#[tokio::test]
+async fn edge_pages_omit_prev_and_next() {
+    let first = send(&app(), get("/v1/employees?per_page=2")).await;
+    let first = links(first.headers()[header::LINK].to_str().unwrap());
+    assert!(!first.contains_key("prev") && first.contains_key("next"));
+
+    let last = send(&app(), get("/v1/employees?per_page=2&page=3")).await;
+    let last = links(last.headers()[header::LINK].to_str().unwrap());
+    assert!(last.contains_key("prev") && !last.contains_key("next"));
+}
+
+#[tokio::test]
+async fn out_of_range_page_parameters_are_rejected_by_name() {
+    let app = app();
+
+    for (query, parameter) in [
+        ("per_page=0", "per_page"),
+        ("per_page=101", "per_page"),
+        ("page=0", "page"),
+    ] {
+        let response = send(&app, get(&format!("/v1/employees?{query}"))).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{query}");
+        let body: serde_json::Value = body_json(response).await;
+        assert_eq!(error_code(&body), "bad_request");
+        let message = error_message(&body);
+        assert!(message.starts_with(parameter), "{query}: {message}");
+    }
+}
+
+#[tokio::test]
+async fn configured_max_per_page_is_enforced_and_documented() {
+    let app = create_app(&config_with(&[("MAX_PER_PAGE", "50")]));
+
+    assert_eq!(
+        send(&app, get("/v1/employees?per_page=50")).await.status(),
+        StatusCode::OK
+    );
+    let response = send(&app, get("/v1/employees?per_page=51")).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = body_json(response).await;
+    assert!(error_message(&body).starts_with("per_page"), "{body}");
+
+    let spec: serde_json::Value = body_json(send(&app, get("/api-docs/openapi.json")).await).await;
+    let per_page = spec["paths"]["/v1/employees"]["get"]["parameters"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|parameter| parameter["name"] == "per_page")
+        .unwrap();
+    assert_eq!(per_page["schema"]["maximum"], 50.0);
+}
+
+#[tokio::test]
+async fn cursor_pages_have_a_next_cursor_and_no_page_counts() {
+    let body: serde_json::Value = body_json(send(&app(), get("/v1/employees?limit=2")).await).await;
+    let pagination = &body["pagination"];
+
+    assert!(pagination["next_cursor"].is_string(), "{pagination}");
+    assert!(pagination.get("total_pages").is_none(), "{pagination}");
+    assert!(pagination.get("page").is_none(), "{pagination}");
+    assert_eq!(body["data"].as_array().unwrap().len(), 2);
+}
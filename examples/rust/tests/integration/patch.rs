@@ -0,0 +1,178 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use axum::Router;
+use openapi_integration::models::employees::Employee;
+
+use crate::common::{app, body_json, get, request, seeded_id, send, validation_errors};
+
+// PATCH with the employee's current ETag in If-Match
+async fn patch(
+    app: &Router,
+    uri: &str,
+    content_type: &str,
+    body: serde_json::Value,
+) -> Request<Body> {
+    let current = send(app, get(uri)).await;
+    let etag = current.headers()[header::ETAG].clone();
+    request(Method::PATCH, uri)
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::IF_MATCH, etag)
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn merge_patch_leaves_omitted_fields_alone() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let before: Employee = body_json(send(&app, get(&uri)).await).await;
+
+    let response = send(
+        &app,
+        patch(
+            &app,
+            &uri,
+            "application/merge-patch+json",
+            serde_json::json!({ "position": "Staff Engineer" }),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let after: Employee = body_json(response).await;
+    assert_eq!(after.position.as_deref(), Some("Staff Engineer"));
+    assert!(before.department_id.is_some());
+    assert_eq!(after.department_id, before.department_id);
+    assert_eq!(after.manager_id, before.manager_id);
+    assert_eq!(after.email, before.email);
+}
+
+#[tokio::test]
+async fn merge_patch_null_clears_the_field() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let before: Employee = body_json(send(&app, get(&uri)).await).await;
+
+    let response = send(
+        &app,
+        patch(
+            &app,
+            &uri,
+            "application/merge-patch+json",
+            serde_json::json!({ "department_id": null }),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let after: Employee = body_json(response).await;
+    assert_eq!(after.department_id, None);
+    assert_eq!(after.position, before.position);
+    assert_eq!(after.manager_id, before.manager_id);
+}
+
+#[tokio::test]
+async fn merge_patch_changes_employee_id_and_hire_date() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+
+    let response = send(
+        &app,
+        patch(
+            &app,
+            &uri,
+            "application/merge-patch+json",
+            serde_json::json!({ "employee_id": "EMP901", "hire_date": "2021-03-01" }),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let after: Employee = body_json(response).await;
+    assert_eq!(after.employee_id, "EMP901");
+    assert_eq!(after.hire_date.to_string(), "2021-03-01");
+}
+
+#[tokio::test]
+async fn merge_patch_null_on_a_required_field_is_422() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let before: Employee = body_json(send(&app, get(&uri)).await).await;
+
+    let response = send(
+        &app,
+        patch(
+            &app,
+            &uri,
+            "application/merge-patch+json",
+            serde_json::json!({ "first_name": null, "position": "Staff Engineer" }),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    let errors = validation_errors(&body);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0]["field"], "first_name");
+    assert_eq!(errors[0]["code"], "required");
+    let after: Employee = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(after.first_name, before.first_name);
+    assert_eq!(after.position, before.position);
+}
+
+#[tokio::test]
+async fn json_patch_replace_updates_the_field() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let before: Employee = body_json(send(&app, get(&uri)).await).await;
+
+    let response = send(
+        &app,
+        patch(
+            &app,
+            &uri,
+            "application/json-patch+json",
+            serde_json::json!([{ "op": "replace", "path": "/position", "value": "Staff Engineer" }]),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let after: Employee = body_json(response).await;
+    assert_eq!(after.position.as_deref(), Some("Staff Engineer"));
+    assert_eq!(after.email, before.email);
+    assert_eq!(after.department_id, before.department_id);
+}
+
+#[tokio::test]
+async fn json_patch_cannot_remove_a_required_field() {
+    let app = app();
+    let uri = format!("/v1/employees/{}", seeded_id(&app, "EMP001").await);
+    let before: Employee = body_json(send(&app, get(&uri)).await).await;
+
+    let response = send(
+        &app,
+        patch(
+            &app,
+            &uri,
+            "application/json-patch+json",
+            serde_json::json!([{ "op": "remove", "path": "/email" }]),
+        )
+        .await,
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    let errors = validation_errors(&body);
+    assert_eq!(errors[0]["code"], "invalid_patch");
+    assert!(errors[0]["message"].as_str().unwrap().contains("email"));
+    let after: Employee = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(after.email, before.email);
+}
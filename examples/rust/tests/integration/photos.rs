@@ -0,0 +1,128 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use axum::Router;
+use openapi_integration::routes::create_app;
+
+use crate::common::{
+    app, body_bytes, body_json, config_with, error_code, get, request, seeded_id, send, temp_path,
+};
+
+pub const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+const BOUNDARY: &str = "photo-boundary";
+
+pub fn upload(uri: &str, bytes: &[u8]) -> Request<Body> {
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"photo\"; filename=\"photo.png\"\r\n\
+         Content-Type: image/png\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(bytes);
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+    request(Method::POST, uri)
+        .header(
+            header::CONTENT_TYPE,
+            format!("multipart/form-data; boundary={BOUNDARY}"),
+        )
+        .body(Body::from(body))
+        .unwrap()
+}
+
+fn local_storage_app(dir: &std::path::Path) -> Router {
+    create_app(&config_with(&[
+        ("PHOTO_STORAGE", "local"),
+        ("PHOTO_DIR", dir.to_str().unwrap()),
+    ]))
+}
+
+#[tokio::test]
+async fn uploaded_photo_is_served_back() {
+    let app = app();
+    let id = seeded_id(&app, "EMP002").await;
+    let uri = format!("/v1/employees/{id}/photo");
+
+    let response = send(&app, upload(&uri, PNG)).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = send(&app, get(&uri)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
+    assert_eq!(body_bytes(response).await, PNG);
+}
+
+#[tokio::test]
+async fn oversized_photo_is_413() {
+    let app = app();
+    let id = seeded_id(&app, "EMP002").await;
+    let uri = format!("/v1/employees/{id}/photo");
+    let mut oversized = PNG.to_vec();
+    oversized.resize(5 * 1024 * 1024 + 1, 0);
+
+    let response = send(&app, upload(&uri, &oversized)).await;
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "payload_too_large");
+    assert_eq!(send(&app, get(&uri)).await.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn local_storage_round_trips_a_photo() {
+    let dir = temp_path("photos");
+    let app = local_storage_app(&dir);
+    let id = seeded_id(&app, "EMP001").await;
+    let uri = format!("/v1/employees/{id}/photo");
+
+    let response = send(&app, upload(&uri, PNG)).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(dir.join(id.to_string()).is_file());
+
+    let response = send(&app, get(&uri)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
+    assert_eq!(body_bytes(response).await, PNG);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[tokio::test]
+async fn missing_photo_is_404() {
+    let dir = temp_path("photos");
+    let app = local_storage_app(&dir);
+    let id = seeded_id(&app, "EMP001").await;
+
+    let response = send(&app, get(&format!("/v1/employees/{id}/photo"))).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn delete_succeeds_and_is_audited_when_the_photo_cannot_be_removed() {
+    // A regular file where the photo directory should be, so every removal fails
+    let not_a_dir = temp_path("photos");
+    std::fs::write(&not_a_dir, b"").unwrap();
+    let app = local_storage_app(&not_a_dir);
+    let id = seeded_id(&app, "EMP003").await;
+
+    let response = send(
+        &app,
+        request(Method::DELETE, &format!("/v1/employees/{id}"))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = send(&app, get(&format!("/v1/employees/{id}/audit"))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let audit: serde_json::Value = body_json(response).await;
+    let actions: Vec<&str> = audit["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|entry| entry["action"].as_str())
+        .collect();
+    assert!(actions.contains(&"deleted"), "audit: {audit}");
+
+    std::fs::remove_file(not_a_dir).unwrap();
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use openapi_integration::builders::EmployeeBuilder;
+use openapi_integration::clock::SystemClock;
+use openapi_integration::repository::postgres::{run_migrations, PgEmployeeRepository};
+use openapi_integration::repository::{EmployeeRepository, RepoError};
+use sqlx::postgres::PgConnectOptions;
+use sqlx::{Executor, PgPool};
+
+fn test_database_url() -> Option<String> {
+    let url = std::env::var("TEST_DATABASE_URL").ok();
+    if url.is_none() {
+        eprintln!("TEST_DATABASE_URL is not set, skipping");
+    }
+    url
+}
+
+// A migrated pool on TEST_DATABASE_URL, or None (and the test passes vacuously) when unset
+async fn test_database() -> Option<PgPool> {
+    let url = test_database_url()?;
+    let pool = PgPool::connect(&url)
+        .await
+        .expect("TEST_DATABASE_URL is reachable");
+    run_migrations(&pool).await.expect("migrations apply");
+    Some(pool)
+}
+
+#[tokio::test]
+async fn creates_gets_and_lists_employees() {
+    let Some(pool) = test_database().await else {
+        return;
+    };
+    let repository = PgEmployeeRepository::new(pool, Arc::new(SystemClock));
+    // Unique per run, so a database left over from an earlier run doesn't clash
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    let employee_id = format!("PG{}", &suffix[..6]);
+    let employee = EmployeeBuilder::new(&employee_id, "Post", "Gres", Utc::now())
+        .with_email(&format!("postgres.{suffix}@company.com"))
+        .build();
+
+    let created = repository.create(employee.clone()).await.unwrap();
+    assert_eq!(created.id, employee.id);
+
+    let fetched = repository.get(employee.id).await.unwrap().expect("stored");
+    assert_eq!(fetched.employee_id, employee.employee_id);
+    assert_eq!(fetched.email, employee.email);
+
+    let listed = repository.list().await.unwrap();
+    assert!(listed.iter().any(|e| e.id == employee.id));
+
+    let duplicate = EmployeeBuilder::new(&employee_id, "Dup", "Licate", Utc::now())
+        .with_email(&format!("duplicate.{suffix}@company.com"))
+        .build();
+    assert!(matches!(
+        repository.create(duplicate).await,
+        Err(RepoError::Conflict("employee_id"))
+    ));
+
+    repository.delete(employee.id).await.unwrap();
+}
+
+#[tokio::test]
+async fn migrations_create_the_schema_on_a_fresh_database() {
+    let Some(url) = test_database_url() else {
+        return;
+    };
+    let admin = PgPool::connect(&url)
+        .await
+        .expect("TEST_DATABASE_URL is reachable");
+    let name = format!("migrations_{}", uuid::Uuid::new_v4().simple());
+    admin
+        .execute(format!("CREATE DATABASE {name}").as_str())
+        .await
+        .unwrap();
+    let options: PgConnectOptions = url.parse().unwrap();
+    let fresh = PgPool::connect_with(options.database(&name)).await.unwrap();
+
+    let applied = run_migrations(&fresh).await.unwrap();
+    let tables: Vec<String> = sqlx::query_scalar(
+        "SELECT table_name::text FROM information_schema.tables \
+         WHERE table_schema = 'public' AND table_name NOT LIKE '\\_sqlx%' ORDER BY table_name",
+    )
+    .fetch_all(&fresh)
+    .await
+    .unwrap();
+    let constraints: Vec<String> = sqlx::query_scalar(
+        "SELECT constraint_name::text FROM information_schema.table_constraints \
+         WHERE table_name = 'employees' AND constraint_type IN ('UNIQUE', 'FOREIGN KEY')",
+    )
+    .fetch_all(&fresh)
+    .await
+    .unwrap();
+    let rerun = run_migrations(&fresh).await.unwrap();
+    fresh.close().await;
+    admin
+        .execute(format!("DROP DATABASE {name}").as_str())
+        .await
+        .unwrap();
+
+    assert!(applied > 0);
+    assert_eq!(rerun, 0);
+    assert_eq!(tables, ["departments", "employees"]);
+    assert!(constraints.contains(&"employees_employee_id_key".to_string()));
+    assert!(
+        constraints.iter().any(|c| c.contains("department_id")),
+        "{constraints:?}"
+    );
+}
@@ -0,0 +1,143 @@
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{header, Method, StatusCode};
+use openapi_integration::models::common::PaginatedResponse;
+use openapi_integration::models::employees::Employee;
+use openapi_integration::repository::{EmployeeRepository, RepoError};
+use openapi_integration::routes::create_app_with_repository;
+use uuid::Uuid;
+
+use crate::common::{body_json, config_with, get, json_request, new_employee, request, send};
+
+// Keeps employees in a Vec and notes every call the handlers make
+#[derive(Default)]
+struct RecordingRepository {
+    calls: Mutex<Vec<String>>,
+    employees: Mutex<Vec<Employee>>,
+}
+
+impl RecordingRepository {
+    fn record(&self, call: String) {
+        self.calls.lock().unwrap().push(call);
+    }
+
+    fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl EmployeeRepository for RecordingRepository {
+    async fn list(&self) -> Result<Vec<Employee>, RepoError> {
+        self.record("list".to_string());
+        Ok(self.employees.lock().unwrap().clone())
+    }
+
+    async fn get(&self, id: Uuid) -> Result<Option<Employee>, RepoError> {
+        self.record(format!("get {id}"));
+        let employees = self.employees.lock().unwrap();
+        Ok(employees.iter().find(|e| e.id == id).cloned())
+    }
+
+    async fn create(&self, employee: Employee) -> Result<Employee, RepoError> {
+        self.record(format!("create {}", employee.employee_id));
+        self.employees.lock().unwrap().push(employee.clone());
+        Ok(employee)
+    }
+
+    async fn update(&self, employee: Employee) -> Result<Employee, RepoError> {
+        self.record(format!("update {}", employee.id));
+        let mut employees = self.employees.lock().unwrap();
+        match employees.iter_mut().find(|e| e.id == employee.id) {
+            Some(stored) => {
+                *stored = employee.clone();
+                Ok(employee)
+            }
+            None => Err(RepoError::NotFound(employee.id)),
+        }
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), RepoError> {
+        self.record(format!("delete {id}"));
+        Err(RepoError::NotFound(id))
+    }
+}
+
+#[tokio::test]
+async fn handlers_go_through_the_repository() {
+    let repository = Arc::new(RecordingRepository::default());
+    let app = create_app_with_repository(&config_with(&[]), repository.clone());
+
+    let response = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: Employee = body_json(response).await;
+    assert_eq!(repository.calls(), ["create TST001"]);
+
+    let page: PaginatedResponse<Employee> = body_json(send(&app, get("/v1/employees")).await).await;
+    assert_eq!(page.data.len(), 1);
+    assert_eq!(repository.calls(), ["create TST001", "list"]);
+
+    let response = send(&app, get(&format!("/v1/employees/{}", created.id))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+        repository.calls().last(),
+        Some(&format!("get {}", created.id))
+    );
+}
+
+#[tokio::test]
+async fn repeated_gets_are_served_from_the_cache_until_an_update() {
+    let repository = Arc::new(RecordingRepository::default());
+    let app = create_app_with_repository(&config_with(&[]), repository.clone());
+    let created: Employee = body_json(
+        send(
+            &app,
+            json_request(
+                Method::POST,
+                "/v1/employees",
+                &new_employee("TST001", "test.employee@company.com"),
+            ),
+        )
+        .await,
+    )
+    .await;
+    let uri = format!("/v1/employees/{}", created.id);
+    let lookup = format!("get {}", created.id);
+    let lookups = || repository.calls().iter().filter(|c| **c == lookup).count();
+
+    send(&app, get(&uri)).await;
+    let after_first = lookups();
+    assert!(after_first > 0);
+    let response = send(&app, get(&uri)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(lookups(), after_first, "second read is a cache hit");
+
+    let response = send(
+        &app,
+        request(Method::PATCH, &uri)
+            .header(header::CONTENT_TYPE, "application/merge-patch+json")
+            .header(header::IF_MATCH, "*")
+            .body(Body::from(r#"{"position":"Staff Engineer"}"#))
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let after_update = lookups();
+
+    let fetched: Employee = body_json(send(&app, get(&uri)).await).await;
+    assert_eq!(fetched.position.as_deref(), Some("Staff Engineer"));
+    assert!(
+        lookups() > after_update,
+        "the update evicted the cached copy"
+    );
+}
@@ -0,0 +1,49 @@
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode};
+use uuid::Uuid;
+
+use crate::common::{app, body_json, get, request, send};
+
+#[tokio::test]
+async fn responses_carry_a_request_id() {
+    let response = send(&app(), get("/v1/employees")).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let id = response.headers()["x-request-id"].to_str().unwrap();
+    assert!(Uuid::parse_str(id).is_ok(), "{id}");
+}
+
+fn with_request_id(uri: &str, id: &str) -> Request<Body> {
+    request(Method::GET, uri)
+        .header("x-request-id", id)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn client_request_id_is_echoed() {
+    let app = app();
+    let ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV";
+    let missing = "/v1/employees/123e4567-e89b-12d3-a456-426614174000";
+
+    let response = send(&app, with_request_id("/v1/employees", ulid)).await;
+    assert_eq!(response.headers()["x-request-id"], ulid);
+
+    let response = send(&app, with_request_id(missing, ulid)).await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    assert_eq!(response.headers()["x-request-id"], ulid);
+    let body: serde_json::Value = body_json(response).await;
+    #[cfg(not(feature = "problem-json"))]
+    assert_eq!(body["error"]["request_id"], ulid);
+    #[cfg(feature = "problem-json")]
+    assert_eq!(body["request_id"], ulid);
+}
+
+#[tokio::test]
+async fn malformed_request_id_is_replaced() {
+    let response = send(&app(), with_request_id("/v1/employees", "not a request id")).await;
+
+    let id = response.headers()["x-request-id"].to_str().unwrap();
+    assert_ne!(id, "not a request id");
+    assert!(Uuid::parse_str(id).is_ok(), "{id}");
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use axum::http::{Method, StatusCode};
+use chrono::{DateTime, Duration, Utc};
+use openapi_integration::clock::{Clock, FixedClock};
+use openapi_integration::retention::purge_terminated;
+use openapi_integration::routes::app_router;
+use openapi_integration::state::AppState;
+
+use crate::common::{config_with, get, json_request, seeded_id, send};
+
+#[tokio::test]
+async fn purges_only_terminated_employees_older_than_the_cutoff() {
+    let start: DateTime<Utc> = "2025-03-01T09:00:00Z".parse().unwrap();
+    let clock = Arc::new(FixedClock::new(start));
+    let config = config_with(&[]);
+    let state = AppState::seeded(&config, clock.clone());
+    let app = app_router(&config, state.clone());
+    let old = seeded_id(&app, "EMP003").await;
+    let recent = seeded_id(&app, "EMP004").await;
+    let reason = serde_json::json!({ "reason": "Relocated" });
+
+    let terminate = |id| {
+        json_request(
+            Method::POST,
+            &format!("/v1/employees/{id}/terminate"),
+            &reason,
+        )
+    };
+    assert_eq!(send(&app, terminate(old)).await.status(), StatusCode::OK);
+    clock.advance(Duration::days(20));
+    assert_eq!(send(&app, terminate(recent)).await.status(), StatusCode::OK);
+    clock.advance(Duration::days(15));
+
+    // 30 days of retention: EMP003 was terminated 35 days ago, EMP004 only 15
+    let Ok(purged) = purge_terminated(&state, clock.now() - Duration::days(30)).await else {
+        panic!("purge failed");
+    };
+
+    assert_eq!(purged, [old]);
+    let uri = |id| format!("/v1/employees/{id}");
+    assert_eq!(
+        send(&app, get(&uri(old))).await.status(),
+        StatusCode::NOT_FOUND
+    );
+    assert_eq!(send(&app, get(&uri(recent))).await.status(), StatusCode::OK);
+    // Active employees are never purged, however old
+    let active = seeded_id(&app, "EMP001").await;
+    assert_eq!(send(&app, get(&uri(active))).await.status(), StatusCode::OK);
+}
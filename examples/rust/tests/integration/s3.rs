@@ -0,0 +1,87 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Once};
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::{Method, StatusCode};
+use axum::routing::any;
+use axum::Router;
+use openapi_integration::routes::create_app;
+use tokio::net::TcpListener;
+
+use crate::common::{body_json, config_with, get, seeded_id, send};
+use crate::photos::{upload, PNG};
+
+type Objects = Arc<Mutex<HashMap<String, Bytes>>>;
+
+// Just enough of the S3 object API for put and head; the SDK addresses an IP endpoint
+// path-style, so requests arrive as /<bucket>/<key>
+async fn object(
+    State(objects): State<Objects>,
+    Path((bucket, key)): Path<(String, String)>,
+    method: Method,
+    body: Bytes,
+) -> StatusCode {
+    let key = format!("{bucket}/{key}");
+    let mut objects = objects.lock().unwrap();
+    match method {
+        Method::PUT => {
+            objects.insert(key, body);
+            StatusCode::OK
+        }
+        Method::HEAD if objects.contains_key(&key) => StatusCode::OK,
+        _ => StatusCode::NOT_FOUND,
+    }
+}
+
+// Points the AWS default chain at `endpoint` with static credentials. The chain reads the
+// process environment, which every S3 test shares, so the first endpoint wins.
+fn aws_environment(endpoint: &str) {
+    static SET: Once = Once::new();
+    SET.call_once(|| {
+        std::env::set_var("AWS_ENDPOINT_URL", endpoint);
+        std::env::set_var("AWS_REGION", "us-east-1");
+        std::env::set_var("AWS_ACCESS_KEY_ID", "AKIDTEST");
+        std::env::set_var("AWS_SECRET_ACCESS_KEY", "test-secret");
+        std::env::set_var("AWS_EC2_METADATA_DISABLED", "true");
+    });
+}
+
+#[tokio::test]
+async fn photo_url_is_presigned_with_the_configured_expiry() {
+    let objects = Objects::default();
+    let mock = Router::new()
+        .route("/:bucket/*key", any(object))
+        .with_state(objects.clone());
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, mock).await.unwrap() });
+    aws_environment(&format!("http://{address}"));
+
+    let app = create_app(&config_with(&[
+        ("PHOTO_STORAGE", "s3"),
+        ("S3_BUCKET", "employee-photos"),
+        ("PHOTO_URL_TTL_SECS", "120"),
+    ]));
+    let id = seeded_id(&app, "EMP002").await;
+    let response = send(&app, upload(&format!("/v1/employees/{id}/photo"), PNG)).await;
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(objects
+        .lock()
+        .unwrap()
+        .contains_key(&format!("employee-photos/photos/{id}")));
+
+    let response = send(&app, get(&format!("/v1/employees/{id}/photo-url"))).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let photo_url: serde_json::Value = body_json(response).await;
+    let url = photo_url["url"].as_str().unwrap();
+    assert!(
+        url.starts_with(&format!("http://{address}/employee-photos/photos/{id}?")),
+        "{url}"
+    );
+    assert!(url.contains("X-Amz-Expires=120"), "{url}");
+    assert!(url.contains("X-Amz-Signature="), "{url}");
+    assert!(url.contains("X-Amz-Credential=AKIDTEST"), "{url}");
+    assert!(photo_url["expires_at"].is_string());
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use openapi_integration::clock::SystemClock;
+use openapi_integration::routes::app_router;
+use openapi_integration::state::AppState;
+use serde_json::json;
+
+use crate::common::{body_json, config_with, get, new_employee, send, temp_path};
+
+#[tokio::test]
+async fn a_seed_file_replaces_the_demo_data() {
+    let department_id = uuid::Uuid::new_v4();
+    let employee_id = uuid::Uuid::new_v4();
+    let mut first = new_employee("RND001", "ada@example.com");
+    first["id"] = json!(employee_id);
+    first["department_id"] = json!(department_id);
+    let seed = json!({
+        "departments": [{ "id": department_id, "name": "Research", "code": "RND" }],
+        "employees": [first, new_employee("RND002", "grace@example.com")],
+    });
+    let path = temp_path("seed.json");
+    std::fs::write(&path, seed.to_string()).unwrap();
+
+    let config = config_with(&[("SEED_FILE", path.to_str().unwrap())]);
+    let state = AppState::from_seed(&config, Arc::new(SystemClock));
+    std::fs::remove_file(&path).unwrap();
+    let Ok(state) = state else {
+        panic!("the seed file loads");
+    };
+    let app = app_router(&config, state);
+
+    let page: serde_json::Value = body_json(send(&app, get("/v1/employees")).await).await;
+    assert_eq!(page["pagination"]["total"], 2);
+    let mut ids: Vec<&str> = page["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|employee| employee["employee_id"].as_str().unwrap())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, ["RND001", "RND002"]);
+
+    let employee: serde_json::Value =
+        body_json(send(&app, get(&format!("/v1/employees/{employee_id}"))).await).await;
+    assert_eq!(employee["email"], "ada@example.com");
+    let research: serde_json::Value =
+        body_json(send(&app, get("/v1/employees?department=RND")).await).await;
+    assert_eq!(research["pagination"]["total"], 1);
+    assert_eq!(research["data"][0]["id"], employee_id.to_string());
+}
@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use openapi_integration::serve;
+use tokio::net::TcpListener;
+use tokio::sync::oneshot;
+
+use crate::common::app;
+
+#[tokio::test]
+async fn serve_returns_once_shutdown_resolves() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let server = tokio::spawn(serve(
+        listener,
+        app(),
+        async {
+            let _ = shutdown_rx.await;
+        },
+        Duration::from_secs(5),
+    ));
+
+    let health = reqwest::get(format!("http://{address}/health"))
+        .await
+        .unwrap();
+    assert!(health.status().is_success());
+
+    shutdown_tx.send(()).unwrap();
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server stops after the shutdown signal")
+        .unwrap()
+        .unwrap();
+    assert!(reqwest::get(format!("http://{address}/health"))
+        .await
+        .is_err());
+}
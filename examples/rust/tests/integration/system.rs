@@ -0,0 +1,37 @@
+use axum::body::Body;
+use axum::http::{Request, StatusCode};
+
+use crate::common::{app, body_json, get, send};
+
+// Probes come from load balancers and orchestrators that hold no token
+fn unauthenticated(uri: &str) -> Request<Body> {
+    Request::builder().uri(uri).body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn health_and_ready_answer_without_a_token() {
+    let app = app();
+
+    for (probe, status) in [("/health", "ok"), ("/ready", "ready")] {
+        let response = send(&app, unauthenticated(probe)).await;
+
+        assert_eq!(response.status(), StatusCode::OK, "{probe}");
+        let body: serde_json::Value = body_json(response).await;
+        assert_eq!(
+            body,
+            serde_json::json!({ "status": status, "maintenance": false }),
+            "{probe}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn version_reports_the_package_version() {
+    let response = send(&app(), get("/version")).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(body["name"], env!("CARGO_PKG_NAME"));
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    assert!(body["git_sha"].as_str().is_some_and(|sha| !sha.is_empty()));
+}
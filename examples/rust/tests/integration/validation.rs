@@ -0,0 +1,122 @@
+use axum::http::{header, Method, StatusCode};
+use axum::Router;
+
+use crate::common::{
+    app, body_json, department_id, error_code, json_request, new_employee, send, validation_errors,
+};
+
+// The validation entry for `field` in a 422 body
+fn failure<'a>(body: &'a serde_json::Value, field: &str) -> &'a serde_json::Value {
+    validation_errors(body)
+        .iter()
+        .find(|error| error["field"] == field)
+        .unwrap_or_else(|| panic!("no validation error for {field}: {body}"))
+}
+
+#[tokio::test]
+async fn employee_id_must_match_the_pattern() {
+    let response = send(
+        &app(),
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("tst-1", "test.employee@company.com"),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "validation_error");
+    let error = failure(&body, "employee_id");
+    assert!(!error["message"].as_str().unwrap_or_default().is_empty());
+}
+
+#[tokio::test]
+async fn email_must_be_an_email() {
+    let response = send(
+        &app(),
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "not-an-email"),
+        ),
+    )
+    .await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(failure(&body, "email")["code"], "invalid_email");
+    assert_eq!(validation_errors(&body).len(), 1);
+}
+
+#[tokio::test]
+async fn unknown_department_is_rejected() {
+    let mut body = new_employee("TST001", "test.employee@company.com");
+    body["department_id"] = uuid::Uuid::new_v4().to_string().into();
+
+    let response = send(&app(), json_request(Method::POST, "/v1/employees", &body)).await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    failure(&body, "department_id");
+}
+
+#[tokio::test]
+async fn existing_department_is_accepted() {
+    let app = app();
+    let engineering = department_id(&app, "ENG").await;
+    let mut body = new_employee("TST001", "test.employee@company.com");
+    body["department_id"] = engineering.to_string().into();
+
+    let response = send(&app, json_request(Method::POST, "/v1/employees", &body)).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created: serde_json::Value = body_json(response).await;
+    assert_eq!(created["department_id"], engineering.to_string());
+}
+
+#[tokio::test]
+async fn unknown_employment_status_is_rejected() {
+    let mut body = new_employee("TST001", "test.employee@company.com");
+    body["employment_status"] = "retired".into();
+
+    let response = send(&app(), json_request(Method::POST, "/v1/employees", &body)).await;
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    let body: serde_json::Value = body_json(response).await;
+    assert_eq!(error_code(&body), "validation_error");
+    let error = failure(&body, "employment_status");
+    assert_eq!(error["code"], "invalid_body");
+    assert!(error["message"].as_str().unwrap().contains("retired"));
+}
+
+// The message for an invalid email, asked for in `language`
+async fn invalid_email_message(app: &Router, language: &str) -> String {
+    let mut request = json_request(
+        Method::POST,
+        "/v1/employees",
+        &new_employee("TST001", "not-an-email"),
+    );
+    request
+        .headers_mut()
+        .insert(header::ACCEPT_LANGUAGE, language.parse().unwrap());
+    let response = send(app, request).await;
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(response.headers()[header::CONTENT_LANGUAGE], language);
+    let body: serde_json::Value = body_json(response).await;
+    let error = failure(&body, "email");
+    assert_eq!(error["code"], "invalid_email");
+    error["message"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn messages_follow_accept_language() {
+    let app = app();
+
+    let english = invalid_email_message(&app, "en").await;
+    let japanese = invalid_email_message(&app, "ja").await;
+
+    assert_ne!(japanese, english);
+    assert_eq!(japanese, "有効なメールアドレスを入力してください");
+}
@@ -0,0 +1,55 @@
+use axum::http::{header, Method, StatusCode};
+
+use crate::common::{app, get, json_request, new_employee, send};
+
+#[tokio::test]
+async fn versioned_path_is_served() {
+    let response = send(&app(), get("/v1/employees")).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn unversioned_paths_redirect_permanently() {
+    let app = app();
+
+    let response = send(&app, get("/employees?page=2&per_page=2")).await;
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(
+        response.headers()[header::LOCATION],
+        "/v1/employees?page=2&per_page=2"
+    );
+
+    // 308 keeps the method, so a POST is redirected rather than handled or downgraded
+    let body = new_employee("TST001", "test.employee@company.com");
+    let response = send(&app, json_request(Method::POST, "/employees", &body)).await;
+    assert_eq!(response.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(response.headers()[header::LOCATION], "/v1/employees");
+}
+
+#[tokio::test]
+async fn only_the_legacy_list_is_marked_deprecated() {
+    let app = app();
+
+    let legacy = send(&app, get("/v1/employees/all")).await;
+    assert_eq!(legacy.status(), StatusCode::OK);
+    assert_eq!(legacy.headers()["deprecation"], "true");
+    assert_eq!(legacy.headers()["sunset"], "Wed, 30 Jun 2027 00:00:00 GMT");
+    let links: Vec<&str> = legacy
+        .headers()
+        .get_all(header::LINK)
+        .iter()
+        .map(|link| link.to_str().unwrap())
+        .collect();
+    assert!(
+        links
+            .iter()
+            .any(|link| link.contains(r#"rel="deprecation""#)),
+        "{links:?}"
+    );
+
+    let current = send(&app, get("/v1/employees")).await;
+    assert_eq!(current.status(), StatusCode::OK);
+    assert!(!current.headers().contains_key("deprecation"));
+    assert!(!current.headers().contains_key("sunset"));
+}
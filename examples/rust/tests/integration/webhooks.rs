@@ -0,0 +1,71 @@
+use std::time::Duration;
+
+use axum::body::Bytes;
+use axum::http::{HeaderMap, Method, StatusCode};
+use axum::routing::post;
+use axum::Router;
+use hmac::{Hmac, Mac};
+use openapi_integration::routes::create_app;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+
+use crate::common::{config_with, json_request, new_employee, send};
+
+const WEBHOOK_SECRET: &str = "webhook-test-secret";
+
+// A receiver on an ephemeral port that hands every delivery back to the test
+async fn mock_receiver() -> (String, mpsc::UnboundedReceiver<(HeaderMap, Bytes)>) {
+    let (deliveries, received) = mpsc::unbounded_channel();
+    let receiver = Router::new().route(
+        "/hooks",
+        post(move |headers: HeaderMap, body: Bytes| async move {
+            let _ = deliveries.send((headers, body));
+            StatusCode::NO_CONTENT
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+    (format!("http://{address}/hooks"), received)
+}
+
+#[tokio::test]
+async fn delivery_signature_verifies_against_the_body() {
+    let (url, mut received) = mock_receiver().await;
+    let app = create_app(&config_with(&[
+        ("WEBHOOK_URL", &url),
+        ("WEBHOOK_SECRET", WEBHOOK_SECRET),
+    ]));
+
+    let created = send(
+        &app,
+        json_request(
+            Method::POST,
+            "/v1/employees",
+            &new_employee("TST001", "test.employee@company.com"),
+        ),
+    )
+    .await;
+    assert_eq!(created.status(), StatusCode::CREATED);
+
+    let (headers, body) = tokio::time::timeout(Duration::from_secs(5), received.recv())
+        .await
+        .expect("the webhook is delivered")
+        .unwrap();
+    let signature = headers["x-signature"].to_str().unwrap();
+    let digest = signature
+        .strip_prefix("sha256=")
+        .expect("signature names its algorithm");
+    let digest: Vec<u8> = (0..digest.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&digest[i..i + 2], 16).unwrap())
+        .collect();
+    let mut mac = Hmac::<sha2::Sha256>::new_from_slice(WEBHOOK_SECRET.as_bytes()).unwrap();
+    mac.update(&body);
+    mac.verify_slice(&digest)
+        .expect("signature is the HMAC-SHA256 of the body");
+
+    let payload: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(payload["event"], "employee.created");
+    assert_eq!(payload["data"]["employee"]["employee_id"], "TST001");
+}
@@ -0,0 +1,19 @@
+use axum::response::IntoResponse;
+use openapi_integration::error::{AppError, AuthFailure};
+
+#[test]
+fn app_errors_render_with_the_status_of_their_code() {
+    let cases = [
+        (AppError::BadRequest(String::new()), 400),
+        (AppError::Unauthorized(AuthFailure::MissingToken), 401),
+        (AppError::Unauthorized(AuthFailure::InvalidToken), 401),
+        (AppError::Forbidden, 403),
+        (AppError::NotFound(String::new()), 404),
+        (AppError::Conflict(String::new()), 409),
+        (AppError::Validation(Vec::new()), 422),
+        (AppError::Internal(String::new()), 500),
+    ];
+    for (error, status) in cases {
+        assert_eq!(error.into_response().status().as_u16(), status);
+    }
+}
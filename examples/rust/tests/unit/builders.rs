@@ -0,0 +1,33 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use openapi_integration::builders::{CreateEmployeeRequestBuilder, EmployeeBuilder};
+use openapi_integration::models::employees::EmploymentStatus;
+
+fn now() -> DateTime<Utc> {
+    "2025-03-01T09:00:00Z".parse().unwrap()
+}
+
+#[test]
+fn request_defaults_are_valid() {
+    let today = NaiveDate::from_ymd_opt(2025, 3, 1).unwrap();
+
+    let request = CreateEmployeeRequestBuilder::new("TST001", "Ada", "Lovelace", today).build();
+
+    assert_eq!(request.email, "ada.lovelace@company.com");
+    assert_eq!(request.hire_date, today);
+    assert!(validator::Validate::validate(&request).is_ok());
+}
+
+#[test]
+fn employee_is_stored_as_created_at_now() {
+    let employee = EmployeeBuilder::new("TST001", "Ada", "Lovelace", now())
+        .with_email("ada@company.com")
+        .terminated("Retired")
+        .build();
+
+    assert_eq!(employee.email, "ada@company.com");
+    assert_eq!(employee.employment_status, EmploymentStatus::Terminated);
+    assert_eq!(employee.termination_reason.as_deref(), Some("Retired"));
+    assert_eq!(employee.version, 1);
+    assert_eq!((employee.created_at, employee.updated_at), (now(), now()));
+    assert_eq!(employee.hire_date, now().date_naive());
+}
@@ -0,0 +1,39 @@
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use openapi_integration::config::app::Config;
+
+fn config(vars: &[(&str, &str)]) -> anyhow::Result<Config> {
+    Config::from_lookup(|name| {
+        vars.iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+#[test]
+fn unset_settings_take_their_defaults() {
+    let config = config(&[("JWT_SECRET", "secret")]).unwrap();
+
+    assert_eq!(config.bind_addr, SocketAddr::from(([0, 0, 0, 0], 3000)));
+    assert_eq!(config.jwt_secret, "secret");
+    assert_eq!(config.page_limits.default_per_page, 20);
+    assert_eq!(config.page_limits.max_per_page, 100);
+    assert!(config.cors_allowed_origins.is_empty());
+    assert_eq!(config.rate_limit_window, Duration::from_secs(60));
+    assert!(config.require_if_match);
+}
+
+#[test]
+fn missing_jwt_secret_fails_fast() {
+    let error = config(&[]).unwrap_err();
+
+    assert!(error.to_string().contains("JWT_SECRET"), "{error}");
+}
+
+#[test]
+fn malformed_setting_is_named_in_the_error() {
+    let error = config(&[("JWT_SECRET", "secret"), ("BIND_ADDR", "localhost")]).unwrap_err();
+
+    assert!(error.to_string().contains("BIND_ADDR"), "{error}");
+}
@@ -0,0 +1,45 @@
+use openapi_integration::models::common::ErrorCode;
+
+// The codes as documented at GET /error-codes; changing one breaks every client matching on it
+const DOCUMENTED: [(ErrorCode, &str, u16); 16] = [
+    (ErrorCode::ValidationError, "validation_error", 422),
+    (ErrorCode::Conflict, "conflict", 409),
+    (ErrorCode::BadRequest, "bad_request", 400),
+    (ErrorCode::NotFound, "not_found", 404),
+    (ErrorCode::PreconditionFailed, "precondition_failed", 412),
+    (
+        ErrorCode::PreconditionRequired,
+        "precondition_required",
+        428,
+    ),
+    (ErrorCode::PayloadTooLarge, "payload_too_large", 413),
+    (
+        ErrorCode::UnsupportedMediaType,
+        "unsupported_media_type",
+        415,
+    ),
+    (ErrorCode::NotAcceptable, "not_acceptable", 406),
+    (ErrorCode::Unauthorized, "unauthorized", 401),
+    (ErrorCode::Forbidden, "forbidden", 403),
+    (ErrorCode::RateLimited, "rate_limited", 429),
+    (ErrorCode::MethodNotAllowed, "method_not_allowed", 405),
+    (ErrorCode::ServiceUnavailable, "service_unavailable", 503),
+    (ErrorCode::GatewayTimeout, "gateway_timeout", 504),
+    (ErrorCode::InternalError, "internal_error", 500),
+];
+
+#[test]
+fn every_code_serializes_to_its_documented_string() {
+    for (code, documented, status) in DOCUMENTED {
+        assert_eq!(serde_json::to_value(code).unwrap(), documented);
+        assert_eq!(code.as_str(), documented);
+        assert_eq!(code.to_string(), documented);
+        assert_eq!(code.status().as_u16(), status, "{documented}");
+    }
+}
+
+#[test]
+fn the_documented_list_covers_every_code() {
+    let documented: Vec<ErrorCode> = DOCUMENTED.iter().map(|(code, _, _)| *code).collect();
+    assert!(ErrorCode::ALL == documented.as_slice());
+}
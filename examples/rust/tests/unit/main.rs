@@ -0,0 +1,6 @@
+// Tests of library pieces on their own, without going through the router
+mod app_error;
+mod builders;
+mod config;
+mod error_codes;
+mod pagination;
@@ -0,0 +1,33 @@
+use openapi_integration::bench_support::Store;
+
+// Walks every page with cursors and checks each against the offset page of the same number
+fn assert_keyset_matches_offset(store: &Store, per_page: u32) {
+    let mut cursor = None;
+    let mut page = 1;
+    loop {
+        let (ids, next_cursor) = store.keyset_page(cursor.as_deref(), per_page);
+        assert_eq!(
+            ids,
+            store.offset_page(page, per_page),
+            "page {page} differs"
+        );
+        assert_eq!(
+            cursor,
+            store.cursor_before(page, per_page),
+            "cursor for page {page} differs"
+        );
+        match next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+        page += 1;
+    }
+    assert!(store.offset_page(page + 1, per_page).is_empty());
+}
+
+#[test]
+fn keyset_pages_equal_offset_pages() {
+    // A partial last page, then an exact multiple of the page size
+    assert_keyset_matches_offset(&Store::seeded(95), 10);
+    assert_keyset_matches_offset(&Store::seeded(100), 10);
+}